@@ -139,3 +139,14 @@
 (self_closing_tag
   (tag_name) @slot_tag
   (#match? @slot_tag "^x-slot:"))
+
+; Match slot tags with a name attribute: <x-slot name="header">
+(element
+  (start_tag
+    (tag_name) @slot_tag_name
+    (attribute
+      (attribute_name) @slot_attribute_name
+      (quoted_attribute_value
+        (attribute_value) @slot_name_attr)))
+  (#eq? @slot_tag_name "x-slot")
+  (#eq? @slot_attribute_name "name"))
@@ -135,12 +135,14 @@
   (#eq? @method_name "view"))
 
 ; ============================================================================
-; Pattern 4: Volt::route('/path', 'view.name') - Volt route registration
+; Pattern 4: Volt::route('/path', 'component.name') - Volt route registration
 ; ============================================================================
 ; Matches: Volt::route('/home', 'welcome')
-;          Volt::route('/about', 'pages.about')
+;          Volt::route('/settings/profile', 'settings.profile')
 ;
-; Same as Route::view() - captures the SECOND argument (view name)
+; Captures the SECOND argument like Route::view(), but the name is a Volt
+; component (resolved against the Volt mount paths), not a regular view.
+; Route::redirect('/a', '/b') is intentionally not matched - it has no view.
 
 ; Single-quoted view name (second argument)
 (scoped_call_expression
@@ -150,7 +152,7 @@
     (argument)
     (argument
       (string
-        (string_content) @route_view_name)))
+        (string_content) @volt_route_name)))
   (#eq? @class_name "Volt")
   (#eq? @method_name "route"))
 
@@ -162,7 +164,7 @@
     (argument)
     (argument
       (encapsed_string
-        (string_content) @route_view_name)))
+        (string_content) @volt_route_name)))
   (#eq? @class_name "Volt")
   (#eq? @method_name "route"))
 
@@ -243,7 +245,7 @@
       (string
         (string_content) @config_key)))
   (#eq? @class_name "Config")
-  (#match? @method_name "^(get|string|integer|boolean|array|set|has)$"))
+  (#match? @method_name "^(get|string|integer|boolean|array|has)$"))
 
 ; Double-quoted strings
 (scoped_call_expression
@@ -255,7 +257,7 @@
       (encapsed_string
         (string_content) @config_key)))
   (#eq? @class_name "Config")
-  (#match? @method_name "^(get|string|integer|boolean|array|set|has)$"))
+  (#match? @method_name "^(get|string|integer|boolean|array|has)$"))
 
 ; Also match fully qualified Config class - single quotes
 (scoped_call_expression
@@ -267,7 +269,7 @@
       (string
         (string_content) @config_key)))
   (#match? @class_name ".*Config$")
-  (#match? @method_name "^(get|string|integer|boolean|array|set|has)$"))
+  (#match? @method_name "^(get|string|integer|boolean|array|has)$"))
 
 ; Also match fully qualified Config class - double quotes
 (scoped_call_expression
@@ -279,7 +281,66 @@
       (encapsed_string
         (string_content) @config_key)))
   (#match? @class_name ".*Config$")
-  (#match? @method_name "^(get|string|integer|boolean|array|set|has)$"))
+  (#match? @method_name "^(get|string|integer|boolean|array|has)$"))
+
+; ============================================================================
+; Pattern 6c: config(['key' => value]) and Config::set('key', value) - Set forms
+; ============================================================================
+; Matches: config(['app.runtime' => true])
+;          Config::set('app.timezone', 'UTC')
+;
+; Setting config at runtime may create keys that no config file defines,
+; so these are captured separately (goto/completion, but no diagnostics)
+
+; Array keys - single quotes
+(function_call_expression
+  function: (name) @function_name
+  arguments: (arguments
+    .
+    (argument
+      (array_creation_expression
+        (array_element_initializer
+          .
+          (string
+            (string_content) @config_set_key)))))
+  (#eq? @function_name "config"))
+
+; Array keys - double quotes
+(function_call_expression
+  function: (name) @function_name
+  arguments: (arguments
+    .
+    (argument
+      (array_creation_expression
+        (array_element_initializer
+          .
+          (encapsed_string
+            (string_content) @config_set_key)))))
+  (#eq? @function_name "config"))
+
+; Config::set - single quotes
+(scoped_call_expression
+  scope: [(name) (qualified_name)] @class_name
+  name: (name) @method_name
+  arguments: (arguments
+    .
+    (argument
+      (string
+        (string_content) @config_set_key)))
+  (#match? @class_name "^(Config|.*\\\\Config)$")
+  (#eq? @method_name "set"))
+
+; Config::set - double quotes
+(scoped_call_expression
+  scope: [(name) (qualified_name)] @class_name
+  name: (name) @method_name
+  arguments: (arguments
+    .
+    (argument
+      (encapsed_string
+        (string_content) @config_set_key)))
+  (#match? @class_name "^(Config|.*\\\\Config)$")
+  (#eq? @method_name "set"))
 
 ; ============================================================================
 ; Pattern 7: route('route.name') function calls
@@ -1028,6 +1089,7 @@
 ; Matches: action('UserController@show')
 ;          action('App\Http\Controllers\AdminController@index')
 ;          action([UserController::class, 'show'])
+;          Route::get('/users', [UserController::class, 'show'])
 ;
 ; Captures controller action for navigation
 
@@ -1051,6 +1113,46 @@
         (string_content) @action_name)))
   (#eq? @function_name "action"))
 
+; Tuple syntax - [UserController::class, 'show'] (the method string is captured)
+(function_call_expression
+  function: (name) @function_name
+  arguments: (arguments
+    .
+    (argument
+      (array_creation_expression
+        .
+        (array_element_initializer
+          (class_constant_access_expression
+            [(name) (qualified_name)] @action_class
+            (name) @constant_name))
+        .
+        (array_element_initializer
+          (string
+            (string_content) @action_method)))))
+  (#eq? @function_name "action")
+  (#eq? @constant_name "class"))
+
+; Route actions - Route::get('/users', [UserController::class, 'show'])
+(scoped_call_expression
+  scope: (name) @route_facade
+  name: (name) @route_verb
+  arguments: (arguments
+    (argument
+      (array_creation_expression
+        .
+        (array_element_initializer
+          (class_constant_access_expression
+            [(name) (qualified_name)] @action_class
+            (name) @constant_name))
+        .
+        (array_element_initializer
+          (string
+            (string_content) @action_method))
+        .)))
+  (#eq? @route_facade "Route")
+  (#match? @route_verb "^(get|post|put|patch|delete|options|any|match)$")
+  (#eq? @constant_name "class"))
+
 ; ============================================================================
 ; Pattern 20: redirect()->route('name') - Redirect to named route
 ; ============================================================================
@@ -1079,6 +1181,38 @@
         (string_content) @route_name)))
   (#eq? @method_name "route"))
 
+; ============================================================================
+; Pattern 20b: Redirect::route('name') - Redirect facade
+; ============================================================================
+; Matches: Redirect::route('home')
+;          Redirect::route('user.profile', ['id' => 1])
+;
+; back() / redirect()->back() have no target and aren't captured
+
+; Single-quoted strings
+(scoped_call_expression
+  scope: (name) @class_name
+  name: (name) @method_name
+  arguments: (arguments
+    .
+    (argument
+      (string
+        (string_content) @route_name)))
+  (#eq? @class_name "Redirect")
+  (#eq? @method_name "route"))
+
+; Double-quoted strings
+(scoped_call_expression
+  scope: (name) @class_name
+  name: (name) @method_name
+  arguments: (arguments
+    .
+    (argument
+      (encapsed_string
+        (string_content) @route_name)))
+  (#eq? @class_name "Redirect")
+  (#eq? @method_name "route"))
+
 ; ============================================================================
 ; Pattern 21: to_route('name') - Laravel 9+ redirect helper
 ; ============================================================================
@@ -1467,3 +1601,268 @@
         (name) @constant_name)))
   (#match? @feature_method_name "^(active|inactive|value|when)$")
   (#eq? @constant_name "class"))
+
+; ============================================================================
+; Pattern 31: Config-backed name references - connections, stores, disks
+; ============================================================================
+; Matches: Queue::connection('redis')
+;          Cache::store('file')
+;          cache()->store('redis')
+;          Broadcast::connection('pusher')
+;          Storage::disk('s3')
+;          DB::connection('mysql')
+;          Log::channel('slack')
+;          Mail::mailer('ses')
+;          Auth::guard('api'), auth()->guard('api')
+;
+; The name is an entry in a config array (e.g. queue.connections.redis).
+; Any facade or helper call with a string first argument is captured here;
+; CONFIG_NAME_REFERENCES in queries.rs decides which receiver/method pairs
+; are config-backed and maps them to their config path, so adding a pair
+; only needs a new table row.
+
+; Facade calls - single quotes
+(scoped_call_expression
+  scope: (name) @config_name_receiver
+  name: (name) @config_name_method
+  arguments: (arguments
+    .
+    (argument
+      (string
+        (string_content) @config_name))))
+
+; Facade calls - double quotes
+(scoped_call_expression
+  scope: (name) @config_name_receiver
+  name: (name) @config_name_method
+  arguments: (arguments
+    .
+    (argument
+      (encapsed_string
+        (string_content) @config_name))))
+
+; Helper calls - single quotes: cache()->store('redis')
+(member_call_expression
+  object: (function_call_expression
+    function: (name) @config_name_receiver)
+  name: (name) @config_name_method
+  arguments: (arguments
+    .
+    (argument
+      (string
+        (string_content) @config_name))))
+
+; Helper calls - double quotes
+(member_call_expression
+  object: (function_call_expression
+    function: (name) @config_name_receiver)
+  name: (name) @config_name_method
+  arguments: (arguments
+    .
+    (argument
+      (encapsed_string
+        (string_content) @config_name))))
+
+; ============================================================================
+; Pattern 32: Model::query(), Model::where(...) - Eloquent query heads
+; ============================================================================
+; Matches: User::query()
+;          User::where('active', 1)
+;          \App\Models\User::find($id)
+;
+; Only the class is captured, for navigating to the model file.
+; The chained query builder methods are not resolved.
+
+(scoped_call_expression
+  scope: [(name) (qualified_name)] @model_class
+  name: (name) @model_method
+  (#match? @model_method "^(query|where|whereIn|whereNotIn|whereNull|whereNotNull|whereHas|whereBelongsTo|find|findOrFail|findMany|findOr|first|firstWhere|firstOrFail|firstOrNew|firstOrCreate|updateOrCreate|with|withCount|withTrashed|onlyTrashed|latest|oldest|orderBy|orderByDesc|all|paginate|simplePaginate|cursorPaginate|destroy)$"))
+
+; ============================================================================
+; Pattern 33: Authorization abilities - gates and policy methods
+; ============================================================================
+; Matches: $user->can('update', $post)
+;          $user->cannot('delete', $post)
+;          $this->authorize('update', $post)
+;          Gate::allows('view-admin')
+;          Gate::denies('publish', Post::class)
+;
+; The ability resolves to a policy method when the next argument names a
+; model with a policy (update + $post → PostPolicy::update), otherwise to
+; its Gate::define(). The subject argument is read from the syntax tree.
+
+; Member calls - single quotes
+(member_call_expression
+  name: (name) @ability_method
+  arguments: (arguments
+    .
+    (argument
+      (string
+        (string_content) @ability_name)))
+  (#match? @ability_method "^(can|cannot|cant|authorize)$"))
+
+; Member calls - double quotes
+(member_call_expression
+  name: (name) @ability_method
+  arguments: (arguments
+    .
+    (argument
+      (encapsed_string
+        (string_content) @ability_name)))
+  (#match? @ability_method "^(can|cannot|cant|authorize)$"))
+
+; Gate facade - single quotes
+(scoped_call_expression
+  scope: (name) @ability_receiver
+  name: (name) @ability_method
+  arguments: (arguments
+    .
+    (argument
+      (string
+        (string_content) @ability_name)))
+  (#eq? @ability_receiver "Gate")
+  (#match? @ability_method "^(allows|denies|check|authorize|inspect)$"))
+
+; Gate facade - double quotes
+(scoped_call_expression
+  scope: (name) @ability_receiver
+  name: (name) @ability_method
+  arguments: (arguments
+    .
+    (argument
+      (encapsed_string
+        (string_content) @ability_name)))
+  (#eq? @ability_receiver "Gate")
+  (#match? @ability_method "^(allows|denies|check|authorize|inspect)$"))
+
+; ============================================================================
+; Pattern 34: Custom validation rule objects in rules arrays
+; ============================================================================
+; Matches: 'title' => ['required', new Uppercase]
+;          'slug' => new \App\Rules\Slug()
+;
+; Only the class is captured, for navigating to the rule class file.
+
+(array_element_initializer
+  (object_creation_expression
+    [(name) (qualified_name)] @rule_class))
+
+; ============================================================================
+; Pattern 35: Artisan console commands
+; ============================================================================
+; Matches: Artisan::call('mail:send')
+;          Artisan::queue('reports:build', ['--force' => true])
+;          $schedule->command('backup:run')->daily()
+;          Schedule::command(BackupCommand::class)
+;
+; String arguments may carry arguments and options after the command name
+; ('mail:send 1 --queue=default'); only the name is used.
+; Artisan::command('inspire', ...) defines a command, so it isn't matched.
+
+; Artisan facade - single quotes
+(scoped_call_expression
+  scope: (name) @command_receiver
+  name: (name) @command_method
+  arguments: (arguments
+    .
+    (argument
+      (string
+        (string_content) @command_name)))
+  (#eq? @command_receiver "Artisan")
+  (#match? @command_method "^(call|callSilent|callSilently|queue)$"))
+
+; Artisan facade - double quotes
+(scoped_call_expression
+  scope: (name) @command_receiver
+  name: (name) @command_method
+  arguments: (arguments
+    .
+    (argument
+      (encapsed_string
+        (string_content) @command_name)))
+  (#eq? @command_receiver "Artisan")
+  (#match? @command_method "^(call|callSilent|callSilently|queue)$"))
+
+; Schedule facade - single quotes
+(scoped_call_expression
+  scope: (name) @command_receiver
+  name: (name) @command_method
+  arguments: (arguments
+    .
+    (argument
+      (string
+        (string_content) @command_name)))
+  (#eq? @command_receiver "Schedule")
+  (#eq? @command_method "command"))
+
+; Schedule facade - double quotes
+(scoped_call_expression
+  scope: (name) @command_receiver
+  name: (name) @command_method
+  arguments: (arguments
+    .
+    (argument
+      (encapsed_string
+        (string_content) @command_name)))
+  (#eq? @command_receiver "Schedule")
+  (#eq? @command_method "command"))
+
+; $schedule->command() - single quotes
+(member_call_expression
+  name: (name) @command_method
+  arguments: (arguments
+    .
+    (argument
+      (string
+        (string_content) @command_name)))
+  (#eq? @command_method "command"))
+
+; $schedule->command() - double quotes
+(member_call_expression
+  name: (name) @command_method
+  arguments: (arguments
+    .
+    (argument
+      (encapsed_string
+        (string_content) @command_name)))
+  (#eq? @command_method "command"))
+
+; Artisan facade - command class with ::class
+(scoped_call_expression
+  scope: (name) @command_receiver
+  name: (name) @command_method
+  arguments: (arguments
+    .
+    (argument
+      (class_constant_access_expression
+        [(name) (qualified_name)] @command_class
+        (name) @constant_name)))
+  (#eq? @command_receiver "Artisan")
+  (#match? @command_method "^(call|callSilent|callSilently|queue)$")
+  (#eq? @constant_name "class"))
+
+; Schedule facade - command class with ::class
+(scoped_call_expression
+  scope: (name) @command_receiver
+  name: (name) @command_method
+  arguments: (arguments
+    .
+    (argument
+      (class_constant_access_expression
+        [(name) (qualified_name)] @command_class
+        (name) @constant_name)))
+  (#eq? @command_receiver "Schedule")
+  (#eq? @command_method "command")
+  (#eq? @constant_name "class"))
+
+; $schedule->command() - command class with ::class
+(member_call_expression
+  name: (name) @command_method
+  arguments: (arguments
+    .
+    (argument
+      (class_constant_access_expression
+        [(name) (qualified_name)] @command_class
+        (name) @constant_name)))
+  (#eq? @command_method "command")
+  (#eq? @constant_name "class"))
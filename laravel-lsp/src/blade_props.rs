@@ -0,0 +1,233 @@
+//! Blade `@props` / `@aware` directive parsing
+//!
+//! Turns `@props(['items' => [], 'count' => 0, 'user'])` into a list of
+//! props with their defaults, so component features can tell required
+//! props from optional ones. `@aware(['theme' => 'light'])` uses the same
+//! array shape for data inherited from a parent component's props.
+
+/// A single entry of a Blade `@props([...])` directive
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BladeProp {
+    /// Prop name without the `$` (e.g., "items")
+    pub name: String,
+    /// Whether a default is given (`'count' => 0`); props without one are required
+    pub has_default: bool,
+    /// Raw PHP expression of the default value, if any
+    pub default: Option<String>,
+}
+
+/// Parse the first `@props([...])` directive in a Blade file
+///
+/// Handles mixed indexed/associative entries and defaults of any shape,
+/// e.g. `@props(['items' => [], 'count' => 0, 'active' => false, 'user'])`.
+/// Returns None when the file has no (well-formed) `@props` directive.
+pub fn parse_props_directive(content: &str) -> Option<Vec<BladeProp>> {
+    parse_array_directive(content, "@props")
+}
+
+/// Parse the first `@aware([...])` directive in a Blade file
+///
+/// Keys name props of a parent component; a default applies when no
+/// ancestor provides the value, e.g. `@aware(['theme' => 'light'])`.
+pub fn parse_aware_directive(content: &str) -> Option<Vec<BladeProp>> {
+    parse_array_directive(content, "@aware")
+}
+
+/// Parse the array argument of the first `directive` occurrence
+fn parse_array_directive(content: &str, directive: &str) -> Option<Vec<BladeProp>> {
+    let start = content.find(directive)? + directive.len();
+    let args = content[start..].trim_start().strip_prefix('(')?;
+    let args = &args[..find_closing_delimiter(args, '(', ')')?];
+
+    let array = args.trim();
+    let entries = if let Some(inner) = array.strip_prefix('[') {
+        inner.strip_suffix(']')?
+    } else {
+        // Old-style array(...) syntax
+        array.strip_prefix("array")?.trim_start().strip_prefix('(')?.strip_suffix(')')?
+    };
+
+    fn unquote(s: &str) -> Option<String> {
+        let s = s.trim();
+        let quote = s.chars().next().filter(|c| *c == '\'' || *c == '"')?;
+        let inner = s.strip_prefix(quote)?.strip_suffix(quote)?;
+        Some(inner.to_string())
+    }
+
+    let mut props = Vec::new();
+    for entry in split_top_level(entries, ',') {
+        let entry = entry.trim();
+        if entry.is_empty() {
+            continue;
+        }
+        match split_top_level(entry, '=').as_slice() {
+            // 'name' => default (split on '=' leaves the '>' on the value)
+            [key, value, ..] if value.starts_with('>') => {
+                let default = entry[key.len() + 2..].trim().to_string();
+                if let Some(name) = unquote(key) {
+                    props.push(BladeProp { name, has_default: true, default: Some(default) });
+                }
+            }
+            // 'name' - required prop
+            _ => {
+                if let Some(name) = unquote(entry) {
+                    props.push(BladeProp { name, has_default: false, default: None });
+                }
+            }
+        }
+    }
+
+    Some(props)
+}
+
+/// Infer a type from a `@props` default value
+///
+/// `User::class` and class-like strings (`'App\Models\User'`) act as type hints,
+/// other literals map to their PHP scalar type.
+pub fn props_default_type(default: &str) -> Option<String> {
+    let default = default.trim();
+    let short_name = |class: &str| {
+        let class = class.trim_start_matches('\\');
+        class.rsplit('\\').next().unwrap_or(class).to_string()
+    };
+
+    if let Some(class) = default.strip_suffix("::class") {
+        return Some(short_name(class));
+    }
+
+    let lower = default.to_lowercase();
+    if default.starts_with('\'') || default.starts_with('"') {
+        let inner = default.get(1..default.len() - 1).unwrap_or("");
+        let looks_like_class = inner.contains('\\') || inner.starts_with(|c: char| c.is_ascii_uppercase());
+        if looks_like_class && inner.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '\\') {
+            return Some(short_name(inner));
+        }
+        Some("string".to_string())
+    } else if default.starts_with('[') || lower.starts_with("array(") {
+        Some("array".to_string())
+    } else if lower == "true" || lower == "false" {
+        Some("bool".to_string())
+    } else if default.parse::<i64>().is_ok() {
+        Some("int".to_string())
+    } else if default.parse::<f64>().is_ok() {
+        Some("float".to_string())
+    } else {
+        None
+    }
+}
+
+/// Byte offset of the delimiter closing an already-opened `open`,
+/// skipping over nested brackets and quoted strings
+pub fn find_closing_delimiter(text: &str, open: char, close: char) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+
+    for (i, c) in text.char_indices() {
+        if let Some(q) = quote {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == q {
+                quote = None;
+            }
+            continue;
+        }
+        match c {
+            '\'' | '"' => quote = Some(c),
+            c if c == open => depth += 1,
+            c if c == close && depth == 0 => return Some(i),
+            c if c == close => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Split on `separator` where it isn't nested in brackets or quotes
+pub fn split_top_level(text: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+    let mut part_start = 0;
+
+    for (i, c) in text.char_indices() {
+        if let Some(q) = quote {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == q {
+                quote = None;
+            }
+            continue;
+        }
+        match c {
+            '\'' | '"' => quote = Some(c),
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            c if c == separator && depth == 0 => {
+                parts.push(&text[part_start..i]);
+                part_start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    parts.push(&text[part_start..]);
+    parts
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_props_directive_fixture() {
+        let content = include_str!("../tests/fixtures/props.blade.php");
+        let props = parse_props_directive(content).unwrap();
+
+        let names: Vec<&str> = props.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["user", "type", "items", "options", "active", "count", "owner", "label", "title"]);
+
+        let required: Vec<&str> = props.iter().filter(|p| !p.has_default).map(|p| p.name.as_str()).collect();
+        assert_eq!(required, vec!["user", "title"], "only entries without a default are required");
+
+        let prop = |name: &str| props.iter().find(|p| p.name == name).unwrap();
+        assert_eq!(prop("options").default.as_deref(), Some("['sm' => 'Small', 'lg' => 'Large']"));
+        assert_eq!(prop("label").default.as_deref(), Some("null"));
+    }
+
+    #[test]
+    fn test_parse_props_directive_edge_cases() {
+        assert!(parse_props_directive("<div>no props</div>").is_none());
+        assert_eq!(parse_props_directive("@props([])"), Some(Vec::new()));
+
+        // Commas and brackets inside strings don't split entries
+        let props = parse_props_directive(r#"@props(['sep' => ', ]', "id"])"#).unwrap();
+        assert_eq!(props.len(), 2);
+        assert_eq!(props[0].default.as_deref(), Some("', ]'"));
+        assert_eq!(props[1], BladeProp { name: "id".to_string(), has_default: false, default: None });
+    }
+
+    #[test]
+    fn test_parse_aware_directive_fixture() {
+        let content = include_str!("../tests/fixtures/aware/menu-item.blade.php");
+        let aware = parse_aware_directive(content).unwrap();
+
+        let names: Vec<&str> = aware.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["color", "size"]);
+        assert_eq!(aware[0].default.as_deref(), Some("'gray'"));
+        assert!(!aware[1].has_default);
+
+        // @aware and @props don't read each other's arrays
+        let props: Vec<String> = parse_props_directive(content).unwrap().into_iter().map(|p| p.name).collect();
+        assert_eq!(props, vec!["active"]);
+        assert!(parse_aware_directive("@props(['color'])").is_none());
+    }
+}
@@ -1,7 +1,8 @@
 //! Smart caching for Laravel LSP
 //!
 //! Tracks file mtimes to avoid unnecessary rescanning.
-//! Caches middleware, bindings, and component data to disk.
+//! Caches middleware, bindings, and component data to disk, along with the
+//! view/component/route/Livewire/translation indexes used for completion.
 
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
@@ -13,7 +14,11 @@ use tracing::{debug, info, warn};
 
 /// Current cache version - increment when cache format changes
 /// v2: Split 'file' into 'class_file' (for existence) and 'source_file' (for navigation)
-const CACHE_VERSION: u32 = 2;
+/// v3: Add Livewire::component() aliases to scan results
+/// v4: Add Blade::directive() custom directives to scan results
+/// v5: Persist completion indexes (views, components, Livewire, routes, translations)
+/// v6: Record the class file of class-based components in the component index
+const CACHE_VERSION: u32 = 6;
 
 /// Cache file name
 const CACHE_FILE: &str = ".laravel-lsp/cache.json";
@@ -74,11 +79,36 @@ pub struct BindingEntry {
     pub line: u32,
 }
 
+/// A cached Livewire component alias from Livewire::component()
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LivewireComponentEntry {
+    pub class: String,
+    /// Path to the component class file (for existence checking and navigation)
+    pub class_file: Option<String>,
+    /// Path to the source file where the alias is registered
+    pub source_file: Option<String>,
+    /// Line number in source_file
+    pub line: u32,
+}
+
+/// A cached custom Blade directive from Blade::directive()
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BladeDirectiveEntry {
+    /// Path to the source file where the directive is registered
+    pub source_file: Option<String>,
+    /// Line number in source_file
+    pub line: u32,
+}
+
 /// Results from scanning a directory
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ScanResult {
     pub middleware: HashMap<String, MiddlewareEntry>,
     pub bindings: HashMap<String, BindingEntry>,
+    #[serde(default)]
+    pub livewire_components: HashMap<String, LivewireComponentEntry>,
+    #[serde(default)]
+    pub blade_directives: HashMap<String, BladeDirectiveEntry>,
 }
 
 impl ScanResult {
@@ -87,12 +117,17 @@ impl ScanResult {
     }
 
     pub fn is_empty(&self) -> bool {
-        self.middleware.is_empty() && self.bindings.is_empty()
+        self.middleware.is_empty()
+            && self.bindings.is_empty()
+            && self.livewire_components.is_empty()
+            && self.blade_directives.is_empty()
     }
 
     pub fn merge(&mut self, other: ScanResult) {
         self.middleware.extend(other.middleware);
         self.bindings.extend(other.bindings);
+        self.livewire_components.extend(other.livewire_components);
+        self.blade_directives.extend(other.blade_directives);
     }
 }
 
@@ -110,7 +145,115 @@ pub struct CachedLaravelConfig {
     pub view_paths: Vec<PathBuf>,
     pub component_paths: Vec<(String, PathBuf)>,
     pub livewire_path: Option<PathBuf>,
+    #[serde(default)]
+    pub livewire_view_path: Option<PathBuf>,
     pub has_livewire: bool,
+    /// Package view namespaces from loadViewsFrom(), so package views are
+    /// known before the provider rescan finishes
+    #[serde(default)]
+    pub view_namespaces: HashMap<String, PathBuf>,
+}
+
+/// A file-backed name in a completion index (view, component or Livewire component)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IndexedFile {
+    pub name: String,
+    /// Display path of the file (relative to the project root where possible)
+    pub path: String,
+    /// Display path of the backing class (class-based components only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub class_path: Option<String>,
+}
+
+/// A named route in the route index
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IndexedRoute {
+    /// The routes file the name was extracted from
+    pub file: PathBuf,
+    pub name: String,
+    /// Display path of the routes file (e.g. "routes/web.php")
+    pub source: String,
+    pub line: u32,
+    pub column: u32,
+    pub end_column: u32,
+}
+
+/// A translation key in the translation index
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IndexedTranslation {
+    pub key: String,
+    pub value: String,
+    /// Display path of the translation file (e.g. "lang/en/auth.php")
+    pub source: String,
+}
+
+/// A completion index together with what it was built from
+///
+/// Only reused for the same `roots`, and only while every directory (and, for
+/// indexes read from file contents, every file) under them has the recorded mtime.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersistedIndex<T> {
+    pub roots: Vec<PathBuf>,
+    pub mtimes: HashMap<String, FileMtime>,
+    pub entries: Vec<T>,
+}
+
+impl<T: Clone> PersistedIndex<T> {
+    /// Entries, if the index was built from `roots` and nothing under them changed since
+    fn fresh_entries(&self, roots: &[PathBuf]) -> Option<Vec<T>> {
+        if self.roots != roots {
+            return None;
+        }
+        // A root that appeared since the index was built
+        let new_root = roots
+            .iter()
+            .any(|root| root.exists() && !self.mtimes.contains_key(root.to_string_lossy().as_ref()));
+        let changed = self
+            .mtimes
+            .iter()
+            .any(|(path, mtime)| FileMtime::from_path(Path::new(path)).as_ref() != Some(mtime));
+        if new_root || changed {
+            return None;
+        }
+        Some(self.entries.clone())
+    }
+}
+
+/// Record the current mtimes under `roots`
+///
+/// Call before building the entries so a change made mid-build marks the
+/// index stale rather than being missed.
+pub fn capture_index_mtimes(roots: &[PathBuf], include_files: bool) -> HashMap<String, FileMtime> {
+    let mut mtimes = HashMap::new();
+    for root in roots {
+        for entry in walkdir::WalkDir::new(root)
+            .follow_links(true)
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .filter(|e| include_files || e.file_type().is_dir())
+        {
+            if let Some(mtime) = FileMtime::from_path(entry.path()) {
+                mtimes.insert(entry.path().to_string_lossy().to_string(), mtime);
+            }
+        }
+    }
+    mtimes
+}
+
+/// Completion indexes built by walking the project, so they're available
+/// straight after a restart
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProjectIndexes {
+    #[serde(default)]
+    pub views: Option<PersistedIndex<IndexedFile>>,
+    #[serde(default)]
+    pub components: Option<PersistedIndex<IndexedFile>>,
+    #[serde(default)]
+    pub livewire: Option<PersistedIndex<IndexedFile>>,
+    #[serde(default)]
+    pub routes: Option<PersistedIndex<IndexedRoute>>,
+    #[serde(default)]
+    pub translations: Option<PersistedIndex<IndexedTranslation>>,
 }
 
 /// Cached environment variables
@@ -138,6 +281,9 @@ pub struct LspCache {
     pub laravel_config: Option<CachedLaravelConfig>,
     /// Environment variables from .env files
     pub env_vars: Option<CachedEnvVars>,
+    /// Completion indexes (views, components, Livewire, routes, translations)
+    #[serde(default)]
+    pub indexes: ProjectIndexes,
 }
 
 impl LspCache {
@@ -151,6 +297,7 @@ impl LspCache {
             node_modules_scan: NodeModulesScan::default(),
             laravel_config: None,
             env_vars: None,
+            indexes: ProjectIndexes::default(),
         }
     }
 }
@@ -361,6 +508,32 @@ impl CacheManager {
         result
     }
 
+    /// Get all cached Livewire component aliases (vendor + app merged)
+    pub fn get_all_livewire_components(&self) -> HashMap<String, LivewireComponentEntry> {
+        let mut result = HashMap::new();
+
+        if let Some(ref cache) = self.cache {
+            // First add vendor (lower priority)
+            result.extend(cache.vendor_scan.livewire_components.clone());
+            // Then add app (higher priority, overwrites vendor)
+            result.extend(cache.app_scan.livewire_components.clone());
+        }
+
+        result
+    }
+
+    /// Get all cached custom Blade directives (vendor + app merged)
+    pub fn get_all_blade_directives(&self) -> HashMap<String, BladeDirectiveEntry> {
+        let mut result = HashMap::new();
+
+        if let Some(ref cache) = self.cache {
+            result.extend(cache.vendor_scan.blade_directives.clone());
+            result.extend(cache.app_scan.blade_directives.clone());
+        }
+
+        result
+    }
+
     /// Get cached vendor scan result
     pub fn get_vendor_scan(&self) -> Option<&ScanResult> {
         self.cache.as_ref().map(|c| &c.vendor_scan)
@@ -426,6 +599,71 @@ impl CacheManager {
         }
     }
 
+    /// Get the cached view index, if still valid for `roots`
+    pub fn get_view_index(&self, roots: &[PathBuf]) -> Option<Vec<IndexedFile>> {
+        self.cache.as_ref()?.indexes.views.as_ref()?.fresh_entries(roots)
+    }
+
+    /// Set the view index
+    pub fn set_view_index(&mut self, index: PersistedIndex<IndexedFile>) {
+        self.ensure_cache();
+        if let Some(ref mut cache) = self.cache {
+            cache.indexes.views = Some(index);
+        }
+    }
+
+    /// Get the cached Blade component index, if still valid for `roots`
+    pub fn get_component_index(&self, roots: &[PathBuf]) -> Option<Vec<IndexedFile>> {
+        self.cache.as_ref()?.indexes.components.as_ref()?.fresh_entries(roots)
+    }
+
+    /// Set the Blade component index
+    pub fn set_component_index(&mut self, index: PersistedIndex<IndexedFile>) {
+        self.ensure_cache();
+        if let Some(ref mut cache) = self.cache {
+            cache.indexes.components = Some(index);
+        }
+    }
+
+    /// Get the cached Livewire component index, if still valid for `roots`
+    pub fn get_livewire_index(&self, roots: &[PathBuf]) -> Option<Vec<IndexedFile>> {
+        self.cache.as_ref()?.indexes.livewire.as_ref()?.fresh_entries(roots)
+    }
+
+    /// Set the Livewire component index
+    pub fn set_livewire_index(&mut self, index: PersistedIndex<IndexedFile>) {
+        self.ensure_cache();
+        if let Some(ref mut cache) = self.cache {
+            cache.indexes.livewire = Some(index);
+        }
+    }
+
+    /// Get the cached route name index, if still valid for `roots`
+    pub fn get_route_index(&self, roots: &[PathBuf]) -> Option<Vec<IndexedRoute>> {
+        self.cache.as_ref()?.indexes.routes.as_ref()?.fresh_entries(roots)
+    }
+
+    /// Set the route name index
+    pub fn set_route_index(&mut self, index: PersistedIndex<IndexedRoute>) {
+        self.ensure_cache();
+        if let Some(ref mut cache) = self.cache {
+            cache.indexes.routes = Some(index);
+        }
+    }
+
+    /// Get the cached translation key index, if still valid for `roots`
+    pub fn get_translation_index(&self, roots: &[PathBuf]) -> Option<Vec<IndexedTranslation>> {
+        self.cache.as_ref()?.indexes.translations.as_ref()?.fresh_entries(roots)
+    }
+
+    /// Set the translation key index
+    pub fn set_translation_index(&mut self, index: PersistedIndex<IndexedTranslation>) {
+        self.ensure_cache();
+        if let Some(ref mut cache) = self.cache {
+            cache.indexes.translations = Some(index);
+        }
+    }
+
     /// Check if cache has any data
     pub fn has_cached_data(&self) -> bool {
         self.cache
@@ -465,6 +703,16 @@ impl CacheManager {
         needed
     }
 
+    /// Drop all cached data and delete the cache file, so everything is rescanned
+    pub fn clear(&mut self) {
+        self.cache = None;
+        if self.cache_path.exists() {
+            if let Err(e) = fs::remove_file(&self.cache_path) {
+                warn!("Failed to delete cache file {:?}: {}", self.cache_path, e);
+            }
+        }
+    }
+
     /// Invalidate a specific rescan type
     pub fn invalidate(&mut self, rescan_type: RescanType) {
         if let Some(ref mut cache) = self.cache {
@@ -528,6 +776,120 @@ mod tests {
         assert!(middleware.contains_key("auth"));
     }
 
+    #[test]
+    fn test_livewire_components_app_overrides_vendor() {
+        let temp = TempDir::new().unwrap();
+        let mut manager = CacheManager::load(temp.path());
+
+        let entry = |class: &str| LivewireComponentEntry {
+            class: class.to_string(),
+            class_file: None,
+            source_file: Some("app/Providers/AppServiceProvider.php".to_string()),
+            line: 12,
+        };
+
+        let mut vendor_scan = ScanResult::new();
+        vendor_scan.livewire_components.insert("custom".to_string(), entry("Vendor\\Custom"));
+        manager.set_vendor_scan(vendor_scan);
+
+        let mut app_scan = ScanResult::new();
+        app_scan.livewire_components.insert("custom".to_string(), entry("App\\Http\\Livewire\\Custom"));
+        assert!(!app_scan.is_empty());
+        manager.set_app_scan(app_scan);
+
+        manager.save().unwrap();
+        let loaded = CacheManager::load(temp.path());
+        let components = loaded.get_all_livewire_components();
+        assert_eq!(components["custom"].class, "App\\Http\\Livewire\\Custom");
+    }
+
+    #[test]
+    fn test_blade_directives_round_trip() {
+        let temp = TempDir::new().unwrap();
+        let mut manager = CacheManager::load(temp.path());
+
+        let mut app_scan = ScanResult::new();
+        app_scan.blade_directives.insert(
+            "datetime".to_string(),
+            BladeDirectiveEntry {
+                source_file: Some("app/Providers/AppServiceProvider.php".to_string()),
+                line: 7,
+            },
+        );
+        assert!(!app_scan.is_empty());
+        manager.set_app_scan(app_scan);
+
+        manager.save().unwrap();
+        let loaded = CacheManager::load(temp.path());
+        let directives = loaded.get_all_blade_directives();
+        assert_eq!(directives["datetime"].line, 7);
+        assert!(!directives.contains_key("money"));
+    }
+
+    #[test]
+    fn test_completion_index_round_trip_and_invalidation() {
+        let temp = TempDir::new().unwrap();
+        let views = temp.path().join("resources/views");
+        std::fs::create_dir_all(views.join("users")).unwrap();
+        std::fs::write(views.join("users/show.blade.php"), "").unwrap();
+        let roots = vec![views.clone()];
+
+        let mut manager = CacheManager::load(temp.path());
+        let mtimes = capture_index_mtimes(&roots, false);
+        assert!(mtimes.contains_key(views.join("users").to_string_lossy().as_ref()));
+        manager.set_view_index(PersistedIndex {
+            roots: roots.clone(),
+            mtimes,
+            entries: vec![IndexedFile {
+                name: "users.show".to_string(),
+                path: "resources/views/users/show.blade.php".to_string(),
+                class_path: None,
+            }],
+        });
+        manager.save().unwrap();
+
+        let loaded = CacheManager::load(temp.path());
+        let entries = loaded.get_view_index(&roots).expect("index should be fresh");
+        assert_eq!(entries[0].name, "users.show");
+        assert!(loaded.get_component_index(&roots).is_none());
+
+        // Different roots (e.g. config now has package view paths)
+        assert!(loaded.get_view_index(&[views.clone(), temp.path().join("vendor/pkg/views")]).is_none());
+
+        // Removing the directory changes what the index was built from
+        std::fs::remove_dir_all(views.join("users")).unwrap();
+        assert!(loaded.get_view_index(&roots).is_none());
+    }
+
+    #[test]
+    fn test_clear_forgets_everything() {
+        let temp = TempDir::new().unwrap();
+        let project_root = temp.path();
+
+        let mut manager = CacheManager::load(project_root);
+        let mut vendor_scan = ScanResult::new();
+        vendor_scan.middleware.insert(
+            "auth".to_string(),
+            MiddlewareEntry {
+                class: "Illuminate\\Auth\\Middleware\\Authenticate".to_string(),
+                class_file: None,
+                source_file: None,
+                line: 10,
+            },
+        );
+        manager.set_vendor_scan(vendor_scan);
+        manager.save().unwrap();
+        assert!(project_root.join(CACHE_FILE).exists());
+
+        manager.clear();
+        assert!(!project_root.join(CACHE_FILE).exists());
+        assert!(!manager.has_cached_data());
+        assert_eq!(
+            manager.get_needed_rescans(),
+            vec![RescanType::Vendor, RescanType::App, RescanType::NodeModules]
+        );
+    }
+
     #[test]
     fn test_mtime_comparison() {
         let mtime1 = FileMtime {
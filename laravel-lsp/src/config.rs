@@ -1,10 +1,13 @@
 //! Laravel project configuration utilities
 //!
-//! This module provides utilities for discovering Laravel projects
-//! and working with Laravel naming conventions.
+//! This module provides utilities for discovering Laravel projects,
+//! loading per-project LSP settings, and working with Laravel naming conventions.
 
 use std::path::{Path, PathBuf};
-use tracing::info;
+use tracing::{debug, info, warn};
+
+/// Name of the per-project settings file, looked up at the Laravel project root
+pub const PROJECT_SETTINGS_FILE: &str = ".laravel-lsp.toml";
 
 /// Find the Laravel project root by walking up from a file path
 ///
@@ -47,6 +50,189 @@ pub fn find_project_root(file_path: &Path) -> Option<PathBuf> {
     }
 }
 
+/// Load `.laravel-lsp.toml` from the project root as a JSON value
+///
+/// The TOML document uses the same keys as the editor settings object
+/// (camelCase, e.g. `autoCompleteDebounce`, `[blade] directiveSpacing`),
+/// so the result can be merged with the editor settings and deserialized
+/// into the same settings struct.
+///
+/// Returns None if the file doesn't exist or can't be parsed.
+pub fn load_project_settings(root: &Path) -> Option<serde_json::Value> {
+    let path = root.join(PROJECT_SETTINGS_FILE);
+    let content = std::fs::read_to_string(&path).ok()?;
+    parse_project_settings(&content).or_else(|| {
+        warn!("Could not parse {:?}, ignoring project settings", path);
+        None
+    })
+}
+
+/// Parse the contents of a `.laravel-lsp.toml` file into a JSON value
+pub fn parse_project_settings(content: &str) -> Option<serde_json::Value> {
+    match toml::from_str::<toml::Value>(content) {
+        Ok(value) => serde_json::to_value(value).ok(),
+        Err(e) => {
+            debug!("Invalid project settings TOML: {}", e);
+            None
+        }
+    }
+}
+
+/// Merge project settings with editor settings
+///
+/// Precedence (highest wins):
+/// 1. Editor settings (Zed `lsp.laravel-lsp.settings` / initialization options)
+/// 2. Project settings (`.laravel-lsp.toml` at the project root)
+/// 3. Built-in defaults (applied later by serde when deserializing)
+///
+/// Objects are merged key by key so a project file can set `blade.directiveSpacing`
+/// while the editor only overrides `autoCompleteDebounce`. Any other value in the
+/// editor settings (including arrays) replaces the project value entirely.
+pub fn merge_settings(project: &serde_json::Value, editor: &serde_json::Value) -> serde_json::Value {
+    use serde_json::Value;
+
+    match (project, editor) {
+        (Value::Object(project_map), Value::Object(editor_map)) => {
+            let mut merged = project_map.clone();
+            for (key, editor_value) in editor_map {
+                let value = match merged.get(key) {
+                    Some(project_value) => merge_settings(project_value, editor_value),
+                    None => editor_value.clone(),
+                };
+                merged.insert(key.clone(), value);
+            }
+            Value::Object(merged)
+        }
+        // Missing editor settings never clobber the project file
+        (_, Value::Null) => project.clone(),
+        (_, editor_value) => editor_value.clone(),
+    }
+}
+
+/// Which filename suffixes count as Blade templates and PHP sources
+///
+/// Configured via the `bladeExtensions` / `phpExtensions` settings, e.g.
+/// `{ "bladeExtensions": [".blade.php", ".antlers.php"] }`. The leading dot is optional.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileTypeSettings {
+    #[serde(default = "default_blade_extensions")]
+    pub blade_extensions: Vec<String>,
+    #[serde(default = "default_php_extensions")]
+    pub php_extensions: Vec<String>,
+}
+
+fn default_blade_extensions() -> Vec<String> {
+    vec![".blade.php".to_string()]
+}
+
+fn default_php_extensions() -> Vec<String> {
+    vec![".php".to_string()]
+}
+
+impl Default for FileTypeSettings {
+    fn default() -> Self {
+        Self {
+            blade_extensions: default_blade_extensions(),
+            php_extensions: default_php_extensions(),
+        }
+    }
+}
+
+/// Kind of source file, as far as pattern extraction is concerned
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileKind {
+    /// Blade template (parsed with the Blade grammar)
+    Blade,
+    /// Plain PHP source
+    Php,
+    /// Anything else (.env, composer.json, assets, ...)
+    Other,
+}
+
+impl FileKind {
+    /// Classify a path by its filename suffix
+    ///
+    /// Blade extensions are checked first since they usually end in `.php` too.
+    pub fn from_path(path: impl AsRef<Path>, settings: &FileTypeSettings) -> Self {
+        let Some(file_name) = path.as_ref().file_name().and_then(|n| n.to_str()) else {
+            return FileKind::Other;
+        };
+        let has_suffix = |extensions: &[String]| {
+            extensions.iter().any(|ext| {
+                let ext = ext.trim_start_matches('.');
+                file_name
+                    .strip_suffix(ext)
+                    .is_some_and(|stem| stem.len() > 1 && stem.ends_with('.'))
+            })
+        };
+
+        if has_suffix(&settings.blade_extensions) {
+            FileKind::Blade
+        } else if has_suffix(&settings.php_extensions) {
+            FileKind::Php
+        } else {
+            FileKind::Other
+        }
+    }
+
+    pub fn is_blade(self) -> bool {
+        self == FileKind::Blade
+    }
+
+    /// PHP or Blade - anything we extract patterns from
+    pub fn is_source(self) -> bool {
+        self != FileKind::Other
+    }
+}
+
+/// Which asset/path helpers get "file not found" diagnostics
+///
+/// Configured via `validateAssets` (default true) and an optional `assetHelpers`
+/// allow-list, e.g. `{ "assetHelpers": ["mix", "@vite"] }` to keep checking
+/// build manifests while skipping `asset()` for CDN or hashed files.
+/// Go-to-definition is unaffected either way.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetValidationSettings {
+    #[serde(default = "default_validate_assets")]
+    pub validate_assets: bool,
+    #[serde(default)]
+    pub asset_helpers: Option<Vec<String>>,
+}
+
+fn default_validate_assets() -> bool {
+    true
+}
+
+impl Default for AssetValidationSettings {
+    fn default() -> Self {
+        Self {
+            validate_assets: default_validate_assets(),
+            asset_helpers: None,
+        }
+    }
+}
+
+impl AssetValidationSettings {
+    /// Whether missing files referenced through `helper` should be reported
+    ///
+    /// `helper` is the name as written in code (`asset`, `mix`, `@vite`); the
+    /// allow-list may omit the `@` for directives.
+    pub fn validates(&self, helper: &str) -> bool {
+        if !self.validate_assets {
+            return false;
+        }
+        match &self.asset_helpers {
+            Some(helpers) => {
+                let helper = helper.trim_start_matches('@');
+                helpers.iter().any(|h| h.trim_start_matches('@') == helper)
+            }
+            None => true,
+        }
+    }
+}
+
 /// Convert kebab-case to PascalCase
 ///
 /// Used for converting Livewire component names to class names.
@@ -65,6 +251,323 @@ pub fn kebab_to_pascal_case(s: &str) -> String {
         .collect()
 }
 
+/// Convert a Blade component name to its class path, relative to the component namespace
+///
+/// Dots separate directories and every segment is PascalCased, so views and
+/// classes resolve from the same name:
+/// - "alert" -> "Alert"
+/// - "forms.text-input" -> "Forms/TextInput"
+///   (view: components/forms/text-input.blade.php)
+pub fn component_class_path(component_name: &str) -> String {
+    component_name
+        .split('.')
+        .map(kebab_to_pascal_case)
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Where a Laravel project keeps its application classes
+///
+/// Controllers, Livewire components, view components and service providers
+/// conventionally live under the `App\` namespace in `app/`. Projects can
+/// rename that namespace or move the directory, so the locations are derived
+/// from composer.json's PSR-4 map (and `config/livewire.php`) instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LaravelPaths {
+    /// The project root these paths were derived for
+    pub root: PathBuf,
+    /// The application's root namespace (e.g., "App")
+    pub app_namespace: String,
+    /// Directory of the application namespace (e.g., `{root}/app`)
+    pub app_dir: PathBuf,
+    /// `{app}/Http/Controllers`
+    pub controllers_dir: PathBuf,
+    /// Livewire classes: `class_namespace` from config/livewire.php, else
+    /// `{app}/Livewire` (v3) or an existing `{app}/Http/Livewire` (v2)
+    pub livewire_dir: PathBuf,
+    /// `{app}/View/Components`
+    pub components_dir: PathBuf,
+    /// `{app}/Providers`
+    pub providers_dir: PathBuf,
+    /// `nwidart/laravel-modules` modules (empty unless the package is installed)
+    pub modules: Vec<LaravelModule>,
+    /// PSR-4 namespace prefixes (without trailing `\`) and their directories, longest first
+    psr4: Psr4Entries,
+}
+
+impl LaravelPaths {
+    /// Derive the paths for a project from its composer.json and config/livewire.php,
+    /// including any `nwidart/laravel-modules` modules
+    pub fn for_root(root: &Path) -> Self {
+        let composer_json = std::fs::read_to_string(root.join("composer.json")).ok();
+        let livewire_config = std::fs::read_to_string(root.join("config/livewire.php")).ok();
+        Self::from_sources(root, composer_json.as_deref(), livewire_config.as_deref())
+            .with_modules(discover_modules(root, composer_json.as_deref()))
+    }
+
+    /// Derive the paths from file contents (either may be missing)
+    pub fn from_sources(root: &Path, composer_json: Option<&str>, livewire_config: Option<&str>) -> Self {
+        let (autoload, autoload_dev) = composer_json.map(parse_psr4_autoload).unwrap_or_default();
+
+        // The application namespace is the one mapped to app/, else the one whose
+        // directory holds the app's controllers or providers, else the top-level
+        // namespace of a relocated app (e.g. `Acme\` => src/)
+        let app = autoload
+            .iter()
+            .find(|(_, dir)| dir == Path::new("app"))
+            .or_else(|| {
+                autoload.iter().find(|(_, dir)| {
+                    let dir = root.join(dir);
+                    dir.join("Http/Controllers").is_dir() || dir.join("Providers").is_dir()
+                })
+            })
+            .or_else(|| autoload.iter().find(|(namespace, _)| !namespace.contains('\\')))
+            .cloned();
+        let (app_namespace, app_dir) = match app {
+            Some((namespace, dir)) => (namespace, root.join(dir)),
+            None => ("App".to_string(), root.join("app")),
+        };
+
+        let mut psr4: Psr4Entries = autoload
+            .into_iter()
+            .chain(autoload_dev)
+            .map(|(namespace, dir)| (namespace, root.join(dir)))
+            .collect();
+        if !psr4.iter().any(|(namespace, _)| *namespace == app_namespace) {
+            psr4.push((app_namespace.clone(), app_dir.clone()));
+        }
+        psr4.sort_by_key(|(namespace, _)| std::cmp::Reverse(namespace.len()));
+
+        let mut paths = Self {
+            root: root.to_path_buf(),
+            controllers_dir: app_dir.join("Http/Controllers"),
+            components_dir: app_dir.join("View/Components"),
+            providers_dir: app_dir.join("Providers"),
+            livewire_dir: app_dir.join("Livewire"),
+            modules: Vec::new(),
+            app_namespace,
+            app_dir,
+            psr4,
+        };
+
+        let configured_livewire = livewire_config
+            .and_then(livewire_class_namespace)
+            .and_then(|namespace| paths.namespace_dir(&namespace));
+        let legacy_livewire = paths.app_dir.join("Http/Livewire");
+        paths.livewire_dir = match configured_livewire {
+            Some(dir) => dir,
+            None if !paths.livewire_dir.exists() && legacy_livewire.exists() => legacy_livewire,
+            None => paths.livewire_dir.clone(),
+        };
+
+        paths
+    }
+
+    /// Add modules and map their namespaces, unless composer.json already does
+    pub fn with_modules(mut self, modules: Vec<LaravelModule>) -> Self {
+        for module in &modules {
+            if !self.psr4.iter().any(|(namespace, _)| *namespace == module.namespace) {
+                self.psr4.push((module.namespace.clone(), module.class_dir.clone()));
+            }
+        }
+        self.psr4.sort_by_key(|(namespace, _)| std::cmp::Reverse(namespace.len()));
+        self.modules = modules;
+        self
+    }
+
+    /// Directory a namespace maps to through PSR-4 (e.g., `App\Livewire` → `{root}/app/Livewire`)
+    pub fn namespace_dir(&self, namespace: &str) -> Option<PathBuf> {
+        let namespace = namespace.trim_start_matches('\\').trim_end_matches('\\');
+        self.psr4.iter().find_map(|(prefix, dir)| {
+            let rest = namespace.strip_prefix(prefix.as_str())?;
+            if rest.is_empty() {
+                Some(dir.clone())
+            } else {
+                rest.strip_prefix('\\').map(|rest| dir.join(rest.replace('\\', "/")))
+            }
+        })
+    }
+
+    /// File a fully-qualified class maps to through PSR-4, whether or not it exists
+    /// (e.g., `App\Models\User` → `{root}/app/Models/User.php`)
+    pub fn class_file(&self, class_name: &str) -> Option<PathBuf> {
+        self.namespace_dir(class_name).map(|path| path.with_extension("php"))
+    }
+
+    /// Namespace of the classes in a directory, the inverse of `namespace_dir`
+    /// (e.g., `{root}/app/View/Components/Forms` → `App\View\Components\Forms`)
+    pub fn dir_namespace(&self, dir: &Path) -> Option<String> {
+        self.psr4
+            .iter()
+            .filter_map(|(prefix, base)| Some((prefix, dir.strip_prefix(base).ok()?)))
+            // The deepest base directory is the most specific mapping
+            .min_by_key(|(_, rest)| rest.components().count())
+            .map(|(prefix, rest)| {
+                std::iter::once(prefix.clone())
+                    .chain(rest.components().map(|c| c.as_os_str().to_string_lossy().into_owned()))
+                    .filter(|segment| !segment.is_empty())
+                    .collect::<Vec<_>>()
+                    .join("\\")
+            })
+    }
+}
+
+/// A module of an `nwidart/laravel-modules` application (e.g., `Modules/Blog`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LaravelModule {
+    /// Module name as listed in modules_statuses.json (e.g., "Blog")
+    pub name: String,
+    /// View and component namespace (e.g., "blog" for `view('blog::posts.index')`)
+    pub view_namespace: String,
+    /// The module directory (e.g., `{root}/Modules/Blog`)
+    pub dir: PathBuf,
+    /// Namespace of the module's classes (e.g., `Modules\Blog`)
+    pub namespace: String,
+    /// Directory of that namespace: `{module}/app` since v11, the module directory before
+    pub class_dir: PathBuf,
+    /// `{module}/resources/views`, or `{module}/Resources/views` in older layouts
+    pub view_dir: PathBuf,
+}
+
+impl LaravelModule {
+    /// Read a module's layout from its module.json and composer.json
+    fn load(modules_dir: &Path, name: String) -> Self {
+        let dir = modules_dir.join(&name);
+
+        let manifest = std::fs::read_to_string(dir.join("module.json"))
+            .ok()
+            .and_then(|json| serde_json::from_str::<serde_json::Value>(&json).ok());
+        let view_namespace = manifest
+            .as_ref()
+            .and_then(|manifest| manifest["alias"].as_str())
+            .filter(|alias| !alias.is_empty())
+            .map(str::to_string)
+            .unwrap_or_else(|| name.to_lowercase());
+
+        // The module's composer.json maps its namespace relative to the module directory
+        let default_namespace = format!("Modules\\{}", name);
+        let (autoload, _) = std::fs::read_to_string(dir.join("composer.json"))
+            .map(|json| parse_psr4_autoload(&json))
+            .unwrap_or_default();
+        let (namespace, class_dir) = match autoload.into_iter().find(|(namespace, _)| *namespace == default_namespace) {
+            Some((namespace, relative)) => (namespace, dir.join(relative)),
+            None => {
+                let app = dir.join("app");
+                (default_namespace, if app.is_dir() { app } else { dir.clone() })
+            }
+        };
+
+        let view_dir = ["resources/views", "Resources/views"]
+            .iter()
+            .map(|relative| dir.join(relative))
+            .find(|path| path.is_dir())
+            .unwrap_or_else(|| dir.join("resources/views"));
+
+        Self { name, view_namespace, dir, namespace, class_dir, view_dir }
+    }
+
+    /// `{classes}/Http/Controllers`
+    pub fn controllers_dir(&self) -> PathBuf {
+        self.class_dir.join("Http/Controllers")
+    }
+}
+
+/// Modules of an `nwidart/laravel-modules` application, by name
+///
+/// Empty unless composer.json requires the package. Modules disabled in
+/// modules_statuses.json are skipped; without that file every directory
+/// under `Modules/` with a module.json counts.
+pub fn discover_modules(root: &Path, composer_json: Option<&str>) -> Vec<LaravelModule> {
+    let requires_modules = composer_json
+        .and_then(|json| serde_json::from_str::<serde_json::Value>(json).ok())
+        .map(|composer| {
+            ["require", "require-dev"]
+                .iter()
+                .any(|section| composer[*section].get("nwidart/laravel-modules").is_some())
+        })
+        .unwrap_or(false);
+    if !requires_modules {
+        return Vec::new();
+    }
+
+    let modules_dir = root.join("Modules");
+    let statuses = std::fs::read_to_string(root.join("modules_statuses.json"))
+        .ok()
+        .and_then(|json| serde_json::from_str::<serde_json::Value>(&json).ok());
+
+    let mut names: Vec<String> = match statuses.as_ref().and_then(|statuses| statuses.as_object()) {
+        Some(statuses) => statuses
+            .iter()
+            .filter(|(_, enabled)| enabled.as_bool().unwrap_or(false))
+            .map(|(name, _)| name.clone())
+            .collect(),
+        None => std::fs::read_dir(&modules_dir)
+            .map(|entries| {
+                entries
+                    .filter_map(|entry| entry.ok())
+                    .filter(|entry| entry.path().join("module.json").is_file())
+                    .filter_map(|entry| entry.file_name().to_str().map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default(),
+    };
+    names.sort();
+
+    names
+        .into_iter()
+        .map(|name| LaravelModule::load(&modules_dir, name))
+        .filter(|module| module.dir.is_dir())
+        .collect()
+}
+
+/// PSR-4 entries as (namespace without trailing `\`, directory)
+type Psr4Entries = Vec<(String, PathBuf)>;
+
+/// PSR-4 entries of composer.json's `autoload` and `autoload-dev` sections,
+/// with directories relative to the root
+fn parse_psr4_autoload(composer_json: &str) -> (Psr4Entries, Psr4Entries) {
+    let Ok(composer) = serde_json::from_str::<serde_json::Value>(composer_json) else {
+        return Default::default();
+    };
+
+    let section = |key: &str| -> Psr4Entries {
+        let Some(map) = composer[key]["psr-4"].as_object() else {
+            return Vec::new();
+        };
+        map.iter()
+            .flat_map(|(namespace, dirs)| {
+                // A namespace may map to one directory or a list of them
+                let dirs: Vec<&str> = match dirs {
+                    serde_json::Value::String(dir) => vec![dir.as_str()],
+                    serde_json::Value::Array(list) => list.iter().filter_map(|d| d.as_str()).collect(),
+                    _ => Vec::new(),
+                };
+                let namespace = namespace.trim_end_matches('\\').to_string();
+                dirs.into_iter()
+                    .map(move |dir| (namespace.clone(), PathBuf::from(dir.trim_start_matches("./").trim_end_matches('/'))))
+            })
+            .collect()
+    };
+
+    (section("autoload"), section("autoload-dev"))
+}
+
+/// The `class_namespace` setting of config/livewire.php (e.g., "App\Livewire")
+fn livewire_class_namespace(livewire_config: &str) -> Option<String> {
+    use lazy_static::lazy_static;
+    use regex::Regex;
+
+    lazy_static! {
+        static ref CLASS_NAMESPACE_RE: Regex = Regex::new(
+            r#"['"]class_namespace['"]\s*=>\s*['"]([^'"]+)['"]"#
+        ).unwrap();
+    }
+
+    CLASS_NAMESPACE_RE
+        .captures(livewire_config)
+        .map(|cap| cap[1].replace("\\\\", "\\"))
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -73,6 +576,13 @@ pub fn kebab_to_pascal_case(s: &str) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_component_class_path() {
+        assert_eq!(component_class_path("alert"), "Alert");
+        assert_eq!(component_class_path("forms.text-input"), "Forms/TextInput");
+        assert_eq!(component_class_path("admin.user-forms.text-input"), "Admin/UserForms/TextInput");
+    }
+
     /// Extract base_path(...) calls from a line (test helper)
     fn extract_base_path(line: &str) -> Option<&str> {
         // Match: base_path('some/path') or base_path("some/path")
@@ -96,6 +606,210 @@ mod tests {
         assert_eq!(kebab_to_pascal_case("simple"), "Simple");
     }
 
+    #[test]
+    fn test_parse_project_settings() {
+        let toml = r#"
+autoCompleteDebounce = 350
+
+[blade]
+directiveSpacing = true
+"#;
+        let value = parse_project_settings(toml).unwrap();
+        assert_eq!(value["autoCompleteDebounce"], 350);
+        assert_eq!(value["blade"]["directiveSpacing"], true);
+
+        assert!(parse_project_settings("not = [valid").is_none());
+    }
+
+    #[test]
+    fn test_merge_settings_editor_wins() {
+        let project = serde_json::json!({
+            "autoCompleteDebounce": 350,
+            "blade": { "directiveSpacing": true }
+        });
+        let editor = serde_json::json!({
+            "autoCompleteDebounce": 100
+        });
+
+        let merged = merge_settings(&project, &editor);
+        assert_eq!(merged["autoCompleteDebounce"], 100);
+        // Nested project values survive when the editor doesn't set them
+        assert_eq!(merged["blade"]["directiveSpacing"], true);
+
+        // Null editor settings keep the project file as-is
+        assert_eq!(merge_settings(&project, &serde_json::Value::Null), project);
+    }
+
+    #[test]
+    fn test_load_project_settings_missing_file() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(load_project_settings(dir.path()).is_none());
+
+        std::fs::write(dir.path().join(PROJECT_SETTINGS_FILE), "autoCompleteDebounce = 50\n").unwrap();
+        let value = load_project_settings(dir.path()).unwrap();
+        assert_eq!(value["autoCompleteDebounce"], 50);
+    }
+
+    #[test]
+    fn test_file_kind_defaults() {
+        let settings = FileTypeSettings::default();
+        assert_eq!(FileKind::from_path("/app/resources/views/home.blade.php", &settings), FileKind::Blade);
+        assert_eq!(FileKind::from_path("/app/app/Models/User.php", &settings), FileKind::Php);
+        assert_eq!(FileKind::from_path("/app/.env", &settings), FileKind::Other);
+        assert_eq!(FileKind::from_path("/app/resources/js/app.js", &settings), FileKind::Other);
+        // A bare ".php" file name has no stem
+        assert_eq!(FileKind::from_path("/app/.php", &settings), FileKind::Other);
+    }
+
+    #[test]
+    fn test_file_kind_custom_extensions() {
+        let settings: FileTypeSettings = serde_json::from_value(serde_json::json!({
+            "bladeExtensions": [".blade.php", "antlers.php"],
+            "phpExtensions": [".php", ".inc"]
+        }))
+        .unwrap();
+
+        assert_eq!(FileKind::from_path("views/home.antlers.php", &settings), FileKind::Blade);
+        assert_eq!(FileKind::from_path("views/home.blade.php", &settings), FileKind::Blade);
+        assert_eq!(FileKind::from_path("lib/helpers.inc", &settings), FileKind::Php);
+        assert_eq!(FileKind::from_path("lib/helpers.php", &settings), FileKind::Php);
+        // "xantlers.php" shares the suffix but not the extension boundary
+        assert_eq!(FileKind::from_path("views/xantlers.php", &settings), FileKind::Php);
+
+        // Omitted keys fall back to the defaults
+        let settings: FileTypeSettings = serde_json::from_value(serde_json::json!({})).unwrap();
+        assert_eq!(settings, FileTypeSettings::default());
+    }
+
+    #[test]
+    fn test_laravel_paths_defaults() {
+        let root = Path::new("/project");
+        let paths = LaravelPaths::from_sources(root, None, None);
+
+        assert_eq!(paths.app_namespace, "App");
+        assert_eq!(paths.controllers_dir, root.join("app/Http/Controllers"));
+        assert_eq!(paths.livewire_dir, root.join("app/Livewire"));
+        assert_eq!(paths.components_dir, root.join("app/View/Components"));
+        assert_eq!(paths.providers_dir, root.join("app/Providers"));
+        assert_eq!(paths.class_file("App\\Models\\User"), Some(root.join("app/Models/User.php")));
+        assert_eq!(paths.class_file("Vendor\\Package\\Thing"), None);
+    }
+
+    #[test]
+    fn test_laravel_paths_remapped_namespace() {
+        let root = Path::new("/project");
+        let composer = r#"{
+            "autoload": {
+                "psr-4": {
+                    "Acme\\": "src/",
+                    "Acme\\Domain\\": ["domain/", "legacy/domain/"],
+                    "Database\\Factories\\": "database/factories/"
+                }
+            },
+            "autoload-dev": {
+                "psr-4": { "Tests\\": "tests/" }
+            }
+        }"#;
+        let livewire = "<?php\n\nreturn [\n    'class_namespace' => 'Acme\\\\Http\\\\Livewire',\n];\n";
+        let paths = LaravelPaths::from_sources(root, Some(composer), Some(livewire));
+
+        assert_eq!(paths.app_namespace, "Acme");
+        assert_eq!(paths.app_dir, root.join("src"));
+        assert_eq!(paths.controllers_dir, root.join("src/Http/Controllers"));
+        assert_eq!(paths.components_dir, root.join("src/View/Components"));
+        assert_eq!(paths.providers_dir, root.join("src/Providers"));
+        assert_eq!(paths.livewire_dir, root.join("src/Http/Livewire"));
+
+        // The longest matching prefix wins
+        assert_eq!(paths.class_file("Acme\\Domain\\Order"), Some(root.join("domain/Order.php")));
+        assert_eq!(paths.class_file("\\Acme\\Models\\User"), Some(root.join("src/Models/User.php")));
+        assert_eq!(paths.class_file("Tests\\Feature\\ExampleTest"), Some(root.join("tests/Feature/ExampleTest.php")));
+        assert_eq!(paths.class_file("App\\Models\\User"), None);
+
+        assert_eq!(paths.dir_namespace(&root.join("src/View/Components/Forms")).as_deref(), Some("Acme\\View\\Components\\Forms"));
+        assert_eq!(paths.dir_namespace(&root.join("domain")).as_deref(), Some("Acme\\Domain"));
+        assert_eq!(paths.dir_namespace(&root.join("resources/views")), None);
+    }
+
+    #[test]
+    fn test_laravel_paths_app_dir_keeps_renamed_namespace() {
+        let root = Path::new("/project");
+        let composer = r#"{"autoload": {"psr-4": {"Database\\Seeders\\": "database/seeders/", "Shop\\": "app/"}}}"#;
+        let paths = LaravelPaths::from_sources(root, Some(composer), None);
+
+        assert_eq!(paths.app_namespace, "Shop");
+        assert_eq!(paths.app_dir, root.join("app"));
+        assert_eq!(paths.class_file("Shop\\Http\\Controllers\\HomeController"), Some(root.join("app/Http/Controllers/HomeController.php")));
+    }
+
+    #[test]
+    fn test_discover_modules() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let composer = include_str!("../tests/fixtures/modules/composer.json");
+        for (path, content) in [
+            ("modules_statuses.json", include_str!("../tests/fixtures/modules/modules_statuses.json")),
+            ("Modules/Blog/module.json", include_str!("../tests/fixtures/modules/module.json")),
+            ("Modules/Blog/composer.json", include_str!("../tests/fixtures/modules/module-composer.json")),
+            ("Modules/Shop/module.json", "{\"name\": \"Shop\"}"),
+        ] {
+            std::fs::create_dir_all(root.join(path).parent().unwrap()).unwrap();
+            std::fs::write(root.join(path), content).unwrap();
+        }
+        std::fs::create_dir_all(root.join("Modules/Blog/resources/views")).unwrap();
+
+        // Without the package there are no modules
+        assert!(discover_modules(root, Some(r#"{"require": {"laravel/framework": "^11.0"}}"#)).is_empty());
+
+        // Shop is disabled in modules_statuses.json
+        let modules = discover_modules(root, Some(composer));
+        assert_eq!(modules.len(), 1);
+        let blog = &modules[0];
+        assert_eq!(blog.name, "Blog");
+        assert_eq!(blog.view_namespace, "blog");
+        assert_eq!(blog.namespace, "Modules\\Blog");
+        assert_eq!(blog.class_dir, root.join("Modules/Blog/app"));
+        assert_eq!(blog.view_dir, root.join("Modules/Blog/resources/views"));
+        assert_eq!(blog.controllers_dir(), root.join("Modules/Blog/app/Http/Controllers"));
+
+        let paths = LaravelPaths::from_sources(root, Some(composer), None).with_modules(modules);
+        assert_eq!(
+            paths.class_file("Modules\\Blog\\Http\\Controllers\\PostController"),
+            Some(root.join("Modules/Blog/app/Http/Controllers/PostController.php"))
+        );
+        assert_eq!(paths.class_file("App\\Models\\User"), Some(root.join("app/Models/User.php")));
+    }
+
+    #[test]
+    fn test_asset_validation_settings() {
+        let settings = AssetValidationSettings::default();
+        assert!(settings.validates("asset"));
+        assert!(settings.validates("@vite"));
+
+        let settings: AssetValidationSettings = serde_json::from_value(serde_json::json!({
+            "validateAssets": false
+        }))
+        .unwrap();
+        assert!(!settings.validates("asset"));
+        assert!(!settings.validates("mix"));
+
+        let settings: AssetValidationSettings = serde_json::from_value(serde_json::json!({
+            "assetHelpers": ["mix", "vite"]
+        }))
+        .unwrap();
+        assert!(settings.validates("mix"));
+        assert!(settings.validates("@vite"));
+        assert!(!settings.validates("asset"));
+
+        // Disabling wins over the allow-list
+        let settings: AssetValidationSettings = serde_json::from_value(serde_json::json!({
+            "validateAssets": false,
+            "assetHelpers": ["mix"]
+        }))
+        .unwrap();
+        assert!(!settings.validates("mix"));
+    }
+
     #[test]
     fn test_extract_base_path() {
         let line = "base_path('resources/templates'),";
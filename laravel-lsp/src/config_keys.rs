@@ -0,0 +1,255 @@
+//! Keys of PHP config files
+//!
+//! Laravel config files return a (possibly nested) array literal:
+//! `return ['name' => env('APP_NAME', 'Laravel'), 'connections' => [...]]`.
+//! This walks those `'key' => value` entries so `config('app.name')` can be
+//! completed, validated and navigated without evaluating any PHP.
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+/// A `'key' => value` entry of a PHP config file
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ConfigEntry {
+    /// Key path inside the file (e.g. `["connections", "mysql", "host"]`)
+    pub path: Vec<String>,
+    /// Line of the key
+    pub line: u32,
+    /// Column of the key, inside the quotes
+    pub column: u32,
+    /// Text after `=>` on the key's line (or the next line), trimmed
+    pub value: String,
+    /// Whether the value is a nested array
+    pub is_array: bool,
+}
+
+impl ConfigEntry {
+    /// The entry's own key, without its parents
+    pub fn key(&self) -> &str {
+        self.path.last().map_or("", String::as_str)
+    }
+}
+
+lazy_static! {
+    static ref ARROW_RE: Regex = Regex::new(r"^\s*=>\s*").unwrap();
+}
+
+/// Walk the `'key' => value` entries of a PHP config file
+///
+/// Every entry at every depth is returned with its key path and the position
+/// of its key (first character inside the quotes), including several keys on
+/// one line (`'guards' => ['web' => [...], 'api' => [...]]`). Brackets inside
+/// string literals are ignored, and `'key' =>` with the `[` on the next line
+/// still opens a nested array.
+pub fn parse_config_entries(content: &str) -> Vec<ConfigEntry> {
+    let mut entries: Vec<ConfigEntry> = Vec::new();
+    // Open nested arrays as (key, bracket depth just outside their `[`)
+    let mut stack: Vec<(String, i32)> = Vec::new();
+    // Entry whose value didn't start on its own line
+    let mut pending: Option<usize> = None;
+    let mut depth: i32 = 0;
+
+    for (line_idx, line) in content.lines().enumerate() {
+        let trimmed = line.trim();
+
+        // Skip comments and empty lines
+        if trimmed.is_empty()
+            || trimmed.starts_with("//")
+            || trimmed.starts_with("/*")
+            || trimmed.starts_with('*')
+            || trimmed.starts_with('#')
+        {
+            continue;
+        }
+
+        // 'key' => on one line, [ on the next
+        if let Some(index) = pending.take() {
+            if trimmed.starts_with('[') {
+                entries[index].is_array = true;
+                stack.push((entries[index].key().to_string(), depth));
+            } else {
+                entries[index].value = trimmed.to_string();
+            }
+        }
+
+        let bytes = line.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            match bytes[i] {
+                quote @ (b'\'' | b'"') => {
+                    let start = i + 1;
+                    i = start;
+                    while i < bytes.len() && bytes[i] != quote {
+                        if bytes[i] == b'\\' {
+                            i += 1;
+                        }
+                        i += 1;
+                    }
+                    let end = i.min(bytes.len());
+                    let key = &line[start..end];
+                    let rest = line.get(end + 1..).unwrap_or("");
+                    if let Some(arrow) = ARROW_RE.find(rest).filter(|_| !key.is_empty() && !key.contains(['\'', '"'])) {
+                        let value = rest[arrow.end()..].trim();
+                        let mut path: Vec<String> = stack.iter().map(|(k, _)| k.clone()).collect();
+                        path.push(key.to_string());
+                        let is_array = value.starts_with('[');
+                        entries.push(ConfigEntry {
+                            path,
+                            line: line_idx as u32,
+                            column: start as u32,
+                            value: value.to_string(),
+                            is_array,
+                        });
+
+                        if is_array {
+                            stack.push((key.to_string(), depth));
+                        } else if value.is_empty() {
+                            pending = Some(entries.len() - 1);
+                        }
+                    }
+                }
+                b'/' if bytes.get(i + 1) == Some(&b'/') => break,
+                b'#' if bytes.get(i + 1) != Some(&b'[') => break,
+                b'[' => depth += 1,
+                b']' => {
+                    depth -= 1;
+                    // Pop the array this bracket closed
+                    while stack.last().is_some_and(|(_, opened_at)| *opened_at >= depth) {
+                        stack.pop();
+                    }
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+    }
+
+    entries
+}
+
+/// Whether a dotted key (without the file name) resolves in a config file
+///
+/// `env()`-backed keys exist like any other. Keys below a computed value
+/// (`array_merge(...)`, a ternary, ...) or an array no keys could be read
+/// from (a list, a spread) can't be seen statically, so they're assumed to
+/// exist, as is everything in a file no keys could be read from.
+pub fn config_key_resolves(entries: &[ConfigEntry], key: &str) -> bool {
+    if entries.is_empty() {
+        return true;
+    }
+    let path: Vec<&str> = key.split('.').collect();
+    entries.iter().any(|entry| {
+        let depth = entry.path.len();
+        if depth > path.len() || entry.path.iter().zip(&path).any(|(a, b)| a != b) {
+            return false;
+        }
+        if depth == path.len() {
+            return true;
+        }
+        if entry.is_array {
+            !entries.iter().any(|child| child.path.len() > depth && child.path.starts_with(&entry.path))
+        } else {
+            !is_literal_scalar(&entry.value)
+        }
+    })
+}
+
+/// Whether a value is a string, number or constant that can't hold nested keys
+fn is_literal_scalar(value: &str) -> bool {
+    let value = value.trim_end_matches(',').trim();
+    value.starts_with(['\'', '"'])
+        || value.starts_with(|c: char| c.is_ascii_digit() || c == '-')
+        || ["true", "false", "null"].contains(&value.to_ascii_lowercase().as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DATABASE: &str = "<?php\n\nreturn [\n    'default' => env('DB_CONNECTION', 'sqlite'),\n\n    'connections' => [\n        'mysql' => [\n            'host' => env('DB_HOST'),\n            'options' => extension_loaded('pdo_mysql') ? array_filter([\n                PDO::MYSQL_ATTR_SSL_CA => env('MYSQL_ATTR_SSL_CA'),\n            ]) : [],\n        ],\n    ],\n\n    'redis' =>\n        [\n            'client' => 'phpredis',\n        ],\n];\n";
+
+    fn keys(content: &str) -> Vec<String> {
+        parse_config_entries(content).iter().map(|entry| entry.path.join(".")).collect()
+    }
+
+    #[test]
+    fn test_nested_keys_at_every_depth() {
+        assert_eq!(keys(DATABASE), vec![
+            "default",
+            "connections",
+            "connections.mysql",
+            "connections.mysql.host",
+            "connections.mysql.options",
+            "redis",
+            "redis.client",
+        ]);
+
+        let entries = parse_config_entries(DATABASE);
+        let host = entries.iter().find(|entry| entry.key() == "host").unwrap();
+        assert_eq!((host.line, host.column), (7, 13));
+        assert!(!host.is_array);
+        // `'redis' =>` with its `[` on the next line is still an array
+        assert!(entries.iter().find(|entry| entry.key() == "redis").unwrap().is_array);
+    }
+
+    #[test]
+    fn test_env_backed_keys_are_listed_with_their_value() {
+        let entries = parse_config_entries(DATABASE);
+        let default = entries.iter().find(|entry| entry.key() == "default").unwrap();
+        assert_eq!(default.value, "env('DB_CONNECTION', 'sqlite'),");
+    }
+
+    #[test]
+    fn test_dotted_keys_resolve_through_nested_arrays() {
+        let entries = parse_config_entries(DATABASE);
+        assert!(config_key_resolves(&entries, "default"));
+        assert!(config_key_resolves(&entries, "connections.mysql"));
+        assert!(config_key_resolves(&entries, "connections.mysql.host"));
+        assert!(config_key_resolves(&entries, "redis.client"));
+
+        assert!(!config_key_resolves(&entries, "nonexistent"));
+        assert!(!config_key_resolves(&entries, "connections.pgsql.host"));
+        assert!(!config_key_resolves(&entries, "redis.client.name"));
+        // Computed values may hold anything
+        assert!(config_key_resolves(&entries, "connections.mysql.options.timeout"));
+        // Nothing to check against
+        assert!(config_key_resolves(&[], "anything"));
+    }
+
+    #[test]
+    fn test_inline_arrays_list_every_key() {
+        let content = "<?php\nreturn [\n    'guards' => ['web' => ['driver' => 'session'], 'api' => ['driver' => 'token']],\n    'defaults' => [\n        'guard' => 'web', 'passwords' => 'users',\n    ],\n    'name' => 'App',\n];\n";
+        assert_eq!(keys(content), vec![
+            "guards",
+            "guards.web",
+            "guards.web.driver",
+            "guards.api",
+            "guards.api.driver",
+            "defaults",
+            "defaults.guard",
+            "defaults.passwords",
+            "name",
+        ]);
+
+        let entries = parse_config_entries(content);
+        let api = entries.iter().find(|entry| entry.key() == "api").unwrap();
+        assert_eq!((api.line, api.column), (2, 52));
+        assert!(config_key_resolves(&entries, "defaults.passwords"));
+        assert!(!config_key_resolves(&entries, "guards.admin"));
+    }
+
+    #[test]
+    fn test_keys_below_unkeyed_arrays_resolve() {
+        let content = "<?php\nreturn [\n    'providers' => [\n        App\\Providers\\AppServiceProvider::class,\n    ],\n    'extra' => [...$defaults],\n    'name' => 'App',\n];\n";
+        let entries = parse_config_entries(content);
+        assert!(config_key_resolves(&entries, "providers.0"));
+        assert!(config_key_resolves(&entries, "extra.anything"));
+        assert!(!config_key_resolves(&entries, "name.first"));
+    }
+
+    #[test]
+    fn test_brackets_in_strings_and_comments_dont_nest() {
+        let content = "<?php\nreturn [\n    // 'commented' => [\n    'pattern' => '/^[a-z]+$/',\n    'name' => 'App',\n];\n";
+        assert_eq!(keys(content), vec!["pattern", "name"]);
+    }
+}
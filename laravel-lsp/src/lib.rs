@@ -7,11 +7,16 @@
 pub mod parser;
 pub mod queries;
 pub mod config;
+pub mod config_keys;
+pub mod blade_props;
 pub mod middleware_parser;
 pub mod cache_manager;
 pub mod validation_rules;
 pub mod database;
 pub mod model_analyzer;
+pub mod raw_echo;
+pub mod livewire_events;
+pub mod suppressions;
 
 // Salsa 0.25 implementation (incremental computation)
 pub mod salsa_impl;
@@ -0,0 +1,164 @@
+//! Livewire event dispatches and the listeners they reach
+//!
+//! `$this->dispatch('saved')` (or `$dispatch('saved')` in a view) is heard by
+//! every component with `#[On('saved')]` or a `'saved'` entry in `$listeners`.
+//! `dispatchTo('cart', 'saved')` only reaches the named component. Matching is
+//! by event name only - no call-graph analysis.
+
+/// An event name passed to `dispatch()` / `dispatchTo()`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventDispatch {
+    pub event: String,
+    /// Component named by `dispatchTo()`, if the event is scoped to one
+    pub target: Option<String>,
+    /// Position of the event name, inside the quotes
+    pub line: u32,
+    pub column: u32,
+    pub end_column: u32,
+}
+
+/// An event a component listens for
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventListener {
+    pub event: String,
+    /// Position of the event name, inside the quotes
+    pub line: u32,
+    pub column: u32,
+    pub end_column: u32,
+}
+
+/// Find `dispatch()`, `dispatchTo()` and `$dispatch()` calls in PHP or Blade source
+pub fn find_event_dispatches(source: &str) -> Vec<EventDispatch> {
+    use lazy_static::lazy_static;
+    use regex::Regex;
+
+    lazy_static! {
+        // ->dispatch('saved') / $dispatch('saved') / ->dispatchTo('cart', 'saved')
+        static ref DISPATCH: Regex = Regex::new(
+            r#"(?:->|\$)dispatch(?:To\s*\(\s*(['"])([^'"]+)['"]\s*,|\s*\()\s*(['"])([^'"]+)['"]"#
+        ).unwrap();
+    }
+
+    DISPATCH
+        .captures_iter(source)
+        .filter_map(|caps| {
+            let event = caps.get(4)?;
+            let (line, column) = line_column(source, event.start());
+            Some(EventDispatch {
+                event: event.as_str().to_string(),
+                target: caps.get(2).map(|t| t.as_str().to_string()),
+                line,
+                column,
+                end_column: column + event.as_str().len() as u32,
+            })
+        })
+        .collect()
+}
+
+/// Find the events a Livewire component class listens for
+///
+/// Covers `#[On('saved')]` attributes (Livewire 3) and the keys or bare
+/// entries of a `$listeners` array (`['saved' => 'refresh', 'deleted']`).
+pub fn find_event_listeners(source: &str) -> Vec<EventListener> {
+    use lazy_static::lazy_static;
+    use regex::Regex;
+
+    lazy_static! {
+        static ref ON_ATTRIBUTE: Regex = Regex::new(
+            r#"#\[\s*(?:\\?Livewire\\Attributes\\)?On\s*\(\s*(?:event\s*:\s*)?(['"])([^'"]+)['"]"#
+        ).unwrap();
+        static ref LISTENERS_ARRAY: Regex = Regex::new(
+            r"\$listeners\s*=\s*(?:\[|array\s*\()"
+        ).unwrap();
+        static ref QUOTED: Regex = Regex::new(r#"'([^']*)'|"([^"]*)""#).unwrap();
+    }
+
+    let mut listeners: Vec<EventListener> = ON_ATTRIBUTE
+        .captures_iter(source)
+        .filter_map(|caps| caps.get(2))
+        .map(|event| listener_at(source, event.start(), event.as_str()))
+        .collect();
+
+    if let Some(open) = LISTENERS_ARRAY.find(source) {
+        let body_start = open.end();
+        let body_end = source[body_start..]
+            .find(';')
+            .map_or(source.len(), |end| body_start + end);
+        let body = &source[body_start..body_end];
+
+        for caps in QUOTED.captures_iter(body) {
+            let Some(name) = caps.get(1).or_else(|| caps.get(2)) else {
+                continue;
+            };
+            // 'saved' => 'refresh' - the value is a method, not an event
+            let whole = caps.get(0).unwrap();
+            if body[..whole.start()].trim_end().ends_with("=>") {
+                continue;
+            }
+            listeners.push(listener_at(source, body_start + name.start(), name.as_str()));
+        }
+    }
+
+    listeners.sort_by_key(|l| (l.line, l.column));
+    listeners
+}
+
+fn listener_at(source: &str, offset: usize, event: &str) -> EventListener {
+    let (line, column) = line_column(source, offset);
+    EventListener {
+        event: event.to_string(),
+        line,
+        column,
+        end_column: column + event.len() as u32,
+    }
+}
+
+/// 0-indexed line and byte column of an offset
+fn line_column(source: &str, offset: usize) -> (u32, u32) {
+    let before = &source[..offset];
+    let line = before.matches('\n').count() as u32;
+    let column = before.rfind('\n').map_or(offset, |nl| offset - nl - 1) as u32;
+    (line, column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_finds_dispatches_and_scoped_dispatches() {
+        let content = include_str!("../tests/fixtures/livewire-events/app/Livewire/EditPost.php");
+        let dispatches = find_event_dispatches(content);
+
+        let events: Vec<(&str, Option<&str>)> = dispatches
+            .iter()
+            .map(|d| (d.event.as_str(), d.target.as_deref()))
+            .collect();
+        assert_eq!(events, vec![("post-saved", None), ("post-saved", Some("sidebar"))]);
+
+        for dispatch in &dispatches {
+            let line = content.lines().nth(dispatch.line as usize).unwrap();
+            assert_eq!(&line[dispatch.column as usize..dispatch.end_column as usize], dispatch.event);
+        }
+    }
+
+    #[test]
+    fn test_finds_blade_dispatch() {
+        let dispatches = find_event_dispatches(r#"<button wire:click="$dispatch('post-saved')">Save</button>"#);
+        assert_eq!(dispatches.len(), 1);
+        assert_eq!(dispatches[0].event, "post-saved");
+        assert_eq!(dispatches[0].column, 31);
+    }
+
+    #[test]
+    fn test_finds_attribute_and_array_listeners() {
+        let content = include_str!("../tests/fixtures/livewire-events/app/Livewire/PostList.php");
+        let events: Vec<String> = find_event_listeners(content).into_iter().map(|l| l.event).collect();
+        assert_eq!(events, vec!["post-saved"]);
+
+        let content = include_str!("../tests/fixtures/livewire-events/app/Livewire/Sidebar.php");
+        let events: Vec<String> = find_event_listeners(content).into_iter().map(|l| l.event).collect();
+        // Array values ('refreshCounts') are handler methods, not events
+        assert_eq!(events, vec!["post-saved", "post-deleted"]);
+    }
+}
@@ -33,6 +33,56 @@ pub fn resolve_class_to_file(class_name: &str, root_path: &Path) -> Option<PathB
     None
 }
 
+/// Resolve a class name as written in a PHP file to its fully qualified name
+///
+/// Uses the file's `use` imports (including `as` aliases), then its `namespace`
+/// for unqualified names. A leading backslash means the name is already fully qualified.
+///
+/// e.g., `User` with `use App\Models\User;` → `App\Models\User`
+pub fn resolve_imported_class(content: &str, class_name: &str) -> String {
+    use regex::Regex;
+    use lazy_static::lazy_static;
+
+    lazy_static! {
+        // use App\Models\User; / use App\Models\User as Member; / use App\Models;
+        static ref USE_RE: Regex = Regex::new(
+            r"(?m)^\s*use\s+\\?([A-Za-z0-9_\\]+?)(?:\s+as\s+([A-Za-z0-9_]+))?\s*;"
+        ).unwrap();
+        static ref NAMESPACE_RE: Regex = Regex::new(
+            r"(?m)^\s*namespace\s+([A-Za-z0-9_\\]+)\s*;"
+        ).unwrap();
+    }
+
+    if let Some(qualified) = class_name.strip_prefix('\\') {
+        return qualified.to_string();
+    }
+
+    // The first segment may be imported: `Models\User` with `use App\Models;`
+    let (head, rest) = match class_name.split_once('\\') {
+        Some((head, rest)) => (head, Some(rest)),
+        None => (class_name, None),
+    };
+
+    for caps in USE_RE.captures_iter(content) {
+        let imported = &caps[1];
+        let alias = caps
+            .get(2)
+            .map(|m| m.as_str())
+            .unwrap_or_else(|| imported.rsplit('\\').next().unwrap_or(imported));
+        if alias == head {
+            return match rest {
+                Some(rest) => format!("{}\\{}", imported, rest),
+                None => imported.to_string(),
+            };
+        }
+    }
+
+    match NAMESPACE_RE.captures(content) {
+        Some(caps) => format!("{}\\{}", &caps[1], class_name),
+        None => class_name.to_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
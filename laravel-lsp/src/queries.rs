@@ -91,6 +91,8 @@ pub struct ViewMatch<'a> {
     pub end_column: usize,
     /// Whether this is from Route::view() or Volt::route() (should be ERROR if missing)
     pub is_route_view: bool,
+    /// Whether this is from Volt::route() - the name is a Volt component, not a view
+    pub is_volt_route: bool,
 }
 
 /// Represents a matched Blade component (<x-*>)
@@ -122,6 +124,37 @@ pub struct LivewireMatch<'a> {
 pub struct SlotMatch<'a> {
     /// The slot name (e.g., "header" from <x-slot:header>)
     pub slot_name: &'a str,
+    /// The component the slot is passed to (e.g., "card" for a slot inside <x-card>)
+    pub component_name: Option<&'a str>,
+    pub byte_start: usize,
+    pub byte_end: usize,
+    pub row: usize,
+    pub column: usize,
+    pub end_column: usize,
+}
+
+/// Represents a section name in `@yield('content')` or `@section('content')`
+#[derive(Debug, Clone, PartialEq)]
+pub struct SectionMatch<'a> {
+    /// The section name (e.g., "content")
+    pub section_name: &'a str,
+    /// True for the layout's `@yield`, false for a view's `@section`
+    pub is_yield: bool,
+    pub byte_start: usize,
+    pub byte_end: usize,
+    pub row: usize,
+    pub column: usize,
+    pub end_column: usize,
+}
+
+/// Represents a stack name in `@stack('scripts')` or `@push('scripts')`
+/// (`@pushOnce`, `@prepend` and `@prependOnce` push too)
+#[derive(Debug, Clone, PartialEq)]
+pub struct StackMatch<'a> {
+    /// The stack name (e.g., "scripts")
+    pub stack_name: &'a str,
+    /// True for the layout's `@stack`, false for a view pushing onto it
+    pub is_stack: bool,
     pub byte_start: usize,
     pub byte_end: usize,
     pub row: usize,
@@ -142,6 +175,9 @@ pub struct DirectiveMatch<'a> {
     pub end_column: usize,
     pub string_column: usize,
     pub string_end_column: usize,
+    /// Where the arguments start, just inside the opening paren (row, column).
+    /// Multi-line arguments continue onto later rows from here.
+    pub arguments_start: Option<(usize, usize)>,
 }
 
 /// Represents a matched env() call in PHP code
@@ -165,6 +201,9 @@ pub struct ConfigMatch<'a> {
     pub row: usize,
     pub column: usize,
     pub end_column: usize,
+    /// True for the set forms (`config(['key' => value])`, `Config::set()`),
+    /// which may define keys that no config file declares
+    pub is_set: bool,
 }
 
 /// Represents a matched middleware call in PHP route definitions
@@ -187,6 +226,9 @@ pub struct TranslationMatch<'a> {
     pub row: usize,
     pub column: usize,
     pub end_column: usize,
+    /// True for `Lang::has()` / `Lang::hasForLocale()`, which only ask whether
+    /// the key exists - a missing key there may be intentional
+    pub is_existence_check: bool,
 }
 
 /// Represents a matched asset or path helper call
@@ -254,7 +296,10 @@ pub struct UrlMatch<'a> {
 /// Represents a matched action('Controller@method') call in PHP code
 #[derive(Debug, Clone, PartialEq)]
 pub struct ActionMatch<'a> {
+    /// The full `Controller@method` string, or the class of the tuple syntax
     pub action_name: &'a str,
+    /// The method of the tuple syntax `[UserController::class, 'show']`
+    pub method_name: Option<&'a str>,
     pub byte_start: usize,
     pub byte_end: usize,
     pub row: usize,
@@ -278,6 +323,128 @@ pub struct FeatureMatch<'a> {
     pub end_column: usize,
 }
 
+/// Represents a config-backed name reference in PHP code
+/// e.g., Queue::connection('redis') → entry 'redis' under queue.connections
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigNameMatch<'a> {
+    /// The referenced name (e.g., "redis")
+    pub name: &'a str,
+    /// Config array path that holds the valid names (e.g., "queue.connections")
+    pub config_path: &'static str,
+    pub byte_start: usize,
+    pub byte_end: usize,
+    pub row: usize,
+    pub column: usize,
+    pub end_column: usize,
+}
+
+/// Laravel's conditional attribute directives: `@checked($cond)`, `@selected`, ...
+///
+/// Their argument is a plain boolean expression with nothing to resolve, so
+/// they're recognized but never extracted as navigable directives.
+pub const ATTRIBUTE_DIRECTIVES: &[&str] = &["checked", "selected", "disabled", "readonly", "required"];
+
+/// A call whose string argument names an entry in a config array
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConfigNameReference {
+    /// Facade class (`Queue`) or helper function (`cache`) the method is called on
+    pub receiver: &'static str,
+    /// Whether the receiver is a helper function, i.e. `cache()->store(...)`
+    pub is_helper: bool,
+    /// The method taking the name (`connection`, `store`, ...)
+    pub method: &'static str,
+    /// Config array path holding the valid names (e.g., "queue.connections")
+    pub config_path: &'static str,
+}
+
+/// Table of config-backed name references
+///
+/// Pattern 31 in php.scm captures every facade/helper call with a string
+/// first argument, so adding a row here is all that's needed to get
+/// extraction, validation, goto, and completion.
+///
+/// `driver()` rows are the managers' aliases for their named entries. The
+/// session manager's drivers are built-in names, not config entries, so it
+/// has no row.
+pub const CONFIG_NAME_REFERENCES: &[ConfigNameReference] = &[
+    ConfigNameReference { receiver: "Queue", is_helper: false, method: "connection", config_path: "queue.connections" },
+    ConfigNameReference { receiver: "Cache", is_helper: false, method: "store", config_path: "cache.stores" },
+    ConfigNameReference { receiver: "Cache", is_helper: false, method: "driver", config_path: "cache.stores" },
+    ConfigNameReference { receiver: "cache", is_helper: true, method: "store", config_path: "cache.stores" },
+    ConfigNameReference { receiver: "Broadcast", is_helper: false, method: "connection", config_path: "broadcasting.connections" },
+    ConfigNameReference { receiver: "Broadcast", is_helper: false, method: "driver", config_path: "broadcasting.connections" },
+    ConfigNameReference { receiver: "Storage", is_helper: false, method: "disk", config_path: "filesystems.disks" },
+    ConfigNameReference { receiver: "DB", is_helper: false, method: "connection", config_path: "database.connections" },
+    ConfigNameReference { receiver: "Log", is_helper: false, method: "channel", config_path: "logging.channels" },
+    ConfigNameReference { receiver: "Log", is_helper: false, method: "driver", config_path: "logging.channels" },
+    ConfigNameReference { receiver: "Mail", is_helper: false, method: "mailer", config_path: "mail.mailers" },
+    ConfigNameReference { receiver: "Auth", is_helper: false, method: "guard", config_path: "auth.guards" },
+    ConfigNameReference { receiver: "auth", is_helper: true, method: "guard", config_path: "auth.guards" },
+    ConfigNameReference { receiver: "Password", is_helper: false, method: "broker", config_path: "auth.passwords" },
+];
+
+/// Look up the config path for a receiver/method pair
+pub fn find_config_name_reference(receiver: &str, method: &str) -> Option<&'static ConfigNameReference> {
+    CONFIG_NAME_REFERENCES
+        .iter()
+        .find(|r| r.receiver == receiver && r.method == method)
+}
+/// Represents an authorization ability checked in PHP code
+/// e.g., $this->authorize('update', $post), Gate::allows('view-admin')
+#[derive(Debug, Clone, PartialEq)]
+pub struct AbilityMatch<'a> {
+    /// The ability name (e.g., "update" or "view-admin")
+    pub ability: &'a str,
+    /// The argument after the ability, as written (e.g., "$post" or "Post::class")
+    pub subject: Option<&'a str>,
+    pub byte_start: usize,
+    pub byte_end: usize,
+    pub row: usize,
+    pub column: usize,
+    pub end_column: usize,
+}
+
+/// Represents the model class heading an Eloquent query in PHP code
+/// e.g., User::query(), User::where('active', 1)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelMatch<'a> {
+    /// The class name as written (e.g., `User` or `\App\Models\User`)
+    pub class_name: &'a str,
+    pub byte_start: usize,
+    pub byte_end: usize,
+    pub row: usize,
+    pub column: usize,
+    pub end_column: usize,
+}
+
+/// Represents a custom validation rule object in a rules array
+/// e.g., 'title' => ['required', new Uppercase]
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuleClassMatch<'a> {
+    /// The class name as written (e.g., `Uppercase` or `\App\Rules\Uppercase`)
+    pub class_name: &'a str,
+    pub byte_start: usize,
+    pub byte_end: usize,
+    pub row: usize,
+    pub column: usize,
+    pub end_column: usize,
+}
+
+/// Represents an Artisan command reference in PHP code
+/// e.g., Artisan::call('mail:send'), $schedule->command(BackupCommand::class)
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommandMatch<'a> {
+    /// The command name (e.g., "mail:send") or class name as written
+    pub command: &'a str,
+    /// Whether this is a `::class` reference rather than a command name
+    pub is_class_reference: bool,
+    pub byte_start: usize,
+    pub byte_end: usize,
+    pub row: usize,
+    pub column: usize,
+    pub end_column: usize,
+}
+
 // ============================================================================
 // Extracted Patterns - Result structs for single-pass extraction
 // ============================================================================
@@ -296,12 +463,19 @@ pub struct ExtractedPhpPatterns<'a> {
     pub url_calls: Vec<UrlMatch<'a>>,
     pub action_calls: Vec<ActionMatch<'a>>,
     pub feature_calls: Vec<FeatureMatch<'a>>,
+    pub config_name_calls: Vec<ConfigNameMatch<'a>>,
+    pub model_calls: Vec<ModelMatch<'a>>,
+    pub ability_calls: Vec<AbilityMatch<'a>>,
+    pub rule_classes: Vec<RuleClassMatch<'a>>,
+    pub command_calls: Vec<CommandMatch<'a>>,
 }
 
 /// Represents PHP content inside Blade echo statements {{ ... }}
 #[derive(Debug, Clone, PartialEq)]
 pub struct EchoPhpMatch<'a> {
     pub php_content: &'a str,
+    /// Raw `{!! ... !!}` echo rather than escaped `{{ ... }}`
+    pub is_raw: bool,
     pub byte_start: usize,
     pub byte_end: usize,
     pub row: usize,
@@ -319,6 +493,81 @@ pub struct ExtractedBladePatterns<'a> {
     pub echo_php: Vec<EchoPhpMatch<'a>>,
     /// Slot tags (<x-slot:name> or <x-slot name="...">)
     pub slots: Vec<SlotMatch<'a>>,
+    /// @yield/@section names
+    pub sections: Vec<SectionMatch<'a>>,
+    /// @stack/@push names
+    pub stacks: Vec<StackMatch<'a>>,
+}
+
+impl<'a> ExtractedPhpPatterns<'a> {
+    /// Add patterns found in the error-free prefix that the full parse missed
+    fn merge_recovered(&mut self, recovered: Self) {
+        merge_by_start(&mut self.views, recovered.views, |m| m.byte_start);
+        merge_by_start(&mut self.env_calls, recovered.env_calls, |m| m.byte_start);
+        merge_by_start(&mut self.config_calls, recovered.config_calls, |m| m.byte_start);
+        merge_by_start(&mut self.middleware_calls, recovered.middleware_calls, |m| m.byte_start);
+        merge_by_start(&mut self.translation_calls, recovered.translation_calls, |m| m.byte_start);
+        merge_by_start(&mut self.asset_calls, recovered.asset_calls, |m| m.byte_start);
+        merge_by_start(&mut self.binding_calls, recovered.binding_calls, |m| m.byte_start);
+        merge_by_start(&mut self.route_calls, recovered.route_calls, |m| m.byte_start);
+        merge_by_start(&mut self.url_calls, recovered.url_calls, |m| m.byte_start);
+        merge_by_start(&mut self.action_calls, recovered.action_calls, |m| m.byte_start);
+        merge_by_start(&mut self.feature_calls, recovered.feature_calls, |m| m.byte_start);
+        merge_by_start(&mut self.config_name_calls, recovered.config_name_calls, |m| m.byte_start);
+        merge_by_start(&mut self.model_calls, recovered.model_calls, |m| m.byte_start);
+        merge_by_start(&mut self.ability_calls, recovered.ability_calls, |m| m.byte_start);
+        merge_by_start(&mut self.rule_classes, recovered.rule_classes, |m| m.byte_start);
+        merge_by_start(&mut self.command_calls, recovered.command_calls, |m| m.byte_start);
+    }
+
+    /// Move matches from a snippet parsed on its own to where the snippet sits in
+    /// the enclosing file. `prefix_len` bytes (e.g. `<?php `) were prepended to the
+    /// snippet before parsing; `byte_start`/`row`/`column` locate it in the file.
+    pub fn relocate(&mut self, prefix_len: usize, byte_start: usize, row: usize, column: usize) {
+        macro_rules! shift {
+            ($($field:ident),*) => {$(
+                for m in self.$field.iter_mut() {
+                    m.byte_start = m.byte_start + byte_start - prefix_len;
+                    m.byte_end = m.byte_end + byte_start - prefix_len;
+                    if m.row == 0 {
+                        m.column = m.column + column - prefix_len;
+                        m.end_column = m.end_column + column - prefix_len;
+                    }
+                    m.row += row;
+                }
+            )*};
+        }
+
+        shift!(
+            views, env_calls, config_calls, middleware_calls, translation_calls,
+            asset_calls, binding_calls, route_calls, url_calls, action_calls,
+            feature_calls, config_name_calls, model_calls, ability_calls, rule_classes,
+            command_calls
+        );
+    }
+}
+
+impl<'a> ExtractedBladePatterns<'a> {
+    /// Add patterns found in the error-free prefix that the full parse missed
+    fn merge_recovered(&mut self, recovered: Self) {
+        merge_by_start(&mut self.components, recovered.components, |m| m.byte_start);
+        merge_by_start(&mut self.livewire, recovered.livewire, |m| m.byte_start);
+        merge_by_start(&mut self.directives, recovered.directives, |m| m.byte_start);
+        merge_by_start(&mut self.echo_php, recovered.echo_php, |m| m.byte_start);
+        merge_by_start(&mut self.slots, recovered.slots, |m| m.byte_start);
+        merge_by_start(&mut self.sections, recovered.sections, |m| m.byte_start);
+        merge_by_start(&mut self.stacks, recovered.stacks, |m| m.byte_start);
+    }
+}
+
+/// Append recovered matches not already present (by start byte), keeping document order
+fn merge_by_start<T>(into: &mut Vec<T>, recovered: Vec<T>, start: fn(&T) -> usize) {
+    let known: std::collections::HashSet<usize> = into.iter().map(start).collect();
+    let before = into.len();
+    into.extend(recovered.into_iter().filter(|m| !known.contains(&start(m))));
+    if into.len() > before {
+        into.sort_by_key(start);
+    }
 }
 
 // ============================================================================
@@ -329,10 +578,35 @@ pub struct ExtractedBladePatterns<'a> {
 ///
 /// This is the primary extraction function - it runs one query and processes
 /// all captures in a single loop, dispatching based on capture name.
+///
+/// When the file has a syntax error (typically something half-typed), the
+/// lines before the error are re-parsed on their own so patterns that error
+/// recovery swallowed are still found.
 pub fn extract_all_php_patterns<'a>(
     tree: &Tree,
     source: &'a str,
     language: &Language,
+) -> Result<ExtractedPhpPatterns<'a>> {
+    let mut result = extract_php_captures(tree, source, language)?;
+
+    if let Some(prefix_len) = error_free_prefix_len(tree, source) {
+        let prefix = &source[..prefix_len];
+        match crate::parser::parse_php(prefix) {
+            Ok(prefix_tree) => {
+                let recovered = extract_php_captures(&prefix_tree, prefix, language)?;
+                result.merge_recovered(recovered);
+            }
+            Err(e) => warn!("Failed to re-parse PHP before syntax error: {}", e),
+        }
+    }
+
+    Ok(result)
+}
+
+fn extract_php_captures<'a>(
+    tree: &Tree,
+    source: &'a str,
+    language: &Language,
 ) -> Result<ExtractedPhpPatterns<'a>> {
     let start = Instant::now();
     let query = get_php_query(language)?;
@@ -350,6 +624,11 @@ pub fn extract_all_php_patterns<'a>(
         let capture_name = query.capture_names()[capture.index as usize];
         let node = capture.node;
 
+        // Half-typed code parses to ERROR/MISSING nodes that aren't real references
+        if node.is_error() || node.is_missing() {
+            continue;
+        }
+
         // Skip if we can't get the text
         let Ok(text) = node.utf8_text(source_bytes) else {
             continue;
@@ -369,6 +648,7 @@ pub fn extract_all_php_patterns<'a>(
                     column: start_pos.column,
                     end_column: end_pos.column,
                     is_route_view: false,
+                    is_volt_route: false,
                 });
             }
             "route_view_name" => {
@@ -380,6 +660,19 @@ pub fn extract_all_php_patterns<'a>(
                     column: start_pos.column,
                     end_column: end_pos.column,
                     is_route_view: true,
+                    is_volt_route: false,
+                });
+            }
+            "volt_route_name" => {
+                result.views.push(ViewMatch {
+                    view_name: text,
+                    byte_start: node.start_byte(),
+                    byte_end: node.end_byte(),
+                    row: start_pos.row,
+                    column: start_pos.column,
+                    end_column: end_pos.column,
+                    is_route_view: true,
+                    is_volt_route: true,
                 });
             }
 
@@ -399,7 +692,7 @@ pub fn extract_all_php_patterns<'a>(
             }
 
             // Config patterns
-            "config_key" => {
+            "config_key" | "config_set_key" => {
                 result.config_calls.push(ConfigMatch {
                     config_key: text,
                     byte_start: node.start_byte(),
@@ -407,6 +700,7 @@ pub fn extract_all_php_patterns<'a>(
                     row: start_pos.row,
                     column: start_pos.column,
                     end_column: end_pos.column,
+                    is_set: capture_name == "config_set_key",
                 });
             }
 
@@ -431,6 +725,7 @@ pub fn extract_all_php_patterns<'a>(
                     row: start_pos.row,
                     column: start_pos.column,
                     end_column: end_pos.column,
+                    is_existence_check: is_translation_existence_check(node, source_bytes),
                 });
             }
 
@@ -610,6 +905,21 @@ pub fn extract_all_php_patterns<'a>(
             "action_name" => {
                 result.action_calls.push(ActionMatch {
                     action_name: text,
+                    method_name: None,
+                    byte_start: node.start_byte(),
+                    byte_end: node.end_byte(),
+                    row: start_pos.row,
+                    column: start_pos.column,
+                    end_column: end_pos.column,
+                });
+            }
+            "action_method" => {
+                let Some(class) = get_capture_text(query_match, query, source_bytes, "action_class") else {
+                    continue;
+                };
+                result.action_calls.push(ActionMatch {
+                    action_name: class.trim_start_matches('\\'),
+                    method_name: Some(text),
                     byte_start: node.start_byte(),
                     byte_end: node.end_byte(),
                     row: start_pos.row,
@@ -652,6 +962,95 @@ pub fn extract_all_php_patterns<'a>(
                 });
             }
 
+            // Config-backed names (Queue::connection('redis'), Cache::store('file'), ...)
+            "config_name" => {
+                let receiver = get_capture_text(query_match, query, source_bytes, "config_name_receiver");
+                let method = get_capture_text(query_match, query, source_bytes, "config_name_method");
+                if let Some(reference) = receiver
+                    .zip(method)
+                    .and_then(|(r, m)| find_config_name_reference(r, m))
+                {
+                    result.config_name_calls.push(ConfigNameMatch {
+                        name: text,
+                        config_path: reference.config_path,
+                        byte_start: node.start_byte(),
+                        byte_end: node.end_byte(),
+                        row: start_pos.row,
+                        column: start_pos.column,
+                        end_column: end_pos.column,
+                    });
+                }
+            }
+
+            // Eloquent query heads (User::query(), User::where(...))
+            "model_class" => {
+                result.model_calls.push(ModelMatch {
+                    class_name: text,
+                    byte_start: node.start_byte(),
+                    byte_end: node.end_byte(),
+                    row: start_pos.row,
+                    column: start_pos.column,
+                    end_column: end_pos.column,
+                });
+            }
+
+            // Authorization abilities ($user->can('update', $post), Gate::allows('view-admin'))
+            "ability_name" => {
+                result.ability_calls.push(AbilityMatch {
+                    ability: text,
+                    subject: find_next_argument(node).and_then(|arg| arg.utf8_text(source_bytes).ok()),
+                    byte_start: node.start_byte(),
+                    byte_end: node.end_byte(),
+                    row: start_pos.row,
+                    column: start_pos.column,
+                    end_column: end_pos.column,
+                });
+            }
+
+            // Custom validation rule objects ('title' => ['required', new Uppercase])
+            "rule_class" => {
+                result.rule_classes.push(RuleClassMatch {
+                    class_name: text,
+                    byte_start: node.start_byte(),
+                    byte_end: node.end_byte(),
+                    row: start_pos.row,
+                    column: start_pos.column,
+                    end_column: end_pos.column,
+                });
+            }
+
+            // Artisan commands - only the name, not arguments/options after it
+            // ('mail:send 1 --queue=default' → 'mail:send')
+            "command_name" => {
+                // Interpolated names ("{$prefix}:sync") can't be resolved
+                let interpolated = node.parent().is_some_and(|string| string.named_child_count() > 1);
+                let name_len = text.find(char::is_whitespace).unwrap_or(text.len());
+                if name_len > 0 && !interpolated {
+                    result.command_calls.push(CommandMatch {
+                        command: &text[..name_len],
+                        is_class_reference: false,
+                        byte_start: node.start_byte(),
+                        byte_end: node.start_byte() + name_len,
+                        row: start_pos.row,
+                        column: start_pos.column,
+                        end_column: start_pos.column + name_len,
+                    });
+                }
+            }
+
+            // Artisan commands referenced by class (Schedule::command(BackupCommand::class))
+            "command_class" => {
+                result.command_calls.push(CommandMatch {
+                    command: text,
+                    is_class_reference: true,
+                    byte_start: node.start_byte(),
+                    byte_end: node.end_byte(),
+                    row: start_pos.row,
+                    column: start_pos.column,
+                    end_column: end_pos.column,
+                });
+            }
+
             // Ignore other captures (function_name, class_name, etc. used for matching)
             _ => {}
         }
@@ -661,7 +1060,8 @@ pub fn extract_all_php_patterns<'a>(
     let pattern_count = result.views.len() + result.env_calls.len() + result.config_calls.len()
         + result.middleware_calls.len() + result.translation_calls.len() + result.asset_calls.len()
         + result.binding_calls.len() + result.route_calls.len() + result.url_calls.len()
-        + result.action_calls.len() + result.feature_calls.len();
+        + result.action_calls.len() + result.feature_calls.len() + result.model_calls.len()
+        + result.ability_calls.len() + result.rule_classes.len() + result.command_calls.len();
     info!(
         "📊 PHP extraction: {:?} total (query fetch: {:?}), {} patterns found",
         total_time, query_fetch_time, pattern_count
@@ -671,10 +1071,34 @@ pub fn extract_all_php_patterns<'a>(
 }
 
 /// Extract all Blade patterns in a single tree traversal
+///
+/// Like the PHP extractor, patterns before a syntax error are recovered from
+/// a re-parse of the error-free lines.
 pub fn extract_all_blade_patterns<'a>(
     tree: &Tree,
     source: &'a str,
     language: &Language,
+) -> Result<ExtractedBladePatterns<'a>> {
+    let mut result = extract_blade_captures(tree, source, language)?;
+
+    if let Some(prefix_len) = error_free_prefix_len(tree, source) {
+        let prefix = &source[..prefix_len];
+        match crate::parser::parse_blade(prefix) {
+            Ok(prefix_tree) => {
+                let recovered = extract_blade_captures(&prefix_tree, prefix, language)?;
+                result.merge_recovered(recovered);
+            }
+            Err(e) => warn!("Failed to re-parse Blade before syntax error: {}", e),
+        }
+    }
+
+    Ok(result)
+}
+
+fn extract_blade_captures<'a>(
+    tree: &Tree,
+    source: &'a str,
+    language: &Language,
 ) -> Result<ExtractedBladePatterns<'a>> {
     let start = Instant::now();
     let query = get_blade_query(language)?;
@@ -685,6 +1109,9 @@ pub fn extract_all_blade_patterns<'a>(
     let root_node = tree.root_node();
     let source_bytes = source.as_bytes();
 
+    // @verbatim blocks and @{{ }} escaped echoes are output literally by Blade
+    let literal_ranges = find_blade_literal_ranges(source);
+
     let mut captures = cursor.captures(query, root_node, source_bytes);
 
     while let Some((query_match, capture_index)) = captures.next() {
@@ -692,6 +1119,16 @@ pub fn extract_all_blade_patterns<'a>(
         let capture_name = query.capture_names()[capture.index as usize];
         let node = capture.node;
 
+        if node.is_error() || node.is_missing() {
+            continue;
+        }
+
+        // Skip anything inside literal Blade content (Vue/Alpine mustaches, etc.)
+        let node_start = node.start_byte();
+        if literal_ranges.iter().any(|&(start, end)| node_start >= start && node_start < end) {
+            continue;
+        }
+
         let Ok(text) = node.utf8_text(source_bytes) else {
             continue;
         };
@@ -702,6 +1139,11 @@ pub fn extract_all_blade_patterns<'a>(
         match capture_name {
             // Tag patterns - could be x-* components or livewire:* components
             "tag_name" => {
+                // <x-slot> tags are slots passed to the enclosing component, not components
+                if text == "x-slot" || text.starts_with("x-slot:") {
+                    continue;
+                }
+
                 if let Some(component_name) = text.strip_prefix("x-") {
                     // Blade component
                     result.components.push(ComponentMatch {
@@ -715,14 +1157,15 @@ pub fn extract_all_blade_patterns<'a>(
                         resolved_path: None,
                     });
                 } else if text.starts_with("livewire:") {
-                    // Livewire component tag syntax
+                    // Livewire component tag syntax - range covers only the name,
+                    // not the "livewire:" prefix or any attributes (wire:key, :prop)
                     let component_name = &text[9..]; // Remove "livewire:" prefix
                     result.livewire.push(LivewireMatch {
                         component_name,
-                        byte_start: node.start_byte(),
+                        byte_start: node.start_byte() + 9,
                         byte_end: node.end_byte(),
                         row: start_pos.row,
-                        column: start_pos.column,
+                        column: start_pos.column + 9,
                         end_column: end_pos.column,
                     });
                 }
@@ -741,8 +1184,15 @@ pub fn extract_all_blade_patterns<'a>(
 
                 let directive_name = text.strip_prefix('@').unwrap_or(text);
 
+                // Self-contained attribute helpers - no end directive, nothing to navigate
+                if ATTRIBUTE_DIRECTIVES.contains(&directive_name) {
+                    continue;
+                }
+
                 // Look for parameter sibling
-                let arguments = find_next_parameter_sibling(node, source_bytes);
+                let parameter = find_next_parameter_sibling(node);
+                let arguments = parameter.and_then(|p| p.utf8_text(source_bytes).ok());
+                let arguments_start = parameter.map(|p| (p.start_position().row, p.start_position().column));
 
                 let full_text = if let Some(param) = arguments {
                     format!("{}{}", text, param)
@@ -755,13 +1205,57 @@ pub fn extract_all_blade_patterns<'a>(
 
                 // Calculate string column positions for view-referencing and translation directives
                 let (string_column, string_end_column) = match (directive_name, &arguments) {
-                    ("extends" | "include" | "slot" | "component" | "lang", Some(args)) => {
+                    ("extends" | "include" | "slot" | "component" | "lang" | "choice", Some(args)) => {
                         calculate_string_column_range(directive_column, directive_name, args)
                             .unwrap_or((directive_column, directive_end_column))
                     }
                     _ => (directive_column, directive_end_column),
                 };
 
+                // @livewire('name', [...]) - the first argument is the component name,
+                // anything after it (parameters, key) is ignored
+                if directive_name == "livewire" {
+                    if let (Some(param), Some(args)) = (parameter, arguments) {
+                        if let Some(lw) = livewire_directive_match(param, args) {
+                            result.livewire.push(lw);
+                        }
+                    }
+                }
+
+                // @yield/@section and @stack/@push name the two ends of a region
+                if let (Some(param), Some(args)) = (parameter, arguments) {
+                    let lowercase = directive_name.to_ascii_lowercase();
+                    match lowercase.as_str() {
+                        "yield" | "section" => {
+                            if let Some((section_name, byte_start, row, column)) = quoted_first_argument(param, args) {
+                                result.sections.push(SectionMatch {
+                                    section_name,
+                                    is_yield: lowercase == "yield",
+                                    byte_start,
+                                    byte_end: byte_start + section_name.len(),
+                                    row,
+                                    column,
+                                    end_column: column + section_name.len(),
+                                });
+                            }
+                        }
+                        "stack" | "push" | "pushonce" | "prepend" | "prependonce" => {
+                            if let Some((stack_name, byte_start, row, column)) = quoted_first_argument(param, args) {
+                                result.stacks.push(StackMatch {
+                                    stack_name,
+                                    is_stack: lowercase == "stack",
+                                    byte_start,
+                                    byte_end: byte_start + stack_name.len(),
+                                    row,
+                                    column,
+                                    end_column: column + stack_name.len(),
+                                });
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+
                 result.directives.push(DirectiveMatch {
                     directive_name,
                     full_text,
@@ -773,6 +1267,7 @@ pub fn extract_all_blade_patterns<'a>(
                     end_column: directive_end_column,
                     string_column,
                     string_end_column,
+                    arguments_start,
                 });
             }
 
@@ -793,6 +1288,7 @@ pub fn extract_all_blade_patterns<'a>(
             "echo_php_content" => {
                 result.echo_php.push(EchoPhpMatch {
                     php_content: text,
+                    is_raw: source[..node.start_byte()].trim_end().ends_with("{!!"),
                     byte_start: node.start_byte(),
                     byte_end: node.end_byte(),
                     row: start_pos.row,
@@ -803,19 +1299,36 @@ pub fn extract_all_blade_patterns<'a>(
 
             // Slot tags: <x-slot:name> or <x-slot name="...">
             "slot_tag" => {
-                // Extract slot name from x-slot:name syntax
+                // Extract slot name from x-slot:name syntax - range covers only the name
+                // Dynamic names (<x-slot:[$name]>) can't be resolved, so skip them
                 if let Some(slot_name) = text.strip_prefix("x-slot:") {
-                    result.slots.push(SlotMatch {
-                        slot_name,
-                        byte_start: node.start_byte(),
-                        byte_end: node.end_byte(),
-                        row: start_pos.row,
-                        column: start_pos.column,
-                        end_column: end_pos.column,
-                    });
+                    if is_slot_name(slot_name) {
+                        result.slots.push(SlotMatch {
+                            slot_name,
+                            component_name: enclosing_component_name(node, source_bytes),
+                            byte_start: node.start_byte() + 7,
+                            byte_end: node.end_byte(),
+                            row: start_pos.row,
+                            column: start_pos.column + 7,
+                            end_column: end_pos.column,
+                        });
+                    }
                 }
             }
 
+            // Slot tags with a name attribute: <x-slot name="header">
+            "slot_name_attr" if is_slot_name(text) => {
+                result.slots.push(SlotMatch {
+                    slot_name: text,
+                    component_name: enclosing_component_name(node, source_bytes),
+                    byte_start: node.start_byte(),
+                    byte_end: node.end_byte(),
+                    row: start_pos.row,
+                    column: start_pos.column,
+                    end_column: end_pos.column,
+                });
+            }
+
             // Ignore vite_directive and other captures
             _ => {}
         }
@@ -835,6 +1348,111 @@ pub fn extract_all_blade_patterns<'a>(
 // Helper Functions
 // ============================================================================
 
+/// Byte offset of the start of the line holding the first syntax error
+///
+/// The first ERROR or MISSING node in document order marks the error. Returns
+/// None when the tree is error-free or the error is on the first line, since
+/// there's no valid prefix to re-parse, and when the tree only has MISSING
+/// nodes: those are zero-width insertions that swallow no code, so a re-parse
+/// would find nothing new.
+fn error_free_prefix_len(tree: &Tree, source: &str) -> Option<usize> {
+    if !tree.root_node().has_error() {
+        return None;
+    }
+
+    // Pre-order walk, only entering subtrees that contain an error
+    let mut cursor = tree.walk();
+    let mut first_error = None;
+    loop {
+        let node = cursor.node();
+        if node.is_missing() {
+            first_error.get_or_insert(node.start_byte());
+        } else if node.is_error() {
+            first_error.get_or_insert(node.start_byte());
+            break;
+        } else if node.has_error() && cursor.goto_first_child() {
+            continue;
+        }
+        // Move to the next sibling, climbing out of exhausted subtrees
+        loop {
+            if cursor.goto_next_sibling() {
+                break;
+            }
+            if !cursor.goto_parent() {
+                // Walked the whole tree without an ERROR node
+                return None;
+            }
+        }
+    }
+
+    let error_start = first_error?.min(source.len());
+    let line_start = source[..error_start].rfind('\n').map_or(0, |i| i + 1);
+    (line_start > 0).then_some(line_start)
+}
+
+/// Find byte ranges of literal Blade content that must not be parsed for patterns
+///
+/// - The body of `@verbatim ... @endverbatim` blocks
+/// - Escaped echoes `@{{ ... }}`, which Blade outputs as-is
+/// - Comments `{{-- ... --}}`, which Blade drops
+/// - Escaped directives `@@foreach`, which Blade outputs as `@foreach`
+///
+/// An unterminated block, echo or comment runs to the end of the source.
+pub fn find_blade_literal_ranges(source: &str) -> Vec<(usize, usize)> {
+    let mut ranges = Vec::new();
+    let mut pos = 0;
+
+    while let Some(offset) = source[pos..].find(['@', '{']) {
+        let at = pos + offset;
+        let rest = &source[at..];
+
+        if rest.starts_with("{{--") {
+            let end = source[at..]
+                .find("--}}")
+                .map(|e| at + e + 4)
+                .unwrap_or(source.len());
+            ranges.push((at, end));
+            pos = end;
+            continue;
+        }
+
+        if let Some(escaped) = rest.strip_prefix("@@") {
+            // @@ escapes the following directive (e.g. @@verbatim outputs "@verbatim")
+            if escaped.starts_with(|c: char| c.is_ascii_alphabetic()) {
+                ranges.push((at, at + 2));
+            }
+            pos = at + 2;
+            continue;
+        }
+
+        if let Some(after) = rest.strip_prefix("@verbatim") {
+            // Don't treat @verbatimFoo as @verbatim
+            if !after.starts_with(|c: char| c.is_alphanumeric() || c == '_') {
+                let content_start = at + "@verbatim".len();
+                let end = source[content_start..]
+                    .find("@endverbatim")
+                    .map(|e| content_start + e)
+                    .unwrap_or(source.len());
+                ranges.push((content_start, end));
+                pos = end.max(at + 1);
+                continue;
+            }
+        } else if rest.starts_with("@{{") {
+            let end = source[at..]
+                .find("}}")
+                .map(|e| at + e + 2)
+                .unwrap_or(source.len());
+            ranges.push((at, end));
+            pos = end;
+            continue;
+        }
+
+        pos = at + 1;
+    }
+
+    ranges
+}
+
 /// Get the feature method name from a query match
 /// Looks for the feature_method_name capture in the same match
 fn get_feature_method_name<'a>(
@@ -851,6 +1469,20 @@ fn get_feature_method_name<'a>(
     None
 }
 
+/// Get the text of a named capture from the same query match
+fn get_capture_text<'a>(
+    query_match: &tree_sitter::QueryMatch,
+    query: &Query,
+    source: &'a [u8],
+    name: &str,
+) -> Option<&'a str> {
+    query_match
+        .captures
+        .iter()
+        .find(|c| query.capture_names()[c.index as usize] == name)
+        .and_then(|c| c.node.utf8_text(source).ok())
+}
+
 /// Check if an env() call has a fallback/default value (second argument)
 fn check_has_fallback_argument(node: tree_sitter::Node) -> bool {
     // Navigate: string_content -> string -> argument -> arguments -> function_call
@@ -872,18 +1504,42 @@ fn check_has_fallback_argument(node: tree_sitter::Node) -> bool {
     false
 }
 
-/// Find the next parameter sibling node after a directive node
-fn find_next_parameter_sibling<'a>(
-    directive_node: tree_sitter::Node,
-    source: &'a [u8],
-) -> Option<&'a str> {
-    let parent = directive_node.parent()?;
-    let mut cursor = parent.walk();
+/// Whether a translation key is the argument of `Lang::has()` / `Lang::hasForLocale()`
+/// Navigate: string_content -> string -> argument -> arguments -> scoped_call_expression
+fn is_translation_existence_check(node: tree_sitter::Node, source: &[u8]) -> bool {
+    let Some(call) = node.parent().and_then(|n| n.parent()).and_then(|n| n.parent()).and_then(|n| n.parent()) else {
+        return false;
+    };
+    call.kind() == "scoped_call_expression"
+        && call
+            .child_by_field_name("name")
+            .and_then(|name| name.utf8_text(source).ok())
+            .is_some_and(|name| matches!(name, "has" | "hasForLocale"))
+}
+
+/// Find the argument following the one holding a string literal
+/// Navigate: string_content -> string -> argument -> next argument
+fn find_next_argument(node: tree_sitter::Node) -> Option<tree_sitter::Node> {
+    let argument = node.parent()?.parent()?;
+    let mut next = argument.next_named_sibling();
+    while let Some(sibling) = next {
+        if sibling.kind() == "argument" {
+            return Some(sibling);
+        }
+        next = sibling.next_named_sibling();
+    }
+    None
+}
+
+/// Find the next parameter sibling node after a directive node
+fn find_next_parameter_sibling(directive_node: tree_sitter::Node) -> Option<tree_sitter::Node> {
+    let parent = directive_node.parent()?;
+    let mut cursor = parent.walk();
 
     let mut found_directive = false;
     for child in parent.children(&mut cursor) {
         if found_directive && child.kind() == "parameter" {
-            return child.utf8_text(source).ok();
+            return Some(child);
         }
         if child.id() == directive_node.id() {
             found_directive = true;
@@ -893,6 +1549,93 @@ fn find_next_parameter_sibling<'a>(
     None
 }
 
+/// Whether a slot name is a plain identifier (not a dynamic `[$name]` / `{{ $name }}`)
+fn is_slot_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+/// Find the `<x-component>` a slot node is nested in (e.g., "card" for `<x-card>`)
+///
+/// Walks up the element ancestors, skipping the slot's own `<x-slot>` element.
+fn enclosing_component_name<'a>(
+    slot_node: tree_sitter::Node,
+    source: &'a [u8],
+) -> Option<&'a str> {
+    let mut current = slot_node.parent();
+    while let Some(node) = current {
+        if node.kind() == "element" {
+            let tag_name = node
+                .child(0)
+                .filter(|start_tag| start_tag.kind() == "start_tag")
+                .and_then(|start_tag| start_tag.named_child(0))
+                .filter(|tag| tag.kind() == "tag_name")
+                .and_then(|tag| tag.utf8_text(source).ok());
+
+            if let Some(component_name) = tag_name.and_then(|t| t.strip_prefix("x-")) {
+                if component_name != "slot" && !component_name.starts_with("slot:") {
+                    return Some(component_name);
+                }
+            }
+        }
+        current = node.parent();
+    }
+
+    None
+}
+
+/// Build a LivewireMatch for the component name in `@livewire('name', [...])`
+///
+/// Returns None for dynamic names like `@livewire($component)`.
+/// The range covers the name only (excluding quotes), matching `<livewire:name>` tags.
+/// Positions are taken from the parameter node, so whitespace between the
+/// directive and its parenthesis (`@livewire ('name')`) doesn't shift them.
+fn livewire_directive_match<'a>(
+    parameter_node: tree_sitter::Node,
+    arguments: &'a str,
+) -> Option<LivewireMatch<'a>> {
+    let (component_name, byte_start, row, column) = quoted_first_argument(parameter_node, arguments)?;
+
+    Some(LivewireMatch {
+        component_name,
+        byte_start,
+        byte_end: byte_start + component_name.len(),
+        row,
+        column,
+        end_column: column + component_name.len(),
+    })
+}
+
+/// The non-empty string literal a directive's arguments start with, with the
+/// byte offset, row and column of its first character (inside the quote)
+fn quoted_first_argument<'a>(
+    parameter_node: tree_sitter::Node,
+    arguments: &'a str,
+) -> Option<(&'a str, usize, usize, usize)> {
+    let trimmed = arguments.trim_start();
+    let leading = &arguments[..arguments.len() - trimmed.len()];
+
+    let quote_char = trimmed.chars().next()?;
+    if quote_char != '\'' && quote_char != '"' {
+        return None;
+    }
+
+    let name_len = trimmed[1..].find(quote_char)?;
+    let name = &trimmed[1..1 + name_len];
+    if name.is_empty() {
+        return None;
+    }
+
+    // The name starts one past the opening quote, which may be on a later line
+    let start_pos = parameter_node.start_position();
+    let byte_start = parameter_node.start_byte() + leading.len() + 1;
+    let (row, column) = match leading.rfind('\n') {
+        Some(newline) => (start_pos.row + leading.matches('\n').count(), leading.len() - newline),
+        None => (start_pos.row, start_pos.column + leading.len() + 1),
+    };
+
+    Some((name, byte_start, row, column))
+}
+
 /// Calculate the column range of the quoted string within a directive's arguments
 fn calculate_string_column_range(
     directive_column: usize,
@@ -954,6 +1697,33 @@ mod tests {
         assert!(!users.is_route_view, "view() should set is_route_view=false");
     }
 
+    #[test]
+    fn test_route_registration_helpers_classified() {
+        let php_code = r#"<?php
+        Route::view('/about', 'pages.about', ['title' => 'About']);
+        Volt::route('/settings/profile', 'settings.profile');
+        Route::redirect('/here', '/there');
+        Route::permanentRedirect('/old', '/new');
+        "#;
+
+        let tree = parse_php(php_code).expect("Should parse PHP");
+        let lang = language_php();
+        let patterns = extract_all_php_patterns(&tree, php_code, &lang)
+            .expect("Should extract patterns");
+
+        assert_eq!(patterns.views.len(), 2, "Redirect targets are not views");
+
+        let about = patterns.views.iter().find(|v| v.view_name == "pages.about").unwrap();
+        assert!(about.is_route_view);
+        assert!(!about.is_volt_route, "Route::view() is a regular view");
+
+        let profile = patterns.views.iter().find(|v| v.view_name == "settings.profile").unwrap();
+        assert!(profile.is_route_view);
+        assert!(profile.is_volt_route, "Volt::route() names a Volt component");
+
+        assert!(!patterns.views.iter().any(|v| v.view_name.starts_with('/')));
+    }
+
     #[test]
     fn test_extract_all_php_patterns_env() {
         let php_code = r#"<?php
@@ -1042,6 +1812,85 @@ mod tests {
         assert!(!directive_names.contains(&"endsection"), "Should not find @endsection");
     }
 
+    #[test]
+    fn test_attribute_directives_are_not_extracted() {
+        let blade_code = r#"<input type="checkbox" @checked($user->active) @disabled($locked) @readonly(true)>
+<option @selected(old('role') === 'admin')>Admin</option>
+<input name="email" @required($mustConfirm)>
+@include('partials.footer')
+"#;
+        let tree = parse_blade(blade_code).expect("Should parse Blade");
+        let lang = language_blade();
+        let patterns = extract_all_blade_patterns(&tree, blade_code, &lang)
+            .expect("Should extract patterns");
+
+        let directive_names: Vec<&str> = patterns.directives.iter()
+            .map(|m| m.directive_name).collect();
+        assert_eq!(directive_names, vec!["include"]);
+    }
+
+    #[test]
+    fn test_extract_blade_slots() {
+        let blade_code = r#"<x-card>
+    <x-slot:header>Title</x-slot:header>
+    <x-slot name="footer">
+        <x-button>Save</x-button>
+    </x-slot>
+</x-card>
+"#;
+        let tree = parse_blade(blade_code).expect("Should parse Blade");
+        let lang = language_blade();
+        let patterns = extract_all_blade_patterns(&tree, blade_code, &lang)
+            .expect("Should extract patterns");
+
+        let slots: Vec<(&str, Option<&str>)> = patterns.slots.iter()
+            .map(|s| (s.slot_name, s.component_name))
+            .collect();
+        assert_eq!(slots, vec![("header", Some("card")), ("footer", Some("card"))]);
+
+        // Range covers the name only: <x-slot:header> -> "header"
+        let header = &patterns.slots[0];
+        assert_eq!((header.row, header.column, header.end_column), (1, 13, 19));
+
+        // Slot tags aren't components
+        assert!(patterns.components.iter().all(|c| !c.component_name.starts_with("slot")));
+        assert!(patterns.components.iter().any(|c| c.component_name == "button"));
+    }
+
+    #[test]
+    fn test_extract_blade_sections_and_stacks() {
+        let blade_code = r#"<title>@yield('title')</title>
+@stack('scripts')
+@section('content')
+    @pushOnce('scripts')
+        <script src="/chart.js"></script>
+    @endPushOnce
+    @prepend("scripts")<script></script>@endprepend
+@endsection
+@section('title', 'Home')
+"#;
+        let tree = parse_blade(blade_code).expect("Should parse Blade");
+        let lang = language_blade();
+        let patterns = extract_all_blade_patterns(&tree, blade_code, &lang)
+            .expect("Should extract patterns");
+
+        let sections: Vec<(&str, bool)> = patterns.sections.iter()
+            .map(|s| (s.section_name, s.is_yield))
+            .collect();
+        assert_eq!(sections, vec![("title", true), ("content", false), ("title", false)]);
+
+        let stacks: Vec<(&str, bool)> = patterns.stacks.iter()
+            .map(|s| (s.stack_name, s.is_stack))
+            .collect();
+        assert_eq!(stacks, vec![("scripts", true), ("scripts", false), ("scripts", false)]);
+
+        // Ranges cover the name only: @yield('title') -> "title"
+        let title = &patterns.sections[0];
+        assert_eq!((title.row, title.column, title.end_column), (0, 15, 20));
+        let prepend = &patterns.stacks[2];
+        assert_eq!((prepend.row, prepend.column, prepend.end_column), (6, 14, 21));
+    }
+
     #[test]
     fn test_extract_blade_feature_directive() {
         let blade_code = r#"
@@ -1187,6 +2036,34 @@ mod tests {
         assert_eq!(config_call.end_column, 22, "end_column should be after last char");
     }
 
+    #[test]
+    fn test_config_set_form() {
+        let php_code = "<?php config(['app.runtime' => true, \"app.locale\" => $locale]);\nConfig::set('app.timezone', 'UTC');\nconfig('app.name', 'Laravel');";
+        let tree = parse_php(php_code).expect("Should parse PHP");
+        let lang = language_php();
+        let patterns = extract_all_php_patterns(&tree, php_code, &lang)
+            .expect("Should extract patterns");
+
+        let mut keys: Vec<(&str, bool)> = patterns
+            .config_calls
+            .iter()
+            .map(|c| (c.config_key, c.is_set))
+            .collect();
+        keys.sort();
+        assert_eq!(
+            keys,
+            vec![
+                ("app.locale", true),
+                ("app.name", false),
+                ("app.runtime", true),
+                ("app.timezone", true),
+            ]
+        );
+
+        // Array values are not keys
+        assert!(!patterns.config_calls.iter().any(|c| c.config_key == "UTC"));
+    }
+
     #[test]
     fn test_translation_column_positions() {
         // __('messages.welcome')
@@ -1209,6 +2086,38 @@ mod tests {
         assert_eq!(trans.end_column, 26, "end_column should be after last char");
     }
 
+    #[test]
+    fn test_lang_facade_translation_calls() {
+        let php_code = include_str!("../tests/fixtures/translation-calls/lang-facade.php");
+        let tree = parse_php(php_code).expect("Should parse PHP");
+        let lang = language_php();
+        let patterns = extract_all_php_patterns(&tree, php_code, &lang)
+            .expect("Should extract patterns");
+
+        let calls: Vec<(&str, bool)> = patterns
+            .translation_calls
+            .iter()
+            .map(|t| (t.translation_key, t.is_existence_check))
+            .collect();
+        assert_eq!(
+            calls,
+            vec![
+                ("messages.welcome", false),
+                ("messages.banner", true),
+                ("messages.banner", true),
+                ("messages.banner", false),
+                ("messages.apples", false),
+                ("messages.minutes", false),
+            ]
+        );
+
+        // Ranges cover the key inside the quotes
+        for trans in &patterns.translation_calls {
+            let line = php_code.lines().nth(trans.row).unwrap();
+            assert_eq!(&line[trans.column..trans.end_column], trans.translation_key);
+        }
+    }
+
     #[test]
     fn test_asset_column_positions() {
         // asset('css/app.css')
@@ -1231,6 +2140,25 @@ mod tests {
         assert_eq!(asset.end_column, 24, "end_column should be after last char");
     }
 
+    #[test]
+    fn test_path_helper_subdirectories_and_bare_calls() {
+        let php_code = r#"<?php
+        $dir = storage_path('app');
+        $file = storage_path('app/public/avatars/default.png');
+        $root = base_path();
+        $storage = storage_path();
+        "#;
+        let tree = parse_php(php_code).expect("Should parse PHP");
+        let lang = language_php();
+        let patterns = extract_all_php_patterns(&tree, php_code, &lang)
+            .expect("Should extract patterns");
+
+        // Bare helpers have no argument to validate
+        let paths: Vec<&str> = patterns.asset_calls.iter().map(|a| a.path).collect();
+        assert_eq!(paths, vec!["app", "app/public/avatars/default.png"]);
+        assert!(patterns.asset_calls.iter().all(|a| a.helper_type == AssetHelperType::StoragePath));
+    }
+
     #[test]
     fn test_middleware_column_positions() {
         // Route::middleware('auth')
@@ -1275,6 +2203,61 @@ mod tests {
         assert_eq!(route.end_column, 17, "end_column should be after last char");
     }
 
+    #[test]
+    fn test_redirect_helpers_capture_route_names() {
+        let php_code = include_str!("../tests/fixtures/redirects/PostController.php");
+        let tree = parse_php(php_code).expect("Should parse PHP");
+        let lang = language_php();
+        let patterns = extract_all_php_patterns(&tree, php_code, &lang)
+            .expect("Should extract patterns");
+
+        // to_route(), redirect()->route() and Redirect::route() - back() has no target
+        let names: Vec<&str> = patterns.route_calls.iter().map(|r| r.route_name).collect();
+        assert_eq!(names, vec!["posts.index", "posts.show", "posts.index", "posts.published"]);
+
+        let redirect = &patterns.route_calls[2];
+        let line = php_code.lines().nth(redirect.row).unwrap();
+        assert_eq!(&line[redirect.column..redirect.end_column], "posts.index");
+        assert!(line.contains("Redirect::route(\""));
+    }
+
+    #[test]
+    fn test_action_string_and_tuple_syntax() {
+        let php_code = "<?php\n$a = action('UserController@show');\n$b = action([UserController::class, 'edit'], ['user' => 1]);\n$c = action([\\App\\Http\\Controllers\\PostController::class, 'index']);\n";
+        let tree = parse_php(php_code).expect("Should parse PHP");
+        let lang = language_php();
+        let patterns = extract_all_php_patterns(&tree, php_code, &lang)
+            .expect("Should extract patterns");
+
+        let actions: Vec<(&str, Option<&str>)> = patterns.action_calls.iter().map(|a| (a.action_name, a.method_name)).collect();
+        assert_eq!(actions, vec![
+            ("UserController@show", None),
+            ("UserController", Some("edit")),
+            ("App\\Http\\Controllers\\PostController", Some("index")),
+        ]);
+
+        // The tuple's range is its method string
+        let edit = &patterns.action_calls[1];
+        let line = php_code.lines().nth(edit.row).unwrap();
+        assert_eq!(&line[edit.column..edit.end_column], "edit");
+    }
+
+    #[test]
+    fn test_route_array_callables_are_actions() {
+        let php_code = "<?php\nuse App\\Http\\Controllers\\UserController;\n\nRoute::get('/users/{user}', [UserController::class, 'show'])->name('users.show');\nRoute::match(['get', 'post'], '/posts', [\\App\\Http\\Controllers\\Admin\\PostController::class, 'store']);\nRoute::get('/invoke', [InvokeController::class]);\nRoute::view('/about', 'about', ['title' => 'About']);\n";
+        let tree = parse_php(php_code).expect("Should parse PHP");
+        let lang = language_php();
+        let patterns = extract_all_php_patterns(&tree, php_code, &lang)
+            .expect("Should extract patterns");
+
+        // Only two-element [Class::class, 'method'] arrays, namespaced or not
+        let actions: Vec<(&str, Option<&str>)> = patterns.action_calls.iter().map(|a| (a.action_name, a.method_name)).collect();
+        assert_eq!(actions, vec![
+            ("UserController", Some("show")),
+            ("App\\Http\\Controllers\\Admin\\PostController", Some("store")),
+        ]);
+    }
+
     #[test]
     fn test_binding_column_positions() {
         // app('cache')
@@ -1334,6 +2317,61 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_livewire_tag_range_excludes_prefix_and_attributes() {
+        // Position: 0         1         2
+        //           0123456789012345678901234
+        let blade_code = "<div><livewire:admin.chart wire:key=\"chart-1\" :range=\"$range\" /></div>";
+        let tree = parse_blade(blade_code).expect("Should parse Blade");
+        let lang = language_blade();
+        let patterns = extract_all_blade_patterns(&tree, blade_code, &lang)
+            .expect("Should extract patterns");
+
+        let livewire = patterns.livewire.iter()
+            .find(|lw| lw.component_name == "admin.chart")
+            .expect("Should find <livewire:admin.chart>");
+        assert_eq!(livewire.column, 15, "column should start after 'livewire:'");
+        assert_eq!(livewire.end_column, 26, "end_column should stop at the end of the name");
+        assert_eq!(&blade_code[livewire.byte_start..livewire.byte_end], "admin.chart");
+    }
+
+    #[test]
+    fn test_livewire_directive_with_parameters() {
+        // Position: 0         1         2
+        //           0123456789012345678901234
+        //           @livewire('admin.chart', ['range' => $range], key($id))
+        let blade_code = "@livewire('admin.chart', ['range' => $range], key($id))\n@livewire($dynamic)";
+        let tree = parse_blade(blade_code).expect("Should parse Blade");
+        let lang = language_blade();
+        let patterns = extract_all_blade_patterns(&tree, blade_code, &lang)
+            .expect("Should extract patterns");
+
+        assert_eq!(patterns.livewire.len(), 1, "Dynamic @livewire($var) should be skipped");
+        let livewire = &patterns.livewire[0];
+        assert_eq!(livewire.component_name, "admin.chart");
+        assert_eq!(livewire.row, 0);
+        assert_eq!(livewire.column, 11, "column should point at the name, not the quote");
+        assert_eq!(livewire.end_column, 22);
+        assert_eq!(&blade_code[livewire.byte_start..livewire.byte_end], "admin.chart");
+    }
+
+    #[test]
+    fn test_livewire_directive_with_space_before_paren() {
+        let blade_code = "@livewire ('admin.chart')\n@livewire( 'counter' )";
+        let tree = parse_blade(blade_code).expect("Should parse Blade");
+        let lang = language_blade();
+        let patterns = extract_all_blade_patterns(&tree, blade_code, &lang)
+            .expect("Should extract patterns");
+
+        let names: Vec<&str> = patterns.livewire.iter().map(|lw| lw.component_name).collect();
+        assert_eq!(names, vec!["admin.chart", "counter"]);
+        for livewire in &patterns.livewire {
+            assert_eq!(&blade_code[livewire.byte_start..livewire.byte_end], livewire.component_name);
+            let line = blade_code.lines().nth(livewire.row).unwrap();
+            assert_eq!(&line[livewire.column..livewire.end_column], livewire.component_name);
+        }
+    }
+
     #[test]
     fn test_blade_directive_column_positions() {
         // @include('partials.header')
@@ -1399,6 +2437,83 @@ mod tests {
         assert_eq!(view.end_column, 25, "end_column should be after last char");
     }
 
+    #[test]
+    fn test_blade_verbatim_and_escaped_echo_skipped() {
+        let blade_code = r#"<div>{{ $realEcho }}</div>
+@verbatim
+    <div id="app">{{ message }}</div>
+    <x-not-a-component />
+    @include('not.a.view')
+@endverbatim
+<span>@{{ alpineValue }}</span>
+<x-alert />"#;
+
+        let tree = parse_blade(blade_code).expect("Should parse Blade");
+        let lang = language_blade();
+        let patterns = extract_all_blade_patterns(&tree, blade_code, &lang)
+            .expect("Should extract Blade patterns");
+
+        let echoes: Vec<&str> = patterns.echo_php.iter().map(|e| e.php_content.trim()).collect();
+        assert_eq!(echoes, vec!["$realEcho"], "Only the real echo should be processed");
+
+        let components: Vec<&str> = patterns.components.iter().map(|c| c.component_name).collect();
+        assert!(components.contains(&"alert"), "Components outside @verbatim are still found");
+        assert!(!components.contains(&"not-a-component"), "Components inside @verbatim are skipped");
+
+        assert!(
+            !patterns.directives.iter().any(|d| d.directive_name == "include"),
+            "Directives inside @verbatim are skipped"
+        );
+    }
+
+    #[test]
+    fn test_find_blade_literal_ranges() {
+        let source = "a @{{ x }} b @verbatim {{ y }} @endverbatim @@{{ z }}";
+        let ranges = find_blade_literal_ranges(source);
+        let texts: Vec<&str> = ranges.iter().map(|&(s, e)| &source[s..e]).collect();
+        assert_eq!(texts, vec!["@{{ x }}", " {{ y }} "]);
+
+        // Unterminated @verbatim runs to the end
+        let source = "@verbatim {{ a }}";
+        assert_eq!(find_blade_literal_ranges(source), vec![(9, source.len())]);
+
+        // Comments (with any @verbatim inside them) and escaped directives
+        let source = "{{-- @verbatim <x-a /> --}} {{ $b }} @@foreach @include('c') {{-- open";
+        let ranges = find_blade_literal_ranges(source);
+        let texts: Vec<&str> = ranges.iter().map(|&(s, e)| &source[s..e]).collect();
+        assert_eq!(texts, vec!["{{-- @verbatim <x-a /> --}}", "@@", "{{-- open"]);
+    }
+
+    #[test]
+    fn test_blade_comments_and_escaped_directives_skipped() {
+        let blade_code = r#"{{-- @include('old.partial') --}}
+{{--
+    <x-legacy-alert />
+    {{ view('old.thing') }}
+--}}
+@@include('not.a.view')
+@include('partials.nav')
+<x-alert />"#;
+
+        let tree = parse_blade(blade_code).expect("Should parse Blade");
+        let lang = language_blade();
+        let patterns = extract_all_blade_patterns(&tree, blade_code, &lang)
+            .expect("Should extract Blade patterns");
+
+        let components: Vec<&str> = patterns.components.iter().map(|c| c.component_name).collect();
+        assert_eq!(components, vec!["alert"]);
+        assert!(patterns.echo_php.is_empty(), "Echoes inside comments are skipped");
+
+        let includes: Vec<&str> = patterns
+            .directives
+            .iter()
+            .filter(|d| d.directive_name == "include")
+            .map(|d| d.row)
+            .map(|row| blade_code.lines().nth(row).unwrap())
+            .collect();
+        assert_eq!(includes, vec!["@include('partials.nav')"]);
+    }
+
     #[test]
     fn test_blade_translation_patterns() {
         // Test that we can extract translations from Blade echo syntax
@@ -1513,4 +2628,202 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_extract_model_query_heads() {
+        let php_code = r#"<?php
+        $users = User::query()->where('active', 1)->get();
+        $post = Post::where('slug', $slug)->firstOrFail();
+        $team = \App\Models\Team::find($id);
+        $order = static::query();
+        Route::get('/', fn () => view('welcome'));
+        "#;
+        let tree = parse_php(php_code).expect("Should parse PHP");
+        let lang = language_php();
+        let patterns = extract_all_php_patterns(&tree, php_code, &lang)
+            .expect("Should extract patterns");
+
+        let classes: Vec<&str> = patterns.model_calls.iter().map(|m| m.class_name).collect();
+        assert_eq!(classes, vec!["User", "Post", "\\App\\Models\\Team"]);
+
+        // Only the class is captured, not the chained calls
+        let user = &patterns.model_calls[0];
+        assert_eq!(user.row, 1);
+        assert_eq!(user.column, 17);
+        assert_eq!(user.end_column, 21);
+    }
+
+    #[test]
+    fn test_extract_ability_patterns() {
+        let php_code = include_str!("../tests/fixtures/authorization/PostController.php");
+        let tree = parse_php(php_code).expect("Should parse PHP");
+        let lang = language_php();
+        let patterns = extract_all_php_patterns(&tree, php_code, &lang)
+            .expect("Should extract patterns");
+
+        let abilities: Vec<(&str, Option<&str>)> = patterns.ability_calls.iter()
+            .map(|a| (a.ability, a.subject))
+            .collect();
+        assert_eq!(abilities, vec![
+            ("view-admin", None),
+            ("update", Some("$post")),
+            ("update", Some("$post")),
+            ("delete", Some("$post")),
+            ("publish", Some("Post::class")),
+            ("view-reports", None),
+        ]);
+
+        // The range covers the ability name inside the quotes
+        let line = php_code.lines().position(|l| l.contains("$this->authorize(")).unwrap();
+        let authorize = patterns.ability_calls.iter().find(|a| a.row == line).unwrap();
+        let column = php_code.lines().nth(line).unwrap().find("update").unwrap();
+        assert_eq!((authorize.column, authorize.end_column), (column, column + "update".len()));
+    }
+
+    #[test]
+    fn test_extract_rule_class_patterns() {
+        let php_code = include_str!("../tests/fixtures/validation/StorePostRequest.php");
+        let tree = parse_php(php_code).expect("Should parse PHP");
+        let lang = language_php();
+        let patterns = extract_all_php_patterns(&tree, php_code, &lang)
+            .expect("Should extract patterns");
+
+        let classes: Vec<&str> = patterns.rule_classes.iter().map(|r| r.class_name).collect();
+        assert_eq!(classes, vec!["Uppercase", "\\App\\Rules\\Slug"]);
+    }
+
+    #[test]
+    fn test_extract_command_patterns() {
+        let php_code = include_str!("../tests/fixtures/console/Kernel.php");
+        let tree = parse_php(php_code).expect("Should parse PHP");
+        let lang = language_php();
+        let patterns = extract_all_php_patterns(&tree, php_code, &lang)
+            .expect("Should extract patterns");
+
+        let commands: Vec<(&str, bool)> = patterns.command_calls.iter()
+            .map(|c| (c.command, c.is_class_reference))
+            .collect();
+        assert_eq!(commands, vec![
+            ("mail:send", false),
+            ("PruneReports", true),
+            ("inspire", false),
+            ("migrate", false),
+            ("backup:rn", false),
+            ("reports:prune", false),
+        ]);
+
+        // The range covers only the name, not the arguments after it
+        let mail = &patterns.command_calls[0];
+        assert_eq!(mail.end_column - mail.column, "mail:send".len());
+
+        // Artisan::command() defines a command rather than referencing one
+        let console = include_str!("../tests/fixtures/console/console.php");
+        let tree = parse_php(console).expect("Should parse PHP");
+        let patterns = extract_all_php_patterns(&tree, console, &lang).expect("Should extract patterns");
+        assert!(patterns.command_calls.is_empty());
+    }
+
+    #[test]
+    fn test_extract_php_patterns_before_syntax_error() {
+        let php_code = include_str!("../tests/fixtures/partial/HomeController.php");
+        let tree = parse_php(php_code).expect("Should parse PHP");
+        assert!(tree.root_node().has_error());
+        let lang = language_php();
+        let patterns = extract_all_php_patterns(&tree, php_code, &lang)
+            .expect("Should extract patterns");
+
+        let views: Vec<&str> = patterns.views.iter().map(|v| v.view_name).collect();
+        assert_eq!(views, vec!["home"]);
+        let configs: Vec<&str> = patterns.config_calls.iter().map(|c| c.config_key).collect();
+        assert_eq!(configs, vec!["app.name"]);
+    }
+
+    #[test]
+    fn test_extract_blade_patterns_before_syntax_error() {
+        let blade_code = include_str!("../tests/fixtures/partial/dashboard.blade.php");
+        let tree = parse_blade(blade_code).expect("Should parse Blade");
+        let lang = language_blade();
+        let patterns = extract_all_blade_patterns(&tree, blade_code, &lang)
+            .expect("Should extract patterns");
+
+        let components: Vec<&str> = patterns.components.iter().map(|c| c.component_name).collect();
+        assert!(components.contains(&"alert"), "got {:?}", components);
+        assert!(components.contains(&"layouts.app"), "got {:?}", components);
+        assert_eq!(patterns.components[0].row, 0);
+    }
+
+    #[test]
+    fn test_error_free_prefix_len() {
+        let valid = "<?php\nreturn view('home');\n";
+        let tree = parse_php(valid).expect("Should parse PHP");
+        assert_eq!(error_free_prefix_len(&tree, valid), None);
+
+        // The prefix ends where the half-typed line starts
+        let partial = "<?php\nreturn view('home');\nreturn view('hom";
+        let tree = parse_php(partial).expect("Should parse PHP");
+        assert_eq!(error_free_prefix_len(&tree, partial), partial.rfind("return view('hom"));
+
+        // An error nested in a block is found before the valid code after it
+        let nested = "<?php\n$a = view('a');\nif ($x) {\n    foo(\n}\n$c = view('c');\n";
+        let tree = parse_php(nested).expect("Should parse PHP");
+        assert_eq!(error_free_prefix_len(&tree, nested), nested.find("    foo("));
+
+        // A missing token alone hides nothing, so there's nothing to re-parse
+        let missing = "<?php\n$a = view('a');\n$b = [1, 2;\n";
+        let tree = parse_php(missing).expect("Should parse PHP");
+        assert!(tree.root_node().has_error());
+        assert_eq!(error_free_prefix_len(&tree, missing), None);
+    }
+
+    #[test]
+    fn test_extract_config_name_patterns() {
+        let php_code = r#"<?php
+        Queue::connection('redis')->push($job);
+        Cache::store("file")->get('key');
+        cache()->store('array');
+        Storage::disk('s3');
+        Queue::push($job);
+        Cache::get('not-a-store');
+        Cache::driver('redis');
+        Auth::guard('api')->user();
+        auth()->guard("sanctum");
+        Password::broker('admins');
+        Session::driver('cookie');
+        request()->input('guard');
+        "#;
+
+        let tree = parse_php(php_code).expect("Should parse PHP");
+        let lang = language_php();
+        let patterns = extract_all_php_patterns(&tree, php_code, &lang)
+            .expect("Should extract patterns");
+
+        let names: Vec<(&str, &str)> = patterns.config_name_calls.iter()
+            .map(|c| (c.name, c.config_path))
+            .collect();
+
+        assert!(names.contains(&("redis", "queue.connections")), "Should find queue connection");
+        assert!(names.contains(&("file", "cache.stores")), "Should find cache store (double quotes)");
+        assert!(names.contains(&("array", "cache.stores")), "Should find cache() helper store");
+        assert!(names.contains(&("s3", "filesystems.disks")), "Should find storage disk");
+        assert!(names.contains(&("redis", "cache.stores")), "Should find Cache::driver() as a store");
+        assert!(names.contains(&("api", "auth.guards")), "Should find auth guard");
+        assert!(names.contains(&("sanctum", "auth.guards")), "Should find auth() helper guard");
+        assert!(names.contains(&("admins", "auth.passwords")), "Should find password broker");
+        assert_eq!(names.len(), 8, "Calls outside the table must not be treated as config names");
+    }
+
+    #[test]
+    fn test_config_name_column_positions() {
+        // 0-5 = "<?php ", 6-10 = "Queue", 11-12 = "::", 13-22 = "connection", 23 = "(", 24 = "'", 25 = "r"
+        let php_code = "<?php Queue::connection('redis');";
+        let tree = parse_php(php_code).expect("Should parse PHP");
+        let lang = language_php();
+        let patterns = extract_all_php_patterns(&tree, php_code, &lang)
+            .expect("Should extract patterns");
+
+        assert_eq!(patterns.config_name_calls.len(), 1);
+        let conn = &patterns.config_name_calls[0];
+        assert_eq!(conn.column, 25, "column should point to first char of name");
+        assert_eq!(conn.end_column, 30, "end_column should be after last char");
+    }
+
 }
@@ -0,0 +1,153 @@
+//! Raw Blade echoes of likely user input
+//!
+//! `{!! ... !!}` skips Blade's escaping, so echoing request data through it is
+//! an XSS risk. This is a conservative hint: only expressions rooted in an
+//! obvious user-input source (`$request`, `$_GET`, `request()`, or a variable
+//! assigned from one of those in the same view) are reported.
+
+use crate::parser::{language_blade, parse_blade};
+use crate::queries::extract_all_blade_patterns;
+
+/// A `{!! ... !!}` echo whose expression looks like user input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawEchoHint {
+    /// The echoed expression, trimmed (e.g., "$request->input('bio')")
+    pub expression: String,
+    pub line: u32,
+    pub column: u32,
+    pub end_column: u32,
+}
+
+/// Variables that hold request data by convention
+const USER_INPUT_VARIABLES: &[&str] = &[
+    "request", "input", "_GET", "_POST", "_REQUEST", "_COOKIE",
+];
+
+/// Methods that return HTML built by the framework rather than user input
+const SAFE_HTML_METHODS: &[&str] = &["links", "toHtml", "render"];
+
+/// Functions whose output is already escaped or sanitized
+const ESCAPING_FUNCTIONS: &[&str] = &["e", "htmlspecialchars", "htmlentities", "strip_tags", "clean"];
+
+/// Find raw echoes in a Blade file that output likely user input
+pub fn find_raw_echo_user_input(source: &str) -> Vec<RawEchoHint> {
+    let Ok(tree) = parse_blade(source) else {
+        return Vec::new();
+    };
+    let Ok(patterns) = extract_all_blade_patterns(&tree, source, &language_blade()) else {
+        return Vec::new();
+    };
+
+    let tainted = variables_assigned_from_input(source);
+
+    patterns
+        .echo_php
+        .iter()
+        .filter(|echo| echo.is_raw)
+        .filter_map(|echo| {
+            let expression = echo.php_content.trim();
+            if !is_user_input(expression, &tainted) {
+                return None;
+            }
+
+            // Highlight the expression itself, or its first line when it spans several
+            let leading = echo.php_content.len() - echo.php_content.trim_start().len();
+            let first_line = expression.lines().next().unwrap_or(expression);
+            let column = (echo.column + leading) as u32;
+            Some(RawEchoHint {
+                expression: expression.to_string(),
+                line: echo.row as u32,
+                column,
+                end_column: column + first_line.len() as u32,
+            })
+        })
+        .collect()
+}
+
+/// Whether an echoed expression reads from a user-input source
+fn is_user_input(expression: &str, tainted: &[String]) -> bool {
+    use lazy_static::lazy_static;
+    use regex::Regex;
+
+    lazy_static! {
+        // $var, $var->prop, $var['key'], $var->method(...) chains
+        static ref VARIABLE_ROOT: Regex = Regex::new(r"^\$([A-Za-z_][A-Za-z0-9_]*)").unwrap();
+        static ref REQUEST_HELPER: Regex = Regex::new(r"^(?:request|Request::(?:input|get|query|post))\s*\(").unwrap();
+        static ref FUNCTION_CALL: Regex = Regex::new(r"^\\?([A-Za-z_][A-Za-z0-9_]*)\s*\(").unwrap();
+        static ref TRAILING_METHOD: Regex = Regex::new(r"->([A-Za-z_][A-Za-z0-9_]*)\s*\([^()]*\)\s*$").unwrap();
+    }
+
+    if let Some(function) = FUNCTION_CALL.captures(expression).and_then(|c| c.get(1)) {
+        if ESCAPING_FUNCTIONS.contains(&function.as_str()) {
+            return false;
+        }
+    }
+
+    if let Some(method) = TRAILING_METHOD.captures(expression).and_then(|c| c.get(1)) {
+        if SAFE_HTML_METHODS.contains(&method.as_str()) {
+            return false;
+        }
+    }
+
+    if REQUEST_HELPER.is_match(expression) {
+        return true;
+    }
+
+    match VARIABLE_ROOT.captures(expression).and_then(|c| c.get(1)) {
+        Some(name) => {
+            let name = name.as_str();
+            USER_INPUT_VARIABLES.contains(&name) || tainted.iter().any(|t| t == name)
+        }
+        None => false,
+    }
+}
+
+/// Names of variables assigned from request data in the view
+/// (e.g. `@php($term = request('q'))` or `@php $term = $request->query('q'); @endphp`)
+fn variables_assigned_from_input(source: &str) -> Vec<String> {
+    use lazy_static::lazy_static;
+    use regex::Regex;
+
+    lazy_static! {
+        static ref ASSIGNMENT: Regex = Regex::new(
+            r"\$([A-Za-z_][A-Za-z0-9_]*)\s*=\s*(?:request\s*\(|Request::|\$(?:request|input|_GET|_POST|_REQUEST|_COOKIE)\b)"
+        ).unwrap();
+    }
+
+    let mut names: Vec<String> = ASSIGNMENT
+        .captures_iter(source)
+        .map(|c| c[1].to_string())
+        .collect();
+    names.sort();
+    names.dedup();
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flags_raw_echo_of_user_input() {
+        let content = include_str!("../tests/fixtures/raw-echo/flagged.blade.php");
+        let hints = find_raw_echo_user_input(content);
+
+        let expressions: Vec<&str> = hints.iter().map(|h| h.expression.as_str()).collect();
+        assert_eq!(
+            expressions,
+            vec!["$request->input('bio')", "request('q')", "$_GET['name']", "$term"]
+        );
+
+        // Ranges cover the expression inside the braces
+        for hint in &hints {
+            let line = content.lines().nth(hint.line as usize).unwrap();
+            assert_eq!(&line[hint.column as usize..hint.end_column as usize], hint.expression);
+        }
+    }
+
+    #[test]
+    fn test_ignores_safe_and_escaped_echoes() {
+        let content = include_str!("../tests/fixtures/raw-echo/safe.blade.php");
+        assert!(find_raw_echo_user_input(content).is_empty());
+    }
+}
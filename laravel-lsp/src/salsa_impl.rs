@@ -10,7 +10,7 @@
 //! and processes requests via channels.
 #![allow(dead_code)]
 
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::num::NonZeroUsize;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
@@ -18,9 +18,12 @@ use std::time::Instant;
 use lru::LruCache;
 use salsa::Setter;
 use tokio::sync::{mpsc, oneshot};
-use tracing::info;
+use tracing::{debug, info};
 
-use crate::config::kebab_to_pascal_case;
+use crate::blade_props::{find_closing_delimiter, parse_props_directive, split_top_level, BladeProp};
+use crate::config::{component_class_path, discover_modules, kebab_to_pascal_case, FileKind, FileTypeSettings, LaravelPaths};
+use crate::config_keys::{parse_config_entries, ConfigEntry};
+use crate::middleware_parser::resolve_imported_class;
 
 // ============================================================================
 // Database Definition
@@ -60,6 +63,9 @@ pub struct SourceFile {
     /// The file content
     #[returns(ref)]
     pub text: String,
+
+    /// Parse with the Blade grammar (classified via `FileKind` when registered)
+    pub is_blade: bool,
 }
 
 /// Represents a configuration file (composer.json, config/*.php)
@@ -133,10 +139,25 @@ pub struct EnvFile {
     #[returns(ref)]
     pub text: String,
 
-    /// Priority: 0=.env.example, 1=.env.local, 2=.env (highest)
+    /// Priority: 0=.env.example, 1=.env.{environment}, 2=.env.local, 3=.env (highest)
     pub priority: u8,
 }
 
+/// Represents a JSON translation file (lang/en.json)
+#[salsa::input]
+pub struct JsonTranslationFile {
+    /// The file path
+    #[returns(ref)]
+    pub path: PathBuf,
+
+    /// Version incremented when file changes
+    pub version: i32,
+
+    /// The file content
+    #[returns(ref)]
+    pub text: String,
+}
+
 // ============================================================================
 // Interned Types - Deduplicated strings
 // ============================================================================
@@ -241,6 +262,7 @@ pub struct ViewReference<'db> {
     pub column: u32,
     pub end_column: u32,
     pub is_route_view: bool,
+    pub is_volt_route: bool,
 }
 
 /// A parsed component reference found in code
@@ -281,6 +303,8 @@ pub struct ConfigReference<'db> {
     pub line: u32,
     pub column: u32,
     pub end_column: u32,
+    /// Set form (`config(['key' => value])`, `Config::set()`)
+    pub is_set: bool,
 }
 
 /// Interned string for Livewire component names
@@ -315,6 +339,8 @@ pub struct TranslationReference<'db> {
     pub line: u32,
     pub column: u32,
     pub end_column: u32,
+    /// `Lang::has()` / `Lang::hasForLocale()` - a missing key may be intentional
+    pub is_existence_check: bool,
 }
 
 /// Asset helper type - mirrors queries::AssetHelperType
@@ -333,6 +359,71 @@ pub enum AssetHelperType {
     ViteAsset,
 }
 
+impl AssetHelperType {
+    /// Directory the helper's argument is resolved against
+    pub fn base_dir(&self, root: &Path) -> PathBuf {
+        match self {
+            AssetHelperType::Asset | AssetHelperType::PublicPath | AssetHelperType::Mix => root.join("public"),
+            AssetHelperType::BasePath => root.to_path_buf(),
+            AssetHelperType::AppPath => root.join("app"),
+            AssetHelperType::StoragePath => root.join("storage"),
+            AssetHelperType::DatabasePath => root.join("database"),
+            AssetHelperType::LangPath => root.join("lang"),
+            AssetHelperType::ConfigPath => root.join("config"),
+            AssetHelperType::ResourcePath | AssetHelperType::ViteAsset => root.join("resources"),
+        }
+    }
+
+    /// Helper name as written in code (for diagnostic messages)
+    pub fn helper_name(&self) -> &'static str {
+        match self {
+            AssetHelperType::Asset => "asset",
+            AssetHelperType::PublicPath => "public_path",
+            AssetHelperType::Mix => "mix",
+            AssetHelperType::BasePath => "base_path",
+            AssetHelperType::AppPath => "app_path",
+            AssetHelperType::StoragePath => "storage_path",
+            AssetHelperType::DatabasePath => "database_path",
+            AssetHelperType::LangPath => "lang_path",
+            AssetHelperType::ConfigPath => "config_path",
+            AssetHelperType::ResourcePath => "resource_path",
+            AssetHelperType::ViteAsset => "@vite",
+        }
+    }
+
+    /// Resolve the full path for a helper argument, including any subdirectories
+    /// (e.g. `storage_path('app/public/x')` -> `{root}/storage/app/public/x`)
+    pub fn resolve(&self, root: &Path, argument: &str) -> PathBuf {
+        let base = self.base_dir(root);
+        let argument = argument.trim_start_matches(['/', '\\']);
+        if argument.is_empty() {
+            base
+        } else {
+            base.join(argument)
+        }
+    }
+}
+
+/// What a path helper argument points at on disk
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssetTarget {
+    File,
+    /// An existing directory - valid for path helpers like `storage_path('app')`
+    Directory,
+    Missing,
+}
+
+impl AssetTarget {
+    /// Check a resolved helper path via its metadata
+    pub fn of(path: &Path) -> Self {
+        match std::fs::metadata(path) {
+            Ok(meta) if meta.is_dir() => AssetTarget::Directory,
+            Ok(_) => AssetTarget::File,
+            Err(_) => AssetTarget::Missing,
+        }
+    }
+}
+
 /// A parsed asset reference found in code
 #[salsa::tracked]
 pub struct AssetReference<'db> {
@@ -427,6 +518,10 @@ pub struct LaravelConfigRef<'db> {
     #[returns(ref)]
     pub livewire_path: Option<PathBuf>,
 
+    /// Livewire view path from `view_path` in config/livewire.php (if Livewire is installed)
+    #[returns(ref)]
+    pub livewire_view_path: Option<PathBuf>,
+
     /// Whether Livewire is installed (detected from composer.json)
     pub has_livewire: bool,
 }
@@ -482,19 +577,20 @@ fn extract_string_from_args(args: &str) -> Option<(String, usize, usize)> {
 /// - __('messages.welcome')
 /// - trans("messages.welcome")
 /// - trans_choice("messages.items", $count)
+/// - Lang::get("messages.welcome"), Lang::has(...), Lang::choice(...)
 /// - @lang("messages.welcome")
 ///
 /// Returns (translation_key, start_offset, end_offset) if found
 fn extract_translation_from_echo(php_content: &str) -> Option<(String, usize, usize)> {
     use regex::Regex;
 
-    // Match translation function calls: __(), trans(), trans_choice()
+    // Match translation function calls: __(), trans(), trans_choice(), Lang::get() and friends
     // We need separate patterns for single and double quotes since regex crate doesn't support backreferences
     static TRANS_REGEX_SINGLE: std::sync::LazyLock<Regex> = std::sync::LazyLock::new(|| {
-        Regex::new(r#"(?:__|trans|trans_choice)\s*\(\s*'([^']+)'"#).unwrap()
+        Regex::new(r#"(?:__|trans|trans_choice|Lang::(?:get|has|hasForLocale|choice))\s*\(\s*'([^']+)'"#).unwrap()
     });
     static TRANS_REGEX_DOUBLE: std::sync::LazyLock<Regex> = std::sync::LazyLock::new(|| {
-        Regex::new(r#"(?:__|trans|trans_choice)\s*\(\s*"([^"]+)""#).unwrap()
+        Regex::new(r#"(?:__|trans|trans_choice|Lang::(?:get|has|hasForLocale|choice))\s*\(\s*"([^"]+)""#).unwrap()
     });
 
     // Try single quotes first
@@ -518,59 +614,60 @@ fn extract_translation_from_echo(php_content: &str) -> Option<(String, usize, us
     None
 }
 
+/// Whether the echo text before a translation key ends in `Lang::has(` / `Lang::hasForLocale(`
+fn is_existence_check_call(before_key: &str) -> bool {
+    use regex::Regex;
+
+    static EXISTENCE_CHECK: std::sync::LazyLock<Regex> = std::sync::LazyLock::new(|| {
+        Regex::new(r#"Lang::has(?:ForLocale)?\s*\(\s*['"]$"#).unwrap()
+    });
+    EXISTENCE_CHECK.is_match(before_key)
+}
+
 /// Parse @vite directive arguments and extract individual file paths with their positions
 ///
 /// Handles both formats:
 /// - @vite('resources/css/app.css')
 /// - @vite(['resources/css/app.css', 'resources/js/app.js'])
 ///
+/// `args` is the text inside the parentheses and `args_row`/`args_col` is where
+/// it starts, so every entry gets its own range - including entries on later
+/// lines of a multi-line array.
+///
 /// Returns Vec of (path, line, column, end_column) for each file path
-fn parse_vite_directive_assets(args: &str, directive_row: usize, directive_col: usize, directive_len: usize) -> Vec<(String, u32, u32, u32)> {
+fn parse_vite_directive_assets(args: &str, args_row: usize, args_col: usize) -> Vec<(String, u32, u32, u32)> {
     let mut results = Vec::new();
 
-    // The args from tree-sitter typically include the parentheses content
-    // e.g., "(['resources/css/app.css', 'resources/js/app.js'])"
-    let args = args.trim();
-
-    // Track position within the arguments string
-    let mut pos = 0;
-    let chars: Vec<char> = args.chars().collect();
-
-    while pos < chars.len() {
-        // Find the start of a quoted string
-        let quote_char = match chars[pos] {
-            '\'' | '"' => chars[pos],
-            _ => {
-                pos += 1;
-                continue;
+    // Document position just past the current character
+    let mut row = args_row;
+    let mut col = args_col;
+    // While inside a string: the quote char and the column where its content starts
+    let mut open: Option<(char, usize)> = None;
+    let mut path = String::new();
+
+    for c in args.chars() {
+        if c == '\n' {
+            row += 1;
+            col = 0;
+            if open.is_some() {
+                path.push(c);
             }
-        };
-
-        let quote_start = pos;
-        pos += 1; // Move past opening quote
-
-        // Find the end of the quoted string
-        let mut path_chars = Vec::new();
-        while pos < chars.len() && chars[pos] != quote_char {
-            path_chars.push(chars[pos]);
-            pos += 1;
+            continue;
         }
-
-        if pos < chars.len() {
-            let path: String = path_chars.into_iter().collect();
-            if !path.is_empty() {
-                // Calculate column positions for the path content (excluding quotes)
-                // directive_col is where @ starts
-                // directive_len is length of @vite (5)
-                // quote_start is position of opening quote within args (which includes the paren)
-                // +1 to skip the opening quote itself and point to the path content
-                // +1 more because LSP columns are 0-based but we need to account for the @ symbol position
-                let col = (directive_col + directive_len + quote_start + 2) as u32;
-                let end_col = col + path.len() as u32; // Just the path, no quotes
-
-                results.push((path, directive_row as u32, col, end_col));
+        col += c.len_utf8();
+
+        match open {
+            Some((quote, start_col)) if c == quote => {
+                let path = std::mem::take(&mut path);
+                // A path never spans lines, so skip strings that do
+                if !path.is_empty() && !path.contains('\n') {
+                    results.push((path, row as u32, start_col as u32, (col - 1) as u32));
+                }
+                open = None;
             }
-            pos += 1; // Move past closing quote
+            Some(_) => path.push(c),
+            None if c == '\'' || c == '"' => open = Some((c, col)),
+            None => {}
         }
     }
 
@@ -581,6 +678,80 @@ fn parse_vite_directive_assets(args: &str, directive_row: usize, directive_col:
 // Query Functions - The actual computation
 // ============================================================================
 
+/// Prefix that turns the content of a Blade echo into a parseable PHP snippet
+const ECHO_PHP_PREFIX: &str = "<?php ";
+
+/// PHP content of a Blade echo (`{{ }}` / `{!! !!}`), wrapped so it parses as PHP
+struct EchoPhpSource {
+    php: String,
+    byte_start: usize,
+    row: usize,
+    column: usize,
+}
+
+/// Wrap each Blade echo's content so the PHP extractor can see helper calls in it
+fn echo_php_sources(echoes: &[crate::queries::EchoPhpMatch]) -> Vec<EchoPhpSource> {
+    echoes
+        .iter()
+        .map(|echo| EchoPhpSource {
+            php: format!("{}{};", ECHO_PHP_PREFIX, echo.php_content),
+            byte_start: echo.byte_start,
+            row: echo.row,
+            column: echo.column,
+        })
+        .collect()
+}
+
+/// Echo snippets of a Blade file, for callers that don't already have its Blade patterns
+fn blade_echo_php_sources(text: &str) -> Vec<EchoPhpSource> {
+    use crate::parser::{parse_blade, language_blade};
+    use crate::queries::extract_all_blade_patterns;
+
+    let Ok(tree) = parse_blade(text) else {
+        return Vec::new();
+    };
+    match extract_all_blade_patterns(&tree, text, &language_blade()) {
+        Ok(patterns) => echo_php_sources(&patterns.echo_php),
+        Err(_) => Vec::new(),
+    }
+}
+
+/// PHP patterns of a file: one set for the whole text plus one per Blade echo,
+/// relocated so positions point into the file
+///
+/// Blade files have no `<?php` tag, so the whole-file parse only finds embedded
+/// PHP blocks; calls like `{{ route('home') }}` come from the echo sets.
+/// Translations in echoes are left to `extract_translation_from_echo`.
+fn extract_php_pattern_sets<'a>(
+    text: &'a str,
+    echo_sources: &'a [EchoPhpSource],
+) -> Vec<crate::queries::ExtractedPhpPatterns<'a>> {
+    use crate::parser::{parse_php, language_php};
+    use crate::queries::extract_all_php_patterns;
+
+    let lang = language_php();
+    let mut sets = Vec::new();
+
+    if let Ok(tree) = parse_php(text) {
+        if let Ok(patterns) = extract_all_php_patterns(&tree, text, &lang) {
+            sets.push(patterns);
+        }
+    }
+
+    for echo in echo_sources {
+        let Ok(tree) = parse_php(&echo.php) else {
+            continue;
+        };
+        if let Ok(mut patterns) = extract_all_php_patterns(&tree, &echo.php, &lang) {
+            patterns.relocate(ECHO_PHP_PREFIX.len(), echo.byte_start, echo.row, echo.column);
+            patterns.translation_calls.clear();
+            sets.push(patterns);
+        }
+    }
+
+    sets
+}
+
 /// Parse a source file and extract all Laravel patterns
 /// This is automatically memoized by Salsa
 ///
@@ -590,15 +761,11 @@ fn parse_vite_directive_assets(args: &str, directive_row: usize, directive_col:
 /// - All patterns extracted in O(n) instead of O(n×k)
 #[salsa::tracked]
 pub fn parse_file_patterns<'db>(db: &'db dyn Db, file: SourceFile) -> ParsedPatterns<'db> {
-    use crate::parser::{parse_blade, parse_php, language_blade, language_php};
-    use crate::queries::{
-        extract_all_php_patterns, extract_all_blade_patterns,
-        AssetHelperType as QueryAssetHelperType,
-    };
+    use crate::parser::{parse_blade, language_blade};
+    use crate::queries::{extract_all_blade_patterns, AssetHelperType as QueryAssetHelperType};
 
     let text = file.text(db);
-    let path = file.path(db);
-    let is_blade = path.to_string_lossy().ends_with(".blade.php");
+    let is_blade = file.is_blade(db);
 
     let mut views = Vec::new();
     let mut components = Vec::new();
@@ -610,6 +777,7 @@ pub fn parse_file_patterns<'db>(db: &'db dyn Db, file: SourceFile) -> ParsedPatt
     let mut translation_refs = Vec::new();
     let mut asset_refs = Vec::new();
     let mut binding_refs = Vec::new();
+    let mut echo_sources = Vec::new();
 
     // Parse Blade files - single pass extraction
     if is_blade {
@@ -648,7 +816,8 @@ pub fn parse_file_patterns<'db>(db: &'db dyn Db, file: SourceFile) -> ParsedPatt
                     // Handle @vite specially - extract individual asset paths
                     if dir.directive_name == "vite" {
                         if let Some(args) = dir.arguments {
-                            let vite_assets = parse_vite_directive_assets(args, dir.row, dir.column, dir.directive_name.len() + 1);
+                            let (args_row, args_col) = dir.arguments_start.unwrap_or((dir.row, dir.end_column + 1));
+                            let vite_assets = parse_vite_directive_assets(args, args_row, args_col);
                             for (path, line, col, end_col) in vite_assets {
                                 let asset_path = AssetPath::new(db, path);
                                 asset_refs.push(AssetReference::new(
@@ -664,30 +833,36 @@ pub fn parse_file_patterns<'db>(db: &'db dyn Db, file: SourceFile) -> ParsedPatt
                         continue; // Don't add @vite as a directive
                     }
 
-                    // Handle @lang specially - extract as translation reference
-                    if dir.directive_name == "lang" {
+                    // Handle @lang / @choice specially - extract as translation reference
+                    if matches!(dir.directive_name, "lang" | "choice") {
                         if let Some(args) = dir.arguments {
                             // Extract the translation key from the arguments
-                            // Args look like: ('welcome') or ("welcome")
+                            // Args look like: ('welcome') or ("apples", $count)
                             if let Some((trans_key, start_offset, end_offset)) = extract_string_from_args(args) {
                                 let key = TranslationKey::new(db, trans_key);
-                                // Calculate column positions: directive_column + @lang + offset into args
-                                // @lang is 5 chars, plus 1 for @
-                                let base_col = dir.column + 6; // position after @lang
+                                // Calculate column positions: directive_column + @name + offset into args
+                                let base_col = dir.column + 1 + dir.directive_name.len(); // position after @name
                                 let col = base_col + start_offset;
                                 let end_col = base_col + end_offset;
-                                info!("📍 @lang translation: key='{}' row={} col={}-{} (args={:?})",
-                                    key.key(db), dir.row, col, end_col, args);
+                                info!("📍 @{} translation: key='{}' row={} col={}-{} (args={:?})",
+                                    dir.directive_name, key.key(db), dir.row, col, end_col, args);
                                 translation_refs.push(TranslationReference::new(
                                     db,
                                     key,
                                     dir.row as u32,
                                     col as u32,
                                     end_col as u32,
+                                    false,
                                 ));
                             }
                         }
-                        continue; // Don't add @lang as a directive
+                        continue; // Don't add @lang / @choice as a directive
+                    }
+
+                    // @livewire is extracted as a Livewire reference (name range only)
+                    // so goto and diagnostics resolve the component, not the directive
+                    if dir.directive_name == "livewire" {
+                        continue;
                     }
 
                     let name = DirectiveName::new(db, dir.directive_name.to_string());
@@ -706,6 +881,7 @@ pub fn parse_file_patterns<'db>(db: &'db dyn Db, file: SourceFile) -> ParsedPatt
                 // Process PHP content inside {{ ... }} echo statements
                 // Extract translation calls like __("Welcome"), trans("key"), etc.
                 info!("🔍 Processing {} echo PHP snippets", blade_patterns.echo_php.len());
+                echo_sources = echo_php_sources(&blade_patterns.echo_php);
                 for echo in blade_patterns.echo_php {
                     info!("🔍 Echo PHP content: {:?} at row {} col {}", echo.php_content, echo.row, echo.column);
                     if let Some((trans_key, start_offset, end_offset)) = extract_translation_from_echo(&echo.php_content) {
@@ -714,6 +890,7 @@ pub fn parse_file_patterns<'db>(db: &'db dyn Db, file: SourceFile) -> ParsedPatt
                         // Calculate column positions relative to the echo statement
                         let col = echo.column + start_offset;
                         let end_col = echo.column + end_offset;
+                        let is_existence_check = is_existence_check_call(&echo.php_content[..start_offset]);
                         info!("📍 Translation ref: row={} col={}-{}", echo.row, col, end_col);
                         translation_refs.push(TranslationReference::new(
                             db,
@@ -721,6 +898,7 @@ pub fn parse_file_patterns<'db>(db: &'db dyn Db, file: SourceFile) -> ParsedPatt
                             echo.row as u32,
                             col as u32,
                             end_col as u32,
+                            is_existence_check,
                         ));
                     } else {
                         info!("❌ No translation found in echo content");
@@ -730,115 +908,114 @@ pub fn parse_file_patterns<'db>(db: &'db dyn Db, file: SourceFile) -> ParsedPatt
         }
     }
 
-    // Parse PHP (including Blade files for embedded PHP) - single pass extraction
-    if let Ok(tree) = parse_php(text) {
-        let lang = language_php();
-
-        if let Ok(php_patterns) = extract_all_php_patterns(&tree, text, &lang) {
-            // Process views
-            for view in php_patterns.views {
-                let name = ViewName::new(db, view.view_name.to_string());
-                views.push(ViewReference::new(
-                    db,
-                    name,
-                    view.row as u32,
-                    view.column as u32,
-                    view.end_column as u32,
-                    view.is_route_view,
-                ));
-            }
-
-            // Process env calls
-            for env in php_patterns.env_calls {
-                let name = EnvVarName::new(db, env.var_name.to_string());
-                env_refs.push(EnvReference::new(
-                    db,
-                    name,
-                    env.has_fallback,
-                    env.row as u32,
-                    env.column as u32,
-                    env.end_column as u32,
-                ));
-            }
-
-            // Process config calls
-            for config in php_patterns.config_calls {
-                let key = ConfigKey::new(db, config.config_key.to_string());
-                config_refs.push(ConfigReference::new(
-                    db,
-                    key,
-                    config.row as u32,
-                    config.column as u32,
-                    config.end_column as u32,
-                ));
-            }
-
-            // Process middleware calls
-            for mw in php_patterns.middleware_calls {
-                let name = MiddlewareName::new(db, mw.middleware_name.to_string());
-                middleware_refs.push(MiddlewareReference::new(
-                    db,
-                    name,
-                    mw.row as u32,
-                    mw.column as u32,
-                    mw.end_column as u32,
-                ));
-            }
-
-            // Process translation calls
-            for trans in php_patterns.translation_calls {
-                let key = TranslationKey::new(db, trans.translation_key.to_string());
-                translation_refs.push(TranslationReference::new(
-                    db,
-                    key,
-                    trans.row as u32,
-                    trans.column as u32,
-                    trans.end_column as u32,
-                ));
-            }
-
-            // Process asset calls
-            for asset in php_patterns.asset_calls {
-                let path = AssetPath::new(db, asset.path.to_string());
-                let helper_type = match asset.helper_type {
-                    QueryAssetHelperType::Asset => AssetHelperType::Asset,
-                    QueryAssetHelperType::PublicPath => AssetHelperType::PublicPath,
-                    QueryAssetHelperType::BasePath => AssetHelperType::BasePath,
-                    QueryAssetHelperType::AppPath => AssetHelperType::AppPath,
-                    QueryAssetHelperType::StoragePath => AssetHelperType::StoragePath,
-                    QueryAssetHelperType::DatabasePath => AssetHelperType::DatabasePath,
-                    QueryAssetHelperType::LangPath => AssetHelperType::LangPath,
-                    QueryAssetHelperType::ConfigPath => AssetHelperType::ConfigPath,
-                    QueryAssetHelperType::ResourcePath => AssetHelperType::ResourcePath,
-                    QueryAssetHelperType::Mix => AssetHelperType::Mix,
-                    QueryAssetHelperType::ViteAsset => AssetHelperType::ViteAsset,
-                };
-                asset_refs.push(AssetReference::new(
-                    db,
-                    path,
-                    helper_type,
-                    asset.row as u32,
-                    asset.column as u32,
-                    asset.end_column as u32,
-                ));
-            }
+    // Parse PHP (including Blade files for embedded PHP and echo content) - single pass extraction
+    for php_patterns in extract_php_pattern_sets(text, &echo_sources) {
+        // Process views
+        for view in php_patterns.views {
+            let name = ViewName::new(db, view.view_name.to_string());
+            views.push(ViewReference::new(
+                db,
+                name,
+                view.row as u32,
+                view.column as u32,
+                view.end_column as u32,
+                view.is_route_view,
+                view.is_volt_route,
+            ));
+        }
 
-            // Process binding calls
-            for binding in php_patterns.binding_calls {
-                let name = BindingName::new(db, binding.binding_name.to_string());
-                binding_refs.push(BindingReference::new(
-                    db,
-                    name,
-                    binding.is_class_reference,
-                    binding.row as u32,
-                    binding.column as u32,
-                    binding.end_column as u32,
-                ));
-            }
+        // Process env calls
+        for env in php_patterns.env_calls {
+            let name = EnvVarName::new(db, env.var_name.to_string());
+            env_refs.push(EnvReference::new(
+                db,
+                name,
+                env.has_fallback,
+                env.row as u32,
+                env.column as u32,
+                env.end_column as u32,
+            ));
+        }
+
+        // Process config calls
+        for config in php_patterns.config_calls {
+            let key = ConfigKey::new(db, config.config_key.to_string());
+            config_refs.push(ConfigReference::new(
+                db,
+                key,
+                config.row as u32,
+                config.column as u32,
+                config.end_column as u32,
+                config.is_set,
+            ));
+        }
+
+        // Process middleware calls
+        for mw in php_patterns.middleware_calls {
+            let name = MiddlewareName::new(db, mw.middleware_name.to_string());
+            middleware_refs.push(MiddlewareReference::new(
+                db,
+                name,
+                mw.row as u32,
+                mw.column as u32,
+                mw.end_column as u32,
+            ));
+        }
+
+        // Process translation calls
+        for trans in php_patterns.translation_calls {
+            let key = TranslationKey::new(db, trans.translation_key.to_string());
+            translation_refs.push(TranslationReference::new(
+                db,
+                key,
+                trans.row as u32,
+                trans.column as u32,
+                trans.end_column as u32,
+                trans.is_existence_check,
+            ));
+        }
+
+        // Process asset calls
+        for asset in php_patterns.asset_calls {
+            let path = AssetPath::new(db, asset.path.to_string());
+            let helper_type = match asset.helper_type {
+                QueryAssetHelperType::Asset => AssetHelperType::Asset,
+                QueryAssetHelperType::PublicPath => AssetHelperType::PublicPath,
+                QueryAssetHelperType::BasePath => AssetHelperType::BasePath,
+                QueryAssetHelperType::AppPath => AssetHelperType::AppPath,
+                QueryAssetHelperType::StoragePath => AssetHelperType::StoragePath,
+                QueryAssetHelperType::DatabasePath => AssetHelperType::DatabasePath,
+                QueryAssetHelperType::LangPath => AssetHelperType::LangPath,
+                QueryAssetHelperType::ConfigPath => AssetHelperType::ConfigPath,
+                QueryAssetHelperType::ResourcePath => AssetHelperType::ResourcePath,
+                QueryAssetHelperType::Mix => AssetHelperType::Mix,
+                QueryAssetHelperType::ViteAsset => AssetHelperType::ViteAsset,
+            };
+            asset_refs.push(AssetReference::new(
+                db,
+                path,
+                helper_type,
+                asset.row as u32,
+                asset.column as u32,
+                asset.end_column as u32,
+            ));
+        }
 
-            // Note: route_refs, url_refs, action_refs are extracted in handle_get_patterns
-            // to keep ParsedPatterns field count under Salsa's 12-element limit
+        // Process binding calls
+        for binding in php_patterns.binding_calls {
+            let name = BindingName::new(db, binding.binding_name.to_string());
+            binding_refs.push(BindingReference::new(
+                db,
+                name,
+                binding.is_class_reference,
+                binding.row as u32,
+                binding.column as u32,
+                binding.end_column as u32,
+            ));
         }
+
+        // Note: route_refs, url_refs, action_refs are extracted in handle_get_patterns
+        // to keep ParsedPatterns field count under Salsa's 12-element limit
     }
 
     ParsedPatterns::new(
@@ -847,6 +1024,133 @@ pub fn parse_file_patterns<'db>(db: &'db dyn Db, file: SourceFile) -> ParsedPatt
     )
 }
 
+/// Parse the `@props` directive of a Blade component view
+/// Memoized per file, so repeated hovers don't re-parse an unchanged component
+#[salsa::tracked]
+pub fn parse_component_props<'db>(db: &'db dyn Db, file: SourceFile) -> Option<Vec<BladeProp>> {
+    parse_props_directive(file.text(db))
+}
+
+/// Parse the slot, section and stack names of a Blade file in one pass
+/// Kept out of ParsedPatterns, which is at Salsa's 12-field limit
+#[salsa::tracked]
+pub fn parse_blade_region_refs<'db>(
+    db: &'db dyn Db,
+    file: SourceFile,
+) -> (Vec<SlotReferenceData>, Vec<SectionReferenceData>, Vec<StackReferenceData>) {
+    use crate::parser::{parse_blade, language_blade};
+    use crate::queries::extract_all_blade_patterns;
+
+    if !file.is_blade(db) {
+        return Default::default();
+    }
+
+    let text = file.text(db);
+    let Ok(tree) = parse_blade(text) else {
+        return Default::default();
+    };
+    let Ok(blade_patterns) = extract_all_blade_patterns(&tree, text, &language_blade()) else {
+        return Default::default();
+    };
+
+    let slots = blade_patterns
+        .slots
+        .into_iter()
+        .map(|slot| SlotReferenceData {
+            name: slot.slot_name.to_string(),
+            component: slot.component_name.map(|c| c.to_string()),
+            line: slot.row as u32,
+            column: slot.column as u32,
+            end_column: slot.end_column as u32,
+        })
+        .collect();
+    let sections = blade_patterns
+        .sections
+        .into_iter()
+        .map(|section| SectionReferenceData {
+            name: section.section_name.to_string(),
+            is_yield: section.is_yield,
+            line: section.row as u32,
+            column: section.column as u32,
+            end_column: section.end_column as u32,
+        })
+        .collect();
+    let stacks = blade_patterns
+        .stacks
+        .into_iter()
+        .map(|stack| StackReferenceData {
+            name: stack.stack_name.to_string(),
+            is_stack: stack.is_stack,
+            line: stack.row as u32,
+            column: stack.column as u32,
+            end_column: stack.end_column as u32,
+        })
+        .collect();
+
+    (slots, sections, stacks)
+}
+
+/// The `<x-slot>` tags of a Blade file
+#[salsa::tracked]
+pub fn parse_slot_refs<'db>(db: &'db dyn Db, file: SourceFile) -> Vec<SlotReferenceData> {
+    parse_blade_region_refs(db, file).0
+}
+
+/// The `@yield`/`@section` names of a Blade file
+#[salsa::tracked]
+pub fn parse_section_refs<'db>(db: &'db dyn Db, file: SourceFile) -> Vec<SectionReferenceData> {
+    parse_blade_region_refs(db, file).1
+}
+
+/// The `@stack`/`@push` names of a Blade file
+#[salsa::tracked]
+pub fn parse_stack_refs<'db>(db: &'db dyn Db, file: SourceFile) -> Vec<StackReferenceData> {
+    parse_blade_region_refs(db, file).2
+}
+
+/// The layout a Blade view extends, i.e. the view name in its first `@extends`
+/// Memoized per file, so walking layout chains only re-parses changed views
+#[salsa::tracked]
+pub fn parse_extends_target<'db>(db: &'db dyn Db, file: SourceFile) -> Option<String> {
+    parse_file_patterns(db, file)
+        .directives(db)
+        .iter()
+        .find(|d| d.name(db).name(db) == "extends")
+        .and_then(|d| d.arguments(db).as_deref().and_then(extract_view_from_args))
+}
+
+/// The section names a layout yields, defines or checks for
+///
+/// Read from `@yield`, `@section` (including `@section ... @show`),
+/// `@hasSection` and `@sectionMissing` among the parsed directives, so names in
+/// comments and `@verbatim` blocks don't count. None when a `@yield` takes a
+/// dynamic name, since any section could then be filled.
+#[salsa::tracked]
+pub fn parse_layout_section_names<'db>(db: &'db dyn Db, file: SourceFile) -> Option<Vec<String>> {
+    let mut names = Vec::new();
+    for directive in parse_file_patterns(db, file).directives(db) {
+        let directive_name = directive.name(db).name(db);
+        if !matches!(directive_name.as_str(), "yield" | "section" | "hasSection" | "sectionMissing") {
+            continue;
+        }
+        let Some(arguments) = directive.arguments(db).as_deref() else {
+            continue;
+        };
+
+        let arguments = arguments.trim().trim_start_matches('(');
+        let end = find_closing_delimiter(arguments, '(', ')').unwrap_or(arguments.len());
+        let first = split_top_level(&arguments[..end], ',')[0].trim();
+        let quoted = first.len() >= 2
+            && (first.starts_with('\'') && first.ends_with('\'') || first.starts_with('"') && first.ends_with('"'));
+        if quoted {
+            names.push(first[1..first.len() - 1].to_string());
+        } else if directive_name == "yield" && !first.is_empty() {
+            return None;
+        }
+    }
+    Some(names)
+}
+
 /// Parse composer.json to detect installed packages
 /// Returns (has_livewire, list of installed packages)
 #[salsa::tracked]
@@ -917,21 +1221,44 @@ pub fn parse_view_config<'db>(db: &'db dyn Db, file: ConfigFile, root: PathBuf)
     paths
 }
 
-/// Parse config/livewire.php to extract Livewire component path
+/// Parse config/livewire.php for the component view path
+/// (`class_namespace` is resolved through PSR-4 by `LaravelPaths`)
 #[salsa::tracked]
-pub fn parse_livewire_config<'db>(db: &'db dyn Db, file: ConfigFile, root: PathBuf) -> Option<PathBuf> {
-    let text = file.text(db);
+pub fn parse_livewire_config<'db>(
+    db: &'db dyn Db,
+    file: ConfigFile,
+    root: PathBuf,
+) -> Option<PathBuf> {
+    use lazy_static::lazy_static;
+    use regex::Regex;
 
-    // Look for class_namespace patterns
-    if text.contains("App\\Livewire") || text.contains("App\\\\Livewire") {
-        return Some(root.join("app/Livewire"));
+    lazy_static! {
+        /// Matches 'view_path' => resource_path('views/livewire')
+        static ref VIEW_PATH_RE: Regex = Regex::new(
+            r#"['"]view_path['"]\s*=>\s*(resource_path|base_path|app_path)\s*\(\s*['"]([^'"]*)['"]\s*\)"#
+        ).unwrap();
     }
 
-    if text.contains("App\\Http\\Livewire") || text.contains("App\\\\Http\\\\Livewire") {
-        return Some(root.join("app/Http/Livewire"));
-    }
+    let text = file.text(db);
 
-    None
+    VIEW_PATH_RE.captures(text).map(|cap| {
+        let base = match &cap[1] {
+            "resource_path" => root.join("resources"),
+            "app_path" => root.join("app"),
+            _ => root.clone(),
+        };
+        let relative = cap[2].trim_start_matches('/');
+        if relative.is_empty() { base } else { base.join(relative) }
+    })
+}
+
+/// Parse a config/*.php file into its `'key' => value` entries
+///
+/// Memoized per file, so completion and validation don't re-read the
+/// project's config files on every keystroke.
+#[salsa::tracked]
+pub fn parse_config_file_entries<'db>(db: &'db dyn Db, file: ConfigFile) -> Vec<ConfigEntry> {
+    parse_config_entries(file.text(db))
 }
 
 /// Build complete Laravel configuration from individual config files
@@ -959,22 +1286,22 @@ pub fn build_laravel_config<'db>(
         .map(|p| (String::new(), p.join("components")))
         .collect();
 
-    // Parse livewire config for component path
-    let livewire_path = if has_livewire {
-        livewire_config
-            .and_then(|f| parse_livewire_config(db, f, root.clone()))
-            .or_else(|| {
-                // Default Livewire paths
-                let v3_path = root.join("app/Livewire");
-                let v2_path = root.join("app/Http/Livewire");
-                if v3_path.exists() {
-                    Some(v3_path)
-                } else if v2_path.exists() {
-                    Some(v2_path)
-                } else {
-                    Some(v3_path) // Default to v3 path
-                }
-            })
+    // Livewire classes: config/livewire.php's class_namespace through the PSR-4 map,
+    // else the v3 (app/Livewire) or v2 (app/Http/Livewire) default
+    let livewire_path = has_livewire.then(|| {
+        LaravelPaths::from_sources(
+            &root,
+            composer.map(|f| f.text(db).as_str()),
+            livewire_config.map(|f| f.text(db).as_str()),
+        )
+        .livewire_dir
+    });
+
+    // Parse livewire config for the component view path
+    let configured_view_path = livewire_config.and_then(|f| parse_livewire_config(db, f, root.clone()));
+
+    let livewire_view_path = if has_livewire {
+        Some(configured_view_path.unwrap_or_else(|| root.join("resources/views/livewire")))
     } else {
         None
     };
@@ -985,6 +1312,7 @@ pub fn build_laravel_config<'db>(
         view_paths,
         component_paths,
         livewire_path,
+        livewire_view_path,
         has_livewire,
     )
 }
@@ -1072,6 +1400,18 @@ pub fn parse_env_source<'db>(db: &'db dyn Db, file: EnvFile) -> Vec<ParsedEnvVar
     variables
 }
 
+/// Parse a JSON translation file into its keys
+///
+/// Invalid JSON (e.g. mid-edit) yields no keys. The set is shared behind an
+/// Arc since every validation of a file with text keys reads it.
+#[salsa::tracked]
+pub fn parse_json_translation_keys<'db>(db: &'db dyn Db, file: JsonTranslationFile) -> Arc<HashSet<String>> {
+    match serde_json::from_str::<serde_json::Value>(file.text(db)) {
+        Ok(serde_json::Value::Object(entries)) => Arc::new(entries.into_iter().map(|(key, _)| key).collect()),
+        _ => Arc::new(HashSet::new()),
+    }
+}
+
 /// Parse an environment variable value, handling quotes
 fn parse_env_value_internal(value: &str) -> String {
     let value = value.trim();
@@ -1167,6 +1507,42 @@ pub struct ParsedViewNamespaceReg<'db> {
     pub source_file: PathBuf,
 }
 
+/// A parsed translation namespace registration from loadTranslationsFrom() (Salsa tracked)
+/// Example: $this->loadTranslationsFrom(__DIR__.'/../lang', 'courier')
+#[salsa::tracked]
+pub struct ParsedTranslationNamespaceReg<'db> {
+    /// Package namespace (e.g., "courier")
+    pub namespace: PackageNamespace<'db>,
+    /// Resolved lang path (if found)
+    #[returns(ref)]
+    pub lang_path: Option<PathBuf>,
+    /// Line in source file where registered
+    pub source_line: u32,
+    /// Priority (0=framework, 1=package, 2=app)
+    pub priority: u8,
+    /// Source file where registered
+    #[returns(ref)]
+    pub source_file: PathBuf,
+}
+
+/// A parsed package config registration from mergeConfigFrom() (Salsa tracked)
+/// Example: $this->mergeConfigFrom(__DIR__.'/../config/courier.php', 'courier')
+#[salsa::tracked]
+pub struct ParsedConfigMergeReg<'db> {
+    /// Top-level config key (e.g., "courier" for `config('courier.driver')`)
+    pub key: PackageNamespace<'db>,
+    /// Resolved path of the package's config file (if found)
+    #[returns(ref)]
+    pub config_path: Option<PathBuf>,
+    /// Line in source file where registered
+    pub source_line: u32,
+    /// Priority (0=framework, 1=package, 2=app)
+    pub priority: u8,
+    /// Source file where registered
+    #[returns(ref)]
+    pub source_file: PathBuf,
+}
+
 /// A parsed Blade component registration from Blade::component() (Salsa tracked)
 /// Example: Blade::component('package-alert', AlertComponent::class)
 #[salsa::tracked]
@@ -1188,6 +1564,43 @@ pub struct ParsedBladeComponentReg<'db> {
     pub source_file: PathBuf,
 }
 
+/// A parsed Livewire component registration from Livewire::component() (Salsa tracked)
+/// Example: Livewire::component('custom-name', \App\Http\Livewire\Custom::class)
+#[salsa::tracked]
+pub struct ParsedLivewireComponentReg<'db> {
+    /// Component alias (e.g., "custom-name")
+    pub alias: ComponentName<'db>,
+    /// Full class name
+    #[returns(ref)]
+    pub class_name: String,
+    /// Resolved file path (if found)
+    #[returns(ref)]
+    pub file_path: Option<PathBuf>,
+    /// Line in source file where registered
+    pub source_line: u32,
+    /// Priority (0=framework, 1=package, 2=app)
+    pub priority: u8,
+    /// Source file where registered
+    #[returns(ref)]
+    pub source_file: PathBuf,
+}
+
+/// A parsed custom Blade directive from Blade::directive() (Salsa tracked)
+/// Example: Blade::directive('datetime', fn ($expression) => ...)
+#[salsa::tracked]
+pub struct ParsedBladeDirectiveReg<'db> {
+    /// Directive name without the @ (e.g., "datetime")
+    #[returns(ref)]
+    pub name: String,
+    /// Line in source file where registered
+    pub source_line: u32,
+    /// Priority (0=framework, 1=package, 2=app)
+    pub priority: u8,
+    /// Source file where registered
+    #[returns(ref)]
+    pub source_file: PathBuf,
+}
+
 /// A parsed component namespace registration from Blade::componentNamespace() (Salsa tracked)
 /// Example: Blade::componentNamespace('Nightshade\\Views\\Components', 'nightshade')
 #[salsa::tracked]
@@ -1215,15 +1628,34 @@ pub struct ParsedServiceProvider<'db> {
     /// Container bindings found in this provider
     #[returns(ref)]
     pub bindings: Vec<ParsedBindingReg<'db>>,
-    /// View namespace registrations from loadViewsFrom()
+    /// View namespace registrations from loadViewsFrom() and View::addNamespace()
     #[returns(ref)]
     pub view_namespaces: Vec<ParsedViewNamespaceReg<'db>>,
+    /// Extra view locations from View::addLocation() / prependLocation(),
+    /// with whether the location was prepended
+    #[returns(ref)]
+    pub view_locations: Vec<(PathBuf, bool)>,
     /// Manual Blade component registrations from Blade::component()
     #[returns(ref)]
     pub blade_components: Vec<ParsedBladeComponentReg<'db>>,
     /// Component namespace registrations from Blade::componentNamespace()
     #[returns(ref)]
     pub component_namespaces: Vec<ParsedComponentNamespaceReg<'db>>,
+    /// Translation namespace registrations from loadTranslationsFrom()
+    #[returns(ref)]
+    pub translation_namespaces: Vec<ParsedTranslationNamespaceReg<'db>>,
+    /// Package config files merged with mergeConfigFrom()
+    #[returns(ref)]
+    pub config_merges: Vec<ParsedConfigMergeReg<'db>>,
+    /// Explicit Livewire component registrations from Livewire::component()
+    #[returns(ref)]
+    pub livewire_components: Vec<ParsedLivewireComponentReg<'db>>,
+    /// Custom directives from Blade::directive()
+    #[returns(ref)]
+    pub blade_directives: Vec<ParsedBladeDirectiveReg<'db>>,
+    /// Route files loaded inside a named group, with the group's name prefix
+    #[returns(ref)]
+    pub route_file_prefixes: Vec<(PathBuf, String)>,
 }
 
 /// Parse a service provider file and extract middleware, bindings, views, and components
@@ -1247,6 +1679,11 @@ pub fn parse_service_provider_source<'db>(
             r#"\$this->app->(bind|singleton)\s*\(\s*['"]([^'"]+)['"](?:\s*,\s*(?:function|\[)?[^)]*\\?([A-Za-z0-9_\\]+)(?:::class)?)?\s*\)"#
         ).unwrap();
 
+        /// Matches $this->app->bind(Contract::class, Concrete::class), ->singleton(...) or ->scoped(...)
+        static ref CLASS_BINDING_RE: Regex = Regex::new(
+            r#"\$this->app->(bind|singleton|scoped)\s*\(\s*(\\?[A-Za-z0-9_\\]+)::class\s*,\s*(\\?[A-Za-z0-9_\\]+)::class\s*\)"#
+        ).unwrap();
+
         /// Matches $this->app->alias('concrete', 'alias')
         static ref ALIAS_RE: Regex = Regex::new(
             r#"\$this->app->alias\s*\(\s*\\?([A-Za-z0-9_\\]+)(?:::class)?\s*,\s*['"]([^'"]+)['"]\s*\)"#
@@ -1257,26 +1694,86 @@ pub fn parse_service_provider_source<'db>(
             r#"\$this->loadViewsFrom\s*\(\s*__DIR__\s*\.\s*['"]([^'"]+)['"]\s*,\s*['"]([^'"]+)['"]\s*\)"#
         ).unwrap();
 
+        /// Matches View::addNamespace('theme', resource_path('themes/default'))
+        /// (also prependNamespace/replaceNamespace and view()->addNamespace())
+        static ref VIEW_ADD_NAMESPACE_RE: Regex = Regex::new(
+            r#"(?:View::|view\(\)->)(?:add|prepend|replace)Namespace\s*\(\s*['"]([^'"]+)['"]\s*,\s*((?:resource_path|base_path|app_path|storage_path|public_path)\s*\(\s*(?:['"][^'"]*['"])?\s*\)|__DIR__\s*\.\s*['"][^'"]+['"])"#
+        ).unwrap();
+
+        /// Matches View::addLocation(resource_path('themes/default')) or
+        /// View::getFinder()->prependLocation(...)
+        static ref VIEW_ADD_LOCATION_RE: Regex = Regex::new(
+            r#"(?:View::|view\(\)->|getFinder\(\)->)(add|prepend)Location\s*\(\s*((?:resource_path|base_path|app_path|storage_path|public_path)\s*\(\s*(?:['"][^'"]*['"])?\s*\)|__DIR__\s*\.\s*['"][^'"]+['"])"#
+        ).unwrap();
+
+        /// Matches $this->loadTranslationsFrom(__DIR__.'/../lang', 'namespace')
+        static ref LOAD_TRANSLATIONS_RE: Regex = Regex::new(
+            r#"\$this->loadTranslationsFrom\s*\(\s*__DIR__\s*\.\s*['"]([^'"]+)['"]\s*,\s*['"]([^'"]+)['"]\s*\)"#
+        ).unwrap();
+
+        /// Matches $this->mergeConfigFrom(__DIR__.'/../config/file.php', 'key')
+        static ref MERGE_CONFIG_RE: Regex = Regex::new(
+            r#"\$this->mergeConfigFrom\s*\(\s*__DIR__\s*\.\s*['"]([^'"]+)['"]\s*,\s*['"]([^'"]+)['"]\s*\)"#
+        ).unwrap();
+
         /// Matches Blade::component('tag-name', Class::class)
         static ref BLADE_COMPONENT_RE: Regex = Regex::new(
             r#"Blade::component\s*\(\s*['"]([^'"]+)['"]\s*,\s*\\?([A-Za-z0-9_\\]+)::class\s*\)"#
         ).unwrap();
 
+        /// Matches Livewire::component('alias', Class::class)
+        static ref LIVEWIRE_COMPONENT_RE: Regex = Regex::new(
+            r#"Livewire::component\s*\(\s*['"]([^'"]+)['"]\s*,\s*\\?([A-Za-z0-9_\\]+)::class\s*\)"#
+        ).unwrap();
+
+        /// Matches Blade::directive('name', ...)
+        static ref BLADE_DIRECTIVE_RE: Regex = Regex::new(
+            r#"Blade::directive\s*\(\s*['"]([A-Za-z_][A-Za-z0-9_]*)['"]"#
+        ).unwrap();
+
         /// Matches Blade::componentNamespace('Namespace\\Path', 'prefix')
         static ref COMPONENT_NAMESPACE_RE: Regex = Regex::new(
             r#"Blade::componentNamespace\s*\(\s*['"]([^'"]+)['"]\s*,\s*['"]([^'"]+)['"]\s*\)"#
         ).unwrap();
-    }
 
-    let text = file.text(db);
+        /// Matches $middleware->appendToGroup('web', [...]), ->prependToGroup(...), ->group(...)
+        /// (Laravel 11+ bootstrap/app.php withMiddleware() closures)
+        static ref MIDDLEWARE_GROUP_CALL_RE: Regex = Regex::new(
+            r#"\$middleware->(?:appendToGroup|prependToGroup|group)\s*\(\s*['"]([^'"]+)['"]\s*,\s*(\[[^\]]*\]|[^)]*)"#
+        ).unwrap();
+
+        /// Matches the start of a middleware groups array:
+        /// - protected $middlewareGroups = [ (Laravel 10 Kernel)
+        /// - function getMiddlewareGroups() { $middleware = [ (framework defaults)
+        static ref MIDDLEWARE_GROUPS_BLOCK_RE: Regex = Regex::new(
+            r#"(?:\$middlewareGroups\s*=\s*|function\s+getMiddlewareGroups\s*\(\s*\)[^{]*\{\s*\$middleware\s*=\s*)\["#
+        ).unwrap();
+
+        /// Matches a group entry inside a middleware groups array: 'web' => [ ...
+        static ref MIDDLEWARE_GROUP_KEY_RE: Regex = Regex::new(
+            r#"['"]([A-Za-z0-9_\-:]+)['"]\s*=>\s*(?:array_values\s*\(\s*array_filter\s*\(\s*)?\["#
+        ).unwrap();
+
+        /// Matches a Class::class reference (group members)
+        static ref CLASS_REF_RE: Regex = Regex::new(
+            r#"\\?([A-Za-z0-9_\\]+)::class"#
+        ).unwrap();
+    }
+
+    let text = file.text(db);
     let path = file.path(db);
     let priority = file.priority(db);
 
     let mut middleware = Vec::new();
     let mut bindings = Vec::new();
     let mut view_namespaces = Vec::new();
+    let mut view_locations = Vec::new();
     let mut blade_components = Vec::new();
     let mut component_namespaces = Vec::new();
+    let mut translation_namespaces = Vec::new();
+    let mut config_merges = Vec::new();
+    let mut livewire_components = Vec::new();
+    let mut blade_directives = Vec::new();
 
     // Parse middleware registrations
     for cap in MIDDLEWARE_RE.captures_iter(text) {
@@ -1303,6 +1800,65 @@ pub fn parse_service_provider_source<'db>(
         }
     }
 
+    // Parse middleware groups so group names (web, api, ...) resolve like aliases.
+    // Groups have no class file - the "class" is a summary of the members.
+    let group_reg = |name: &str, members: Vec<&str>, name_start: usize| {
+        let line = text[..name_start].lines().count() as u32;
+        let summary = if members.is_empty() {
+            "middleware group".to_string()
+        } else {
+            format!("middleware group: {}", members.join(", "))
+        };
+        ParsedMiddlewareReg::new(
+            db,
+            MiddlewareName::new(db, name.to_string()),
+            summary,
+            None,
+            line,
+            priority,
+            path.clone(),
+        )
+    };
+
+    // $middleware->appendToGroup('web', [...]) / prependToGroup / group
+    for cap in MIDDLEWARE_GROUP_CALL_RE.captures_iter(text) {
+        if let (Some(group), Some(members)) = (cap.get(1), cap.get(2)) {
+            let member_classes: Vec<&str> = CLASS_REF_RE
+                .captures_iter(members.as_str())
+                .filter_map(|c| c.get(1).map(|m| m.as_str()))
+                .collect();
+            middleware.push(group_reg(group.as_str(), member_classes, group.start()));
+        }
+    }
+
+    // $middlewareGroups = ['web' => [...], 'api' => [...]]
+    for block in MIDDLEWARE_GROUPS_BLOCK_RE.find_iter(text) {
+        let open = block.end() - 1;
+        let Some(close) = find_matching_bracket(text, open) else {
+            continue;
+        };
+
+        // Only top-level keys of the block are group names
+        let mut search_from = open + 1;
+        while let Some(cap) = MIDDLEWARE_GROUP_KEY_RE.captures_at(text, search_from) {
+            let whole = cap.get(0).unwrap();
+            if whole.start() >= close {
+                break;
+            }
+            let group = cap.get(1).unwrap();
+            let value_open = whole.end() - 1;
+            let value_close = find_matching_bracket(text, value_open).unwrap_or(close);
+
+            let member_classes: Vec<&str> = CLASS_REF_RE
+                .captures_iter(&text[value_open..value_close])
+                .filter_map(|c| c.get(1).map(|m| m.as_str()))
+                .collect();
+            middleware.push(group_reg(group.as_str(), member_classes, group.start()));
+
+            search_from = value_close + 1;
+        }
+    }
+
     // Parse bind/singleton registrations
     for cap in BINDING_RE.captures_iter(text) {
         if let (Some(method), Some(name)) = (cap.get(1), cap.get(2)) {
@@ -1334,6 +1890,36 @@ pub fn parse_service_provider_source<'db>(
         }
     }
 
+    // Parse interface-to-concrete registrations, keyed by the contract's
+    // fully qualified name so `app(Contract::class)` can find its concrete
+    for cap in CLASS_BINDING_RE.captures_iter(text) {
+        let (Some(method), Some(abstract_class), Some(concrete)) = (cap.get(1), cap.get(2), cap.get(3)) else {
+            continue;
+        };
+        let binding_type = match method.as_str() {
+            "singleton" => BindingTypeEnum::Singleton,
+            _ => BindingTypeEnum::Bind,
+        };
+
+        let abstract_name = resolve_imported_class(text, abstract_class.as_str());
+        let concrete_class = resolve_imported_class(text, concrete.as_str());
+
+        let line = text[..abstract_class.start()].lines().count() as u32;
+        let file_path = resolve_class_to_file_internal(&concrete_class, &root);
+
+        let binding_name = BindingName::new(db, abstract_name);
+        bindings.push(ParsedBindingReg::new(
+            db,
+            binding_name,
+            concrete_class,
+            file_path,
+            binding_type,
+            line,
+            priority,
+            path.clone(),
+        ));
+    }
+
     // Parse alias registrations
     for cap in ALIAS_RE.captures_iter(text) {
         if let (Some(concrete), Some(alias)) = (cap.get(1), cap.get(2)) {
@@ -1394,6 +1980,32 @@ pub fn parse_service_provider_source<'db>(
         }
     }
 
+    // Parse runtime view namespaces (theme systems)
+    // Example: View::addNamespace('theme', resource_path('themes/default'))
+    let provider_dir = path.parent().unwrap_or(path.as_path());
+    for cap in VIEW_ADD_NAMESPACE_RE.captures_iter(text) {
+        if let (Some(namespace), Some(expression)) = (cap.get(1), cap.get(2)) {
+            let line = text[..namespace.start()].lines().count() as u32;
+            let pkg_namespace = PackageNamespace::new(db, namespace.as_str().to_string());
+            view_namespaces.push(ParsedViewNamespaceReg::new(
+                db,
+                pkg_namespace,
+                resolve_path_expression(expression.as_str(), provider_dir, &root),
+                line,
+                priority,
+                path.clone(),
+            ));
+        }
+    }
+
+    // Parse extra view locations searched for un-namespaced views
+    // Example: View::addLocation(resource_path('themes/default'))
+    for cap in VIEW_ADD_LOCATION_RE.captures_iter(text) {
+        if let Some(location) = resolve_path_expression(&cap[2], provider_dir, &root) {
+            view_locations.push((location, &cap[1] == "prepend"));
+        }
+    }
+
     // Parse Blade::component() registrations
     // Example: Blade::component('package-alert', AlertComponent::class)
     for cap in BLADE_COMPONENT_RE.captures_iter(text) {
@@ -1417,6 +2029,43 @@ pub fn parse_service_provider_source<'db>(
         }
     }
 
+    // Parse Livewire::component() registrations
+    // Example: Livewire::component('custom-name', \App\Http\Livewire\Custom::class)
+    for cap in LIVEWIRE_COMPONENT_RE.captures_iter(text) {
+        if let (Some(alias), Some(class)) = (cap.get(1), cap.get(2)) {
+            let class_str = class.as_str().trim_start_matches('\\');
+
+            let line = text[..alias.start()].lines().count() as u32;
+            let file_path = resolve_class_to_file_internal(class_str, &root);
+
+            let component_name = ComponentName::new(db, alias.as_str().to_string());
+            livewire_components.push(ParsedLivewireComponentReg::new(
+                db,
+                component_name,
+                class_str.to_string(),
+                file_path,
+                line,
+                priority,
+                path.clone(),
+            ));
+        }
+    }
+
+    // Parse Blade::directive() registrations
+    // Example: Blade::directive('datetime', fn ($expression) => "<?php echo ...; ?>")
+    for cap in BLADE_DIRECTIVE_RE.captures_iter(text) {
+        if let Some(name) = cap.get(1) {
+            let line = text[..name.start()].lines().count() as u32;
+            blade_directives.push(ParsedBladeDirectiveReg::new(
+                db,
+                name.as_str().to_string(),
+                line,
+                priority,
+                path.clone(),
+            ));
+        }
+    }
+
     // Parse Blade::componentNamespace() registrations
     // Example: Blade::componentNamespace('Nightshade\\Views\\Components', 'nightshade')
     for cap in COMPONENT_NAMESPACE_RE.captures_iter(text) {
@@ -1438,16 +2087,240 @@ pub fn parse_service_provider_source<'db>(
         }
     }
 
+    // Parse loadTranslationsFrom() registrations
+    // Example: $this->loadTranslationsFrom(__DIR__.'/../lang', 'courier')
+    for cap in LOAD_TRANSLATIONS_RE.captures_iter(text) {
+        if let (Some(relative_path), Some(namespace)) = (cap.get(1), cap.get(2)) {
+            let line = text[..namespace.start()].lines().count() as u32;
+
+            // __DIR__ is the directory containing the service provider file
+            let provider_dir = path.parent().unwrap_or(path.as_path());
+            let lang_path = dir_relative_path(provider_dir, relative_path.as_str());
+            let resolved_path = if lang_path.exists() {
+                lang_path.canonicalize().unwrap_or(lang_path)
+            } else {
+                lang_path
+            };
+
+            let pkg_namespace = PackageNamespace::new(db, namespace.as_str().to_string());
+            translation_namespaces.push(ParsedTranslationNamespaceReg::new(
+                db,
+                pkg_namespace,
+                Some(resolved_path),
+                line,
+                priority,
+                path.clone(),
+            ));
+        }
+    }
+
+    // Parse mergeConfigFrom() registrations
+    // Example: $this->mergeConfigFrom(__DIR__.'/../config/courier.php', 'courier')
+    for cap in MERGE_CONFIG_RE.captures_iter(text) {
+        if let (Some(relative_path), Some(key)) = (cap.get(1), cap.get(2)) {
+            let line = text[..key.start()].lines().count() as u32;
+
+            // __DIR__ is the directory containing the service provider file
+            let provider_dir = path.parent().unwrap_or(path.as_path());
+            let config_path = dir_relative_path(provider_dir, relative_path.as_str());
+            let resolved_path = if config_path.exists() {
+                config_path.canonicalize().unwrap_or(config_path)
+            } else {
+                config_path
+            };
+
+            let config_key = PackageNamespace::new(db, key.as_str().to_string());
+            config_merges.push(ParsedConfigMergeReg::new(
+                db,
+                config_key,
+                Some(resolved_path),
+                line,
+                priority,
+                path.clone(),
+            ));
+        }
+    }
+
     ParsedServiceProvider::new(
         db,
         middleware,
         bindings,
         view_namespaces,
+        view_locations,
         blade_components,
         component_namespaces,
+        translation_namespaces,
+        config_merges,
+        livewire_components,
+        blade_directives,
+        route_group_file_prefixes(text, path.parent().unwrap_or(path.as_path()), &root),
     )
 }
 
+/// Name prefixes of route groups loading a routes file from `group(...)`,
+/// memoized per routes file (e.g. web.php loading routes/admin.php)
+#[salsa::tracked]
+pub fn parse_route_file_prefixes<'db>(db: &'db dyn Db, file: SourceFile, root: PathBuf) -> Vec<(PathBuf, String)> {
+    let path = file.path(db);
+    route_group_file_prefixes(file.text(db), path.parent().unwrap_or(path.as_path()), &root)
+}
+
+/// Authorization registrations in a service provider
+#[salsa::tracked]
+pub struct ParsedAuthorization<'db> {
+    /// Abilities from `Gate::define()`, with the line and column of the name
+    #[returns(ref)]
+    pub gates: Vec<(String, u32, u32)>,
+    /// Fully qualified (model, policy) classes from `Gate::policy()`
+    #[returns(ref)]
+    pub policies: Vec<(String, String)>,
+}
+
+/// Parse a service provider's `Gate::define()` and `Gate::policy()` calls,
+/// memoized per provider
+/// Policy class names are resolved through the provider's `use` imports.
+#[salsa::tracked]
+pub fn parse_authorization_source<'db>(db: &'db dyn Db, file: ServiceProviderFile) -> ParsedAuthorization<'db> {
+    use lazy_static::lazy_static;
+    use regex::Regex;
+
+    lazy_static! {
+        static ref GATE_DEFINE_RE: Regex = Regex::new(r#"Gate::define\(\s*['"]([^'"]+)['"]"#).unwrap();
+        static ref GATE_POLICY_RE: Regex = Regex::new(
+            r"Gate::policy\(\s*(\\?[A-Za-z0-9_\\]+)::class\s*,\s*(\\?[A-Za-z0-9_\\]+)::class"
+        ).unwrap();
+    }
+
+    let text = file.text(db);
+    let mut gates = Vec::new();
+    for (line_idx, line) in text.lines().enumerate() {
+        for caps in GATE_DEFINE_RE.captures_iter(line) {
+            let name = caps.get(1).unwrap();
+            gates.push((name.as_str().to_string(), line_idx as u32, name.start() as u32));
+        }
+    }
+
+    let policies = GATE_POLICY_RE
+        .captures_iter(text)
+        .map(|caps| (resolve_imported_class(text, &caps[1]), resolve_imported_class(text, &caps[2])))
+        .collect();
+
+    ParsedAuthorization::new(db, gates, policies)
+}
+
+/// Route files loaded by a named route group, with the prefix their route names get
+///
+/// Covers `->name('api.')` / `->as('api.')` chains ending in
+/// `->group(base_path('routes/api.php'))`, and `Route::group(['as' => 'admin.'], ...)`
+/// whose closure calls `$this->loadRoutesFrom(__DIR__.'/../routes/web.php')` or
+/// requires the file. Nested groups contribute their prefixes outermost first.
+pub fn route_group_file_prefixes(text: &str, file_dir: &Path, root: &Path) -> Vec<(PathBuf, String)> {
+    use lazy_static::lazy_static;
+    use regex::Regex;
+
+    lazy_static! {
+        static ref NAMED_GROUP_RE: Regex = Regex::new(
+            r#"(?:Route::|->)\s*(?:name|as)\s*\(\s*['"]([^'"]*)['"]\s*\)(?:\s*->\s*\w+\s*\([^()]*\))*\s*->\s*group\s*\("#
+        ).unwrap();
+        static ref ARRAY_GROUP_RE: Regex = Regex::new(r"Route::group\s*\(").unwrap();
+        static ref AS_KEY_RE: Regex = Regex::new(r#"['"]as['"]\s*=>\s*['"]([^'"]*)['"]"#).unwrap();
+        static ref ROUTE_FILE_RE: Regex = Regex::new(
+            r#"base_path\s*\(\s*['"]([^'"]+\.php)['"]\s*\)|__DIR__\s*\.\s*['"]([^'"]+\.php)['"]"#
+        ).unwrap();
+    }
+
+    // (argument span of the group call, prefix)
+    let mut groups: Vec<(std::ops::Range<usize>, &str)> = Vec::new();
+    for caps in NAMED_GROUP_RE.captures_iter(text) {
+        let (Some(whole), Some(prefix)) = (caps.get(0), caps.get(1)) else { continue };
+        let arguments = &text[whole.end()..];
+        if let Some(end) = find_closing_delimiter(arguments, '(', ')') {
+            groups.push((whole.end()..whole.end() + end, prefix.as_str()));
+        }
+    }
+    for group in ARRAY_GROUP_RE.find_iter(text) {
+        let arguments = &text[group.end()..];
+        // The attribute array comes first: Route::group(['as' => 'admin.', ...], ...)
+        let open = arguments.len() - arguments.trim_start().len();
+        if !arguments[open..].starts_with('[') {
+            continue;
+        }
+        let (Some(end), Some(close)) = (find_closing_delimiter(arguments, '(', ')'), find_matching_bracket(arguments, open)) else {
+            continue;
+        };
+        if let Some(prefix) = AS_KEY_RE.captures(&arguments[open..close]).and_then(|caps| caps.get(1)) {
+            groups.push((group.end()..group.end() + end, prefix.as_str()));
+        }
+    }
+    groups.sort_by_key(|(span, _)| span.start);
+
+    let mut prefixes = Vec::new();
+    for caps in ROUTE_FILE_RE.captures_iter(text) {
+        let Some(whole) = caps.get(0) else { continue };
+        let prefix: String = groups
+            .iter()
+            .filter(|(span, _)| span.contains(&whole.start()))
+            .map(|(_, prefix)| *prefix)
+            .collect();
+        if prefix.is_empty() {
+            continue;
+        }
+        let path = match (caps.get(1), caps.get(2)) {
+            (Some(relative), _) => normalize_path(&root.join(relative.as_str().trim_start_matches('/'))),
+            (None, Some(relative)) => dir_relative_path(file_dir, relative.as_str()),
+            _ => continue,
+        };
+        prefixes.push((path, prefix));
+    }
+    prefixes
+}
+
+/// Find the index of the `]` matching the `[` at `open`, skipping quoted strings
+fn find_matching_bracket(text: &str, open: usize) -> Option<usize> {
+    find_closing_delimiter(text.get(open + 1..)?, '[', ']').map(|close| open + 1 + close)
+}
+
+/// Resolve a path expression from a service provider to an absolute path
+///
+/// Handles Laravel path helpers (`resource_path('themes')`, `base_path()`, ...)
+/// and `__DIR__.'/../views'` relative to the provider file.
+fn resolve_path_expression(expression: &str, provider_dir: &Path, root: &Path) -> Option<PathBuf> {
+    use lazy_static::lazy_static;
+    use regex::Regex;
+
+    lazy_static! {
+        static ref HELPER_RE: Regex = Regex::new(
+            r#"^(resource_path|base_path|app_path|storage_path|public_path)\s*\(\s*(?:['"]([^'"]*)['"])?\s*\)$"#
+        ).unwrap();
+        static ref DIR_RE: Regex = Regex::new(r#"^__DIR__\s*\.\s*['"]([^'"]+)['"]$"#).unwrap();
+    }
+
+    let expression = expression.trim();
+    if let Some(cap) = HELPER_RE.captures(expression) {
+        let base = match &cap[1] {
+            "resource_path" => root.join("resources"),
+            "app_path" => root.join("app"),
+            "storage_path" => root.join("storage"),
+            "public_path" => root.join("public"),
+            _ => root.to_path_buf(),
+        };
+        let relative = cap.get(2).map_or("", |m| m.as_str()).trim_start_matches('/');
+        return Some(if relative.is_empty() { base } else { base.join(relative) });
+    }
+
+    DIR_RE
+        .captures(expression)
+        .map(|cap| dir_relative_path(provider_dir, &cap[1]))
+}
+
+/// Resolve the string appended to `__DIR__` (e.g. `'/../lang'`) against the provider's directory
+///
+/// The leading separator is dropped first - `Path::join` would otherwise treat
+/// the suffix as absolute and lose the provider directory.
+fn dir_relative_path(provider_dir: &Path, relative: &str) -> PathBuf {
+    normalize_path(&provider_dir.join(relative.trim_start_matches(['/', '\\'])))
+}
+
 /// Normalize a path by resolving . and .. components without requiring the path to exist
 fn normalize_path(path: &Path) -> PathBuf {
     let mut components = Vec::new();
@@ -1519,6 +2392,8 @@ pub struct ViewReferenceData {
     pub column: u32,
     pub end_column: u32,
     pub is_route_view: bool,
+    /// Volt::route() component name - resolve with `resolve_volt_path`
+    pub is_volt_route: bool,
 }
 
 /// Component reference data for transfer across async boundaries
@@ -1558,6 +2433,7 @@ pub struct ConfigReferenceData {
     pub line: u32,
     pub column: u32,
     pub end_column: u32,
+    pub is_set: bool,
 }
 
 /// Livewire reference data for transfer across async boundaries
@@ -1585,6 +2461,7 @@ pub struct TranslationReferenceData {
     pub line: u32,
     pub column: u32,
     pub end_column: u32,
+    pub is_existence_check: bool,
 }
 
 /// Asset reference data for transfer across async boundaries
@@ -1648,6 +2525,118 @@ pub struct FeatureReferenceData {
     pub end_column: u32,
 }
 
+/// Named slot passed to a component, for transfer across async boundaries
+/// e.g., `<x-slot:footer>` inside `<x-card>` → ("footer", Some("card"))
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SlotReferenceData {
+    /// The slot name (e.g., "footer")
+    pub name: String,
+    /// The enclosing component (e.g., "card"), None if not inside one
+    pub component: Option<String>,
+    pub line: u32,
+    pub column: u32,
+    pub end_column: u32,
+}
+
+/// Section name in `@yield('content')` or `@section('content')`, for transfer across async boundaries
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SectionReferenceData {
+    pub name: String,
+    /// True for a layout's `@yield`, false for a `@section` filling it
+    pub is_yield: bool,
+    pub line: u32,
+    pub column: u32,
+    pub end_column: u32,
+}
+
+/// Stack name in `@stack('scripts')` or `@push('scripts')`, for transfer across async boundaries
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StackReferenceData {
+    pub name: String,
+    /// True for a layout's `@stack`, false for a `@push`/`@prepend` (or their `Once` forms)
+    pub is_stack: bool,
+    pub line: u32,
+    pub column: u32,
+    pub end_column: u32,
+}
+
+/// A `@yield`/`@section` or `@stack`/`@push` site in a Blade file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BladeRegionSiteData {
+    pub file_path: PathBuf,
+    pub line: u32,
+    pub column: u32,
+    pub end_column: u32,
+}
+
+/// Model class heading an Eloquent query, for transfer across async boundaries
+/// e.g., User::query() → "User"
+#[derive(Debug, Clone)]
+pub struct ModelReferenceData {
+    /// The class name as written (short, aliased or fully qualified)
+    pub class_name: String,
+    pub line: u32,
+    pub column: u32,
+    pub end_column: u32,
+}
+
+/// Authorization ability data for transfer across async boundaries
+/// e.g., $this->authorize('update', $post) → ("update", Some("$post"))
+#[derive(Debug, Clone)]
+pub struct AbilityReferenceData {
+    /// The ability name (e.g., "update" or "view-admin")
+    pub ability: String,
+    /// The argument after the ability, as written (e.g., "$post")
+    pub subject: Option<String>,
+    pub line: u32,
+    pub column: u32,
+    pub end_column: u32,
+}
+
+/// Custom validation rule object for transfer across async boundaries
+/// e.g., 'title' => ['required', new Uppercase] → "Uppercase"
+#[derive(Debug, Clone)]
+pub struct RuleClassReferenceData {
+    /// The class name as written (short, aliased or fully qualified)
+    pub class_name: String,
+    pub line: u32,
+    pub column: u32,
+    pub end_column: u32,
+}
+
+/// Artisan command reference data for transfer across async boundaries
+/// e.g., $schedule->command('backup:run') → "backup:run"
+#[derive(Debug, Clone)]
+pub struct CommandReferenceData {
+    /// The command name (e.g., "backup:run") or class name as written
+    pub command: String,
+    /// Whether this is a `::class` reference rather than a command name
+    pub is_class_reference: bool,
+    pub line: u32,
+    pub column: u32,
+    pub end_column: u32,
+}
+
+/// Config-backed name reference data for transfer across async boundaries
+/// e.g., Queue::connection('redis') → 'redis' under queue.connections
+#[derive(Debug, Clone)]
+pub struct ConfigNameReferenceData {
+    /// The referenced name (e.g., "redis")
+    pub name: String,
+    /// Config array path holding the valid names (e.g., "queue.connections")
+    pub config_path: String,
+    pub line: u32,
+    pub column: u32,
+    pub end_column: u32,
+}
+
+impl ConfigNameReferenceData {
+    /// Full config key for this name (e.g., "queue.connections.redis")
+    pub fn config_key(&self) -> String {
+        format!("{}.{}", self.config_path, self.name)
+    }
+}
+
 /// Laravel configuration data for transfer across async boundaries
 #[derive(Debug, Clone)]
 pub struct LaravelConfigData {
@@ -1655,6 +2644,8 @@ pub struct LaravelConfigData {
     pub view_paths: Vec<PathBuf>,
     pub component_paths: Vec<(String, PathBuf)>,
     pub livewire_path: Option<PathBuf>,
+    /// Livewire view directory (defaults to resources/views/livewire)
+    pub livewire_view_path: Option<PathBuf>,
     pub has_livewire: bool,
     /// Package view namespaces from loadViewsFrom() calls
     /// Maps namespace (e.g., "courier") to view path
@@ -1662,6 +2653,12 @@ pub struct LaravelConfigData {
     /// Package component namespaces from Blade::componentNamespace() calls
     /// Maps prefix (e.g., "nightshade") to PHP namespace
     pub component_namespaces: HashMap<String, String>,
+    /// Package translation namespaces from loadTranslationsFrom() calls
+    /// Maps namespace (e.g., "courier") to the package's lang path
+    pub translation_namespaces: HashMap<String, PathBuf>,
+    /// Package config files from mergeConfigFrom() calls
+    /// Maps the top-level key (e.g., "courier") to the package's config file
+    pub package_configs: HashMap<String, PathBuf>,
 }
 
 impl LaravelConfigData {
@@ -1708,10 +2705,98 @@ impl LaravelConfigData {
         paths
     }
 
+    /// View name for a Blade file - the inverse of `resolve_view_path`
+    ///
+    /// Checks the view paths and every view namespace (packages, modules,
+    /// `View::addNamespace()`); when several contain the file, the deepest
+    /// directory wins, so a namespace nested in resources/views is preferred.
+    /// e.g. `{admin views}/layouts/app.blade.php` -> "admin::layouts.app"
+    pub fn view_name_for_path(&self, path: &Path) -> Option<String> {
+        let relative_name = |base: &Path| -> Option<String> {
+            let relative = path.strip_prefix(base).ok()?.to_string_lossy().into_owned();
+            let without_ext = relative.strip_suffix(".blade.php")?;
+            Some(without_ext.replace(['/', '\\'], "."))
+        };
+
+        let plain = self.view_paths.iter().filter_map(|base| {
+            let base = self.root.join(base);
+            relative_name(&base).map(|name| (base.components().count(), name))
+        });
+        let namespaced = self.view_namespaces.iter().filter_map(|(namespace, base)| {
+            relative_name(base).map(|name| (base.components().count(), format!("{}::{}", namespace, name)))
+        });
+
+        plain
+            .chain(namespaced)
+            .max_by_key(|(depth, _)| *depth)
+            .map(|(_, name)| name)
+    }
+
+    /// Livewire view directory, honoring `view_path` from config/livewire.php
+    pub fn livewire_view_dir(&self) -> PathBuf {
+        self.livewire_view_path
+            .clone()
+            .unwrap_or_else(|| self.root.join("resources/views/livewire"))
+    }
+
+    /// Config file for a top-level config key: the first existing layer from
+    /// `config_file_layers`, or the project's `config/{key}.php` if none exists
+    pub fn resolve_config_file(&self, key: &str) -> PathBuf {
+        let layers = self.config_file_layers(key);
+        layers.iter().find(|path| path.exists()).unwrap_or(&layers[0]).clone()
+    }
+
+    /// Files merged into a top-level config key, most specific first
+    ///
+    /// The project's `config/{key}.php`, a package file merged with
+    /// `mergeConfigFrom()`, then the framework default under
+    /// vendor/laravel/framework/config that Laravel 11+ merges beneath both.
+    pub fn config_file_layers(&self, key: &str) -> Vec<PathBuf> {
+        let file_name = format!("{}.php", key);
+        let mut layers = vec![self.root.join("config").join(&file_name)];
+        layers.extend(self.package_configs.get(key).cloned());
+        layers.push(self.root.join("vendor/laravel/framework/config").join(file_name));
+        layers
+    }
+
+    /// Resolve a Volt component name (from `Volt::route()`) to possible file paths
+    ///
+    /// Volt mounts the Livewire view path and `resources/views/pages` by default,
+    /// so "settings.profile" -> "resources/views/livewire/settings/profile.blade.php".
+    pub fn resolve_volt_path(&self, component_name: &str) -> Vec<PathBuf> {
+        let component_path = component_name.replace('.', "/");
+
+        [self.livewire_view_dir(), self.root.join("resources/views/pages")]
+            .iter()
+            .map(|mount| {
+                let mut full_path = mount.join(&component_path);
+                full_path.set_extension("blade.php");
+                full_path
+            })
+            .collect()
+    }
+
+    /// Resolve a view reference to possible file paths, honoring Volt routes
+    pub fn resolve_view_reference_path(&self, view: &ViewReferenceData) -> Vec<PathBuf> {
+        if view.is_volt_route {
+            self.resolve_volt_path(&view.name)
+        } else {
+            self.resolve_view_path(&view.name)
+        }
+    }
+
     /// Resolve a component name to file path
+    ///
+    /// Every candidate directory yields both the flat view (`forms/input.blade.php`)
+    /// and the anonymous index view (`forms/input/index.blade.php`).
     pub fn resolve_component_path(&self, component_name: &str) -> Vec<PathBuf> {
         let mut paths = Vec::new();
 
+        fn push_view_variants(paths: &mut Vec<PathBuf>, base: PathBuf, component_path: &str) {
+            paths.push(base.join(format!("{}.blade.php", component_path)));
+            paths.push(base.join(component_path).join("index.blade.php"));
+        }
+
         // Handle package components (e.g., "courier::alert")
         let (namespace, actual_component) = if let Some(pos) = component_name.find("::") {
             let namespace = &component_name[..pos];
@@ -1728,40 +2813,32 @@ impl LaravelConfigData {
             // Package component - check package view path first
             if let Some(package_view_path) = self.view_namespaces.get(ns) {
                 // Anonymous package component: {package_views}/components/{component}.blade.php
-                let mut full_path = package_view_path.join("components").join(&component_path);
-                full_path.set_extension("blade.php");
-                paths.push(full_path);
+                push_view_variants(&mut paths, package_view_path.join("components"), &component_path);
             }
 
             // Also check component namespace (Blade::componentNamespace)
             if let Some(php_namespace) = self.component_namespaces.get(ns) {
                 // Convert component name to PascalCase class path
-                // "alert" -> "Alert.php", "alert-box" -> "AlertBox.php"
-                let class_name = kebab_to_pascal_case(&component_path.replace('/', "\\"));
+                // "alert" -> "Alert.php", "forms.text-input" -> "Forms/TextInput.php"
+                let class_name = component_class_path(actual_component);
                 let class_path = format!("{}/{}.php", php_namespace.replace('\\', "/"), class_name);
                 // Try common locations for package classes
                 paths.push(self.root.join("vendor").join(&class_path));
-                paths.push(self.root.join("app/View/Components").join(&class_name).with_extension("php"));
+                paths.push(self.root.join("app/View/Components").join(format!("{}.php", class_name)));
             }
 
             // Check vendor published components: resources/views/vendor/{namespace}/components/
-            let mut vendor_path = self.root.join("resources/views/vendor").join(ns).join("components").join(&component_path);
-            vendor_path.set_extension("blade.php");
-            paths.push(vendor_path);
+            push_view_variants(&mut paths, self.root.join("resources/views/vendor").join(ns).join("components"), &component_path);
         } else {
             // Regular component - check each component path
             for (_namespace, base_path) in &self.component_paths {
-                let mut full_path = self.root.join(base_path).join(&component_path);
-                full_path.set_extension("blade.php");
-                paths.push(full_path);
+                push_view_variants(&mut paths, self.root.join(base_path), &component_path);
             }
 
             // If no component paths found, use default within view paths
             if paths.is_empty() {
                 for view_path in &self.view_paths {
-                    let mut full_path = self.root.join(view_path).join("components").join(&component_path);
-                    full_path.set_extension("blade.php");
-                    paths.push(full_path);
+                    push_view_variants(&mut paths, self.root.join(view_path).join("components"), &component_path);
                 }
             }
         }
@@ -1796,6 +2873,54 @@ impl LaravelConfigData {
     }
 }
 
+/// Which project file list a path belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProjectFileKind {
+    Controller,
+    View,
+    Livewire,
+    Route,
+    /// Any other PHP under app/ (mailables, notifications, view composers...)
+    Source,
+}
+
+/// Absolute directories scanned by `register_project_files`
+#[derive(Debug, Clone)]
+pub struct ProjectFileRoots {
+    pub controller_dirs: Vec<PathBuf>,
+    pub view_dirs: Vec<PathBuf>,
+    pub livewire_dir: Option<PathBuf>,
+    pub routes_dir: PathBuf,
+    pub app_dir: PathBuf,
+}
+
+impl ProjectFileRoots {
+    /// Classify a file the same way the full directory scan would
+    pub fn classify(&self, path: &Path, file_types: &FileTypeSettings) -> Option<ProjectFileKind> {
+        let kind = FileKind::from_path(path, file_types);
+        let is_blade = kind.is_blade();
+        let is_php = kind.is_source();
+
+        if is_blade && self.view_dirs.iter().any(|dir| path.starts_with(dir)) {
+            return Some(ProjectFileKind::View);
+        }
+        if !is_php {
+            return None;
+        }
+        if self.controller_dirs.iter().any(|dir| path.starts_with(dir)) {
+            Some(ProjectFileKind::Controller)
+        } else if self.livewire_dir.as_ref().is_some_and(|dir| path.starts_with(dir)) {
+            Some(ProjectFileKind::Livewire)
+        } else if path.starts_with(&self.routes_dir) {
+            Some(ProjectFileKind::Route)
+        } else if path.starts_with(&self.app_dir) {
+            Some(ProjectFileKind::Source)
+        } else {
+            None
+        }
+    }
+}
+
 /// Type of file that contains a view reference
 #[derive(Debug, Clone, PartialEq, serde::Serialize)]
 pub enum FileReferenceType {
@@ -1803,6 +2928,7 @@ pub enum FileReferenceType {
     BladeTemplate,
     LivewireComponent,
     Route,
+    PhpSource,
 }
 
 /// View reference location data for transfer across async boundaries
@@ -1822,6 +2948,28 @@ pub struct ViewReferenceLocationData {
     pub is_route_view: bool,
 }
 
+/// Number of files in each project file list, for status reporting
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProjectFileCountsData {
+    pub controllers: usize,
+    pub views: usize,
+    pub livewire: usize,
+    pub routes: usize,
+}
+
+/// Translation key usage location for transfer across async boundaries
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranslationReferenceLocationData {
+    /// The file that contains the usage
+    pub file_path: PathBuf,
+    /// The line number of the usage (0-based)
+    pub line: u32,
+    /// Column where the key starts (inside the quotes)
+    pub column: u32,
+    /// Column where the key ends
+    pub end_column: u32,
+}
+
 /// Middleware registration data for transfer across async boundaries
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct MiddlewareRegistrationData {
@@ -1902,6 +3050,38 @@ pub struct ViewNamespaceData {
     pub priority: u8,
 }
 
+/// Livewire component registration data for transfer across async boundaries
+/// From: Livewire::component('custom-name', \App\Http\Livewire\Custom::class)
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct LivewireComponentRegData {
+    /// Component alias (e.g., "custom-name")
+    pub alias: String,
+    /// Full class name
+    pub class_name: String,
+    /// Resolved file path of the component class
+    pub file_path: Option<PathBuf>,
+    /// Source file where registered
+    pub source_file: PathBuf,
+    /// Line number in source file
+    pub source_line: u32,
+    /// Priority: 0=framework, 1=package, 2=app
+    pub priority: u8,
+}
+
+/// Custom Blade directive registration data for transfer across async boundaries
+/// From: Blade::directive('datetime', fn ($expression) => ...)
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct BladeDirectiveRegData {
+    /// Directive name without the @ (e.g., "datetime")
+    pub name: String,
+    /// Source file where registered
+    pub source_file: PathBuf,
+    /// Line number in source file
+    pub source_line: u32,
+    /// Priority: 0=framework, 1=package, 2=app
+    pub priority: u8,
+}
+
 /// Blade component registration data for transfer across async boundaries
 /// From: Blade::component('package-alert', AlertComponent::class)
 #[derive(Debug, Clone, serde::Serialize)]
@@ -1955,12 +3135,23 @@ pub struct ParsedEnvVarData {
     pub value_column: u32,
     /// Whether commented out
     pub is_commented: bool,
-    /// Priority (0=.env.example, 1=.env.local, 2=.env)
+    /// Priority (0=.env.example, 1=.env.{environment}, 2=.env.local, 3=.env)
     pub priority: u8,
     /// Source file path
     pub source_file: PathBuf,
 }
 
+/// A `Gate::define()` ability (for transfer across async boundaries)
+#[derive(Debug, Clone)]
+pub struct GateDefinitionData {
+    pub name: String,
+    /// The service provider defining the gate
+    pub source_file: PathBuf,
+    /// Position of the ability name inside the quotes
+    pub line: u32,
+    pub column: u32,
+}
+
 /// Parsed middleware data from Salsa (for transfer across async boundaries)
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct ParsedMiddlewareData {
@@ -2024,6 +3215,14 @@ pub struct ParsedPatternsData {
     pub url_refs: Vec<Arc<UrlReferenceData>>,
     pub action_refs: Vec<Arc<ActionReferenceData>>,
     pub feature_refs: Vec<Arc<FeatureReferenceData>>,
+    pub config_name_refs: Vec<Arc<ConfigNameReferenceData>>,
+    pub model_refs: Vec<Arc<ModelReferenceData>>,
+    pub ability_refs: Vec<Arc<AbilityReferenceData>>,
+    pub rule_class_refs: Vec<Arc<RuleClassReferenceData>>,
+    pub command_refs: Vec<Arc<CommandReferenceData>>,
+    pub slot_refs: Vec<Arc<SlotReferenceData>>,
+    pub section_refs: Vec<Arc<SectionReferenceData>>,
+    pub stack_refs: Vec<Arc<StackReferenceData>>,
     /// Sorted index of all patterns by (line, column) for O(log n) lookup
     sorted_positions: Vec<PositionEntry>,
 }
@@ -2046,6 +3245,14 @@ pub enum PatternAtPosition {
     Url(Arc<UrlReferenceData>),
     Action(Arc<ActionReferenceData>),
     Feature(Arc<FeatureReferenceData>),
+    ConfigName(Arc<ConfigNameReferenceData>),
+    Model(Arc<ModelReferenceData>),
+    Ability(Arc<AbilityReferenceData>),
+    RuleClass(Arc<RuleClassReferenceData>),
+    Command(Arc<CommandReferenceData>),
+    Slot(Arc<SlotReferenceData>),
+    Section(Arc<SectionReferenceData>),
+    Stack(Arc<StackReferenceData>),
 }
 
 impl ParsedPatternsData {
@@ -2181,6 +3388,78 @@ impl ParsedPatternsData {
             });
         }
 
+        for config_name in &self.config_name_refs {
+            entries.push(PositionEntry {
+                line: config_name.line,
+                column: config_name.column,
+                end_column: config_name.end_column,
+                pattern: PatternAtPosition::ConfigName(config_name.clone()),
+            });
+        }
+
+        for model in &self.model_refs {
+            entries.push(PositionEntry {
+                line: model.line,
+                column: model.column,
+                end_column: model.end_column,
+                pattern: PatternAtPosition::Model(model.clone()),
+            });
+        }
+
+        for ability in &self.ability_refs {
+            entries.push(PositionEntry {
+                line: ability.line,
+                column: ability.column,
+                end_column: ability.end_column,
+                pattern: PatternAtPosition::Ability(ability.clone()),
+            });
+        }
+
+        for rule in &self.rule_class_refs {
+            entries.push(PositionEntry {
+                line: rule.line,
+                column: rule.column,
+                end_column: rule.end_column,
+                pattern: PatternAtPosition::RuleClass(rule.clone()),
+            });
+        }
+
+        for command in &self.command_refs {
+            entries.push(PositionEntry {
+                line: command.line,
+                column: command.column,
+                end_column: command.end_column,
+                pattern: PatternAtPosition::Command(command.clone()),
+            });
+        }
+
+        for slot in &self.slot_refs {
+            entries.push(PositionEntry {
+                line: slot.line,
+                column: slot.column,
+                end_column: slot.end_column,
+                pattern: PatternAtPosition::Slot(slot.clone()),
+            });
+        }
+
+        for section in &self.section_refs {
+            entries.push(PositionEntry {
+                line: section.line,
+                column: section.column,
+                end_column: section.end_column,
+                pattern: PatternAtPosition::Section(section.clone()),
+            });
+        }
+
+        for stack in &self.stack_refs {
+            entries.push(PositionEntry {
+                line: stack.line,
+                column: stack.column,
+                end_column: stack.end_column,
+                pattern: PatternAtPosition::Stack(stack.clone()),
+            });
+        }
+
         // Sort by (line, column) for efficient binary search
         entries.sort_by(|a, b| {
             a.line.cmp(&b.line).then_with(|| a.column.cmp(&b.column))
@@ -2217,9 +3496,36 @@ impl ParsedPatternsData {
     }
 }
 
-// ============================================================================
-// Actor Pattern - For async integration
-// ============================================================================
+// ============================================================================
+// Actor Pattern - For async integration
+// ============================================================================
+
+/// Capacity of the interactive queue
+const INTERACTIVE_QUEUE_CAPACITY: usize = 256;
+
+/// Capacity of the bulk queue, in batches - senders wait once it's full
+const BULK_QUEUE_CAPACITY: usize = 8;
+
+/// Service providers registered between checks for interactive requests
+const BATCH_CHUNK_SIZE: usize = 16;
+
+/// A service provider file registered as part of a batch
+#[derive(Debug, Clone)]
+pub struct ServiceProviderSource {
+    pub path: PathBuf,
+    pub text: String,
+    /// 0=framework, 1=package, 2=app
+    pub priority: u8,
+}
+
+/// Which queue a request travels on to the actor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RequestPriority {
+    /// Editor-facing requests (patterns, config, lookups) - always served first
+    Interactive,
+    /// Indexing work - served only while no interactive request is waiting
+    Bulk,
+}
 
 /// Requests that can be sent to the Salsa actor
 pub enum SalsaRequest {
@@ -2240,6 +3546,26 @@ pub enum SalsaRequest {
         path: PathBuf,
         reply: oneshot::Sender<()>,
     },
+    /// Get the `@props` of a Blade component view (read from disk if not open)
+    GetComponentProps {
+        path: PathBuf,
+        reply: oneshot::Sender<Option<Vec<BladeProp>>>,
+    },
+    /// Get the layout a Blade view `@extends` (read from disk if not open)
+    GetExtendsTarget {
+        path: PathBuf,
+        reply: oneshot::Sender<Option<String>>,
+    },
+    /// Get the section names a Blade layout renders (read from disk if not open)
+    GetLayoutSectionNames {
+        path: PathBuf,
+        reply: oneshot::Sender<Option<Vec<String>>>,
+    },
+    /// Set which file extensions are parsed as Blade / PHP
+    SetFileTypes {
+        file_types: FileTypeSettings,
+        reply: oneshot::Sender<()>,
+    },
 
     // === Config Management ===
 
@@ -2261,6 +3587,27 @@ pub enum SalsaRequest {
     GetLaravelConfig {
         reply: oneshot::Sender<Option<LaravelConfigData>>,
     },
+    /// Get the key entries of a config file (None if not registered)
+    GetConfigFileEntries {
+        path: PathBuf,
+        reply: oneshot::Sender<Option<Vec<ConfigEntry>>>,
+    },
+    /// Get the name prefix each routes file gets from the named group loading it
+    GetRouteFilePrefixes {
+        reply: oneshot::Sender<HashMap<PathBuf, String>>,
+    },
+    /// Get every `Gate::define()` in the registered service providers
+    GetGateDefinitions {
+        reply: oneshot::Sender<Vec<GateDefinitionData>>,
+    },
+    /// Get every `Gate::policy()` (model, policy) registration in the service providers
+    GetPolicyRegistrations {
+        reply: oneshot::Sender<Vec<(String, String)>>,
+    },
+    /// Get the packages required in composer.json
+    GetComposerPackages {
+        reply: oneshot::Sender<Vec<String>>,
+    },
 
     // === Reference Finding ===
 
@@ -2274,11 +3621,43 @@ pub enum SalsaRequest {
         routes_path: PathBuf,
         reply: oneshot::Sender<()>,
     },
+    /// Add or remove a single file from the project file lists
+    /// Replies true if the lists changed (file created/deleted)
+    UpdateProjectFile {
+        path: PathBuf,
+        exists: bool,
+        reply: oneshot::Sender<bool>,
+    },
     /// Find all references to a specific view across the project
     FindViewReferences {
         view_name: String,
         reply: oneshot::Sender<Vec<ViewReferenceLocationData>>,
     },
+    /// Find the `@section`s filling a `@yield` name, or the `@yield`s a section fills
+    FindSectionSites {
+        name: String,
+        yields: bool,
+        reply: oneshot::Sender<Vec<BladeRegionSiteData>>,
+    },
+    /// Find the `@push`es onto a `@stack` name, or the `@stack`s a push targets
+    FindStackSites {
+        name: String,
+        stacks: bool,
+        reply: oneshot::Sender<Vec<BladeRegionSiteData>>,
+    },
+    /// Every view name referenced anywhere in the registered files
+    GetReferencedViewNames {
+        reply: oneshot::Sender<HashSet<String>>,
+    },
+    /// Get the size of each project file list
+    GetProjectFileCounts {
+        reply: oneshot::Sender<ProjectFileCountsData>,
+    },
+    /// Find all usages of a translation key (__(), trans(), @lang) across registered files
+    FindTranslationReferences {
+        key: String,
+        reply: oneshot::Sender<Vec<TranslationReferenceLocationData>>,
+    },
 
     // === Service Provider Management ===
 
@@ -2317,6 +3696,19 @@ pub enum SalsaRequest {
     GetAllBladeComponentRegs {
         reply: oneshot::Sender<Vec<BladeComponentRegData>>,
     },
+    /// Get an explicitly registered Livewire component by alias
+    GetLivewireComponentReg {
+        alias: String,
+        reply: oneshot::Sender<Option<LivewireComponentRegData>>,
+    },
+    /// Get all explicitly registered Livewire components
+    GetAllLivewireComponentRegs {
+        reply: oneshot::Sender<Vec<LivewireComponentRegData>>,
+    },
+    /// Get all custom directives registered via Blade::directive()
+    GetAllBladeDirectiveRegs {
+        reply: oneshot::Sender<Vec<BladeDirectiveRegData>>,
+    },
     /// Get component namespace by prefix (e.g., "nightshade")
     GetComponentNamespace {
         prefix: String,
@@ -2350,7 +3742,7 @@ pub enum SalsaRequest {
     RegisterEnvSource {
         path: PathBuf,
         text: String,
-        priority: u8, // 0=.env.example, 1=.env.local, 2=.env
+        priority: u8, // 0=.env.example, 1=.env.{environment}, 2=.env.local, 3=.env
         reply: oneshot::Sender<()>,
     },
     /// Get a parsed env variable from Salsa
@@ -2363,6 +3755,20 @@ pub enum SalsaRequest {
         reply: oneshot::Sender<Vec<ParsedEnvVarData>>,
     },
 
+    // === JSON Translation Files ===
+
+    /// Register a lang/*.json file for Salsa to parse
+    RegisterJsonTranslationSource {
+        path: PathBuf,
+        text: String,
+        reply: oneshot::Sender<()>,
+    },
+    /// Get the keys of a registered JSON translation file (None if not registered)
+    GetJsonTranslationKeys {
+        path: PathBuf,
+        reply: oneshot::Sender<Option<Arc<HashSet<String>>>>,
+    },
+
     // === Salsa-based Service Provider Management (New) ===
 
     /// Register a raw service provider file for Salsa to parse
@@ -2373,6 +3779,15 @@ pub enum SalsaRequest {
         root_path: PathBuf,
         reply: oneshot::Sender<()>,
     },
+    /// Register many service provider files at once (indexing)
+    ///
+    /// Travels on the bulk queue and is worked through in chunks, with
+    /// interactive requests served in between. Replies with the number registered.
+    RegisterServiceProviderBatch {
+        sources: Vec<ServiceProviderSource>,
+        root_path: PathBuf,
+        reply: oneshot::Sender<usize>,
+    },
     /// Get middleware from Salsa-parsed service providers
     GetParsedMiddleware {
         alias: String,
@@ -2437,17 +3852,43 @@ pub enum SalsaRequest {
         reply: oneshot::Sender<()>,
     },
 
+    /// Forget service providers, env files and cache-registered data before a full rebuild
+    ResetProjectIndex {
+        reply: oneshot::Sender<()>,
+    },
+
     /// Shutdown the actor
     Shutdown,
 }
 
+impl SalsaRequest {
+    /// Which queue this request travels on
+    pub fn priority(&self) -> RequestPriority {
+        match self {
+            SalsaRequest::RegisterServiceProviderBatch { .. } => RequestPriority::Bulk,
+            _ => RequestPriority::Interactive,
+        }
+    }
+}
+
 /// Handle to communicate with the Salsa actor
 #[derive(Clone)]
 pub struct SalsaHandle {
     sender: mpsc::Sender<SalsaRequest>,
+    /// Bounded queue for indexing work, so it never fills the interactive one
+    bulk_sender: mpsc::Sender<SalsaRequest>,
 }
 
 impl SalsaHandle {
+    /// Send a request on the queue its priority calls for
+    async fn send(&self, request: SalsaRequest) -> Result<(), &'static str> {
+        let sender = match request.priority() {
+            RequestPriority::Interactive => &self.sender,
+            RequestPriority::Bulk => &self.bulk_sender,
+        };
+        sender.send(request).await.map_err(|_| "Salsa actor disconnected")
+    }
+
     /// Update or create a file in the database
     pub async fn update_file(&self, path: PathBuf, version: i32, text: String) -> Result<(), &'static str> {
         let (reply_tx, reply_rx) = oneshot::channel();
@@ -2458,6 +3899,52 @@ impl SalsaHandle {
         reply_rx.await.map_err(|_| "Salsa actor dropped reply channel")
     }
 
+    /// Get the `@props` declared by a Blade component view
+    /// Returns None if the file can't be read or has no `@props` directive
+    pub async fn get_component_props(&self, path: PathBuf) -> Result<Option<Vec<BladeProp>>, &'static str> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.sender
+            .send(SalsaRequest::GetComponentProps { path, reply: reply_tx })
+            .await
+            .map_err(|_| "Salsa actor disconnected")?;
+        reply_rx.await.map_err(|_| "Salsa actor dropped reply channel")
+    }
+
+    /// Get the view name a Blade view `@extends`
+    /// Returns None if the file can't be read or doesn't extend a layout
+    pub async fn get_extends_target(&self, path: PathBuf) -> Result<Option<String>, &'static str> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.sender
+            .send(SalsaRequest::GetExtendsTarget { path, reply: reply_tx })
+            .await
+            .map_err(|_| "Salsa actor disconnected")?;
+        reply_rx.await.map_err(|_| "Salsa actor dropped reply channel")
+    }
+
+    /// Get the section names a Blade layout yields, defines or checks for
+    /// Returns None if the file can't be read or yields a dynamic name
+    pub async fn get_layout_section_names(&self, path: PathBuf) -> Result<Option<Vec<String>>, &'static str> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.sender
+            .send(SalsaRequest::GetLayoutSectionNames { path, reply: reply_tx })
+            .await
+            .map_err(|_| "Salsa actor disconnected")?;
+        reply_rx.await.map_err(|_| "Salsa actor dropped reply channel")
+    }
+
+    /// Set which file extensions are parsed as Blade / PHP
+    ///
+    /// Files already in the database are re-classified, so a changed
+    /// `bladeExtensions` setting takes effect without reopening them.
+    pub async fn set_file_types(&self, file_types: FileTypeSettings) -> Result<(), &'static str> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.sender
+            .send(SalsaRequest::SetFileTypes { file_types, reply: reply_tx })
+            .await
+            .map_err(|_| "Salsa actor disconnected")?;
+        reply_rx.await.map_err(|_| "Salsa actor dropped reply channel")
+    }
+
     /// Get parsed patterns for a file
     /// Returns Arc for efficient sharing without cloning the entire data structure
     pub async fn get_patterns(&self, path: PathBuf) -> Result<Option<Arc<ParsedPatternsData>>, &'static str> {
@@ -2521,6 +4008,59 @@ impl SalsaHandle {
         reply_rx.await.map_err(|_| "Salsa actor dropped reply channel")
     }
 
+    /// Get the key entries of a config file registered with `update_config_file`
+    pub async fn get_config_file_entries(&self, path: PathBuf) -> Result<Option<Vec<ConfigEntry>>, &'static str> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.sender
+            .send(SalsaRequest::GetConfigFileEntries { path, reply: reply_tx })
+            .await
+            .map_err(|_| "Salsa actor disconnected")?;
+        reply_rx.await.map_err(|_| "Salsa actor dropped reply channel")
+    }
+
+    /// Get the name prefix each routes file gets from the named group loading it
+    ///
+    /// e.g. `->name('api.')->group(base_path('routes/api.php'))` in bootstrap/app.php
+    /// gives every route name in routes/api.php the `api.` prefix.
+    pub async fn get_route_file_prefixes(&self) -> Result<HashMap<PathBuf, String>, &'static str> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.sender
+            .send(SalsaRequest::GetRouteFilePrefixes { reply: reply_tx })
+            .await
+            .map_err(|_| "Salsa actor disconnected")?;
+        reply_rx.await.map_err(|_| "Salsa actor dropped reply channel")
+    }
+
+    /// Get every `Gate::define()` in the registered service providers
+    pub async fn get_gate_definitions(&self) -> Result<Vec<GateDefinitionData>, &'static str> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.sender
+            .send(SalsaRequest::GetGateDefinitions { reply: reply_tx })
+            .await
+            .map_err(|_| "Salsa actor disconnected")?;
+        reply_rx.await.map_err(|_| "Salsa actor dropped reply channel")
+    }
+
+    /// Get every `Gate::policy()` registration as fully qualified (model, policy) classes
+    pub async fn get_policy_registrations(&self) -> Result<Vec<(String, String)>, &'static str> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.sender
+            .send(SalsaRequest::GetPolicyRegistrations { reply: reply_tx })
+            .await
+            .map_err(|_| "Salsa actor disconnected")?;
+        reply_rx.await.map_err(|_| "Salsa actor dropped reply channel")
+    }
+
+    /// Get the packages required in composer.json (require and require-dev)
+    pub async fn get_composer_packages(&self) -> Result<Vec<String>, &'static str> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.sender
+            .send(SalsaRequest::GetComposerPackages { reply: reply_tx })
+            .await
+            .map_err(|_| "Salsa actor disconnected")?;
+        reply_rx.await.map_err(|_| "Salsa actor dropped reply channel")
+    }
+
     /// Get the current Laravel configuration
     pub async fn get_laravel_config(&self) -> Result<Option<LaravelConfigData>, &'static str> {
         let (reply_tx, reply_rx) = oneshot::channel();
@@ -2558,6 +4098,19 @@ impl SalsaHandle {
         reply_rx.await.map_err(|_| "Salsa actor dropped reply channel")
     }
 
+    /// Add (exists=true) or remove (exists=false) one file from the project file lists
+    ///
+    /// Keeps reference finding in sync with created/deleted files without
+    /// re-walking the project. Returns true if the lists changed.
+    pub async fn update_project_file(&self, path: PathBuf, exists: bool) -> Result<bool, &'static str> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.sender
+            .send(SalsaRequest::UpdateProjectFile { path, exists, reply: reply_tx })
+            .await
+            .map_err(|_| "Salsa actor disconnected")?;
+        reply_rx.await.map_err(|_| "Salsa actor dropped reply channel")
+    }
+
     /// Find all references to a specific view across the project
     /// Returns cached results when possible, only scanning changed files
     pub async fn find_view_references(&self, view_name: String) -> Result<Vec<ViewReferenceLocationData>, &'static str> {
@@ -2572,6 +4125,60 @@ impl SalsaHandle {
         reply_rx.await.map_err(|_| "Salsa actor dropped reply channel")
     }
 
+    /// Find every `@yield` (`yields`) or `@section` with the given name in the project's Blade files
+    pub async fn find_section_sites(&self, name: String, yields: bool) -> Result<Vec<BladeRegionSiteData>, &'static str> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.sender
+            .send(SalsaRequest::FindSectionSites { name, yields, reply: reply_tx })
+            .await
+            .map_err(|_| "Salsa actor disconnected")?;
+        reply_rx.await.map_err(|_| "Salsa actor dropped reply channel")
+    }
+
+    /// Find every `@stack` (`stacks`) or `@push`/`@prepend` with the given name in the project's Blade files
+    pub async fn find_stack_sites(&self, name: String, stacks: bool) -> Result<Vec<BladeRegionSiteData>, &'static str> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.sender
+            .send(SalsaRequest::FindStackSites { name, stacks, reply: reply_tx })
+            .await
+            .map_err(|_| "Salsa actor disconnected")?;
+        reply_rx.await.map_err(|_| "Salsa actor dropped reply channel")
+    }
+
+    /// Collect every view name referenced across the project
+    ///
+    /// Covers `view()` calls, view directives (`@extends`, `@include*`, `@each`,
+    /// `@component`), `<x-*>` components and `<livewire:*>` tags in the project
+    /// file lists and open documents. Used to find views nothing points at.
+    pub async fn get_referenced_view_names(&self) -> Result<HashSet<String>, &'static str> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.sender
+            .send(SalsaRequest::GetReferencedViewNames { reply: reply_tx })
+            .await
+            .map_err(|_| "Salsa actor disconnected")?;
+        reply_rx.await.map_err(|_| "Salsa actor dropped reply channel")
+    }
+
+    /// Get the number of controller, view, Livewire and route files registered
+    pub async fn get_project_file_counts(&self) -> Result<ProjectFileCountsData, &'static str> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.sender
+            .send(SalsaRequest::GetProjectFileCounts { reply: reply_tx })
+            .await
+            .map_err(|_| "Salsa actor disconnected")?;
+        reply_rx.await.map_err(|_| "Salsa actor dropped reply channel")
+    }
+
+    /// Find all usages of a translation key across registered project and open files
+    pub async fn find_translation_references(&self, key: String) -> Result<Vec<TranslationReferenceLocationData>, &'static str> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.sender
+            .send(SalsaRequest::FindTranslationReferences { key, reply: reply_tx })
+            .await
+            .map_err(|_| "Salsa actor disconnected")?;
+        reply_rx.await.map_err(|_| "Salsa actor dropped reply channel")
+    }
+
     // === Service Provider Methods ===
 
     /// Register the service provider registry from the existing analyzer
@@ -2654,6 +4261,36 @@ impl SalsaHandle {
         reply_rx.await.map_err(|_| "Salsa actor dropped reply channel")
     }
 
+    /// Get a Livewire component registered via Livewire::component() by alias
+    pub async fn get_livewire_component_reg(&self, alias: String) -> Result<Option<LivewireComponentRegData>, &'static str> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.sender
+            .send(SalsaRequest::GetLivewireComponentReg { alias, reply: reply_tx })
+            .await
+            .map_err(|_| "Salsa actor disconnected")?;
+        reply_rx.await.map_err(|_| "Salsa actor dropped reply channel")
+    }
+
+    /// Get all Livewire components registered via Livewire::component()
+    pub async fn get_all_livewire_component_regs(&self) -> Result<Vec<LivewireComponentRegData>, &'static str> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.sender
+            .send(SalsaRequest::GetAllLivewireComponentRegs { reply: reply_tx })
+            .await
+            .map_err(|_| "Salsa actor disconnected")?;
+        reply_rx.await.map_err(|_| "Salsa actor dropped reply channel")
+    }
+
+    /// Get all custom directives registered via Blade::directive()
+    pub async fn get_all_blade_directive_regs(&self) -> Result<Vec<BladeDirectiveRegData>, &'static str> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.sender
+            .send(SalsaRequest::GetAllBladeDirectiveRegs { reply: reply_tx })
+            .await
+            .map_err(|_| "Salsa actor disconnected")?;
+        reply_rx.await.map_err(|_| "Salsa actor dropped reply channel")
+    }
+
     /// Get component namespace by prefix (for resolving <x-package::component>)
     pub async fn get_component_namespace(&self, prefix: String) -> Result<Option<ComponentNamespaceData>, &'static str> {
         let (reply_tx, reply_rx) = oneshot::channel();
@@ -2735,6 +4372,26 @@ impl SalsaHandle {
         reply_rx.await.map_err(|_| "Salsa actor dropped reply channel")
     }
 
+    /// Register a JSON translation file (lang/en.json) for Salsa to parse
+    pub async fn register_json_translation_source(&self, path: PathBuf, text: String) -> Result<(), &'static str> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.sender
+            .send(SalsaRequest::RegisterJsonTranslationSource { path, text, reply: reply_tx })
+            .await
+            .map_err(|_| "Salsa actor disconnected")?;
+        reply_rx.await.map_err(|_| "Salsa actor dropped reply channel")
+    }
+
+    /// Get the translation keys of a registered JSON file (memoized until it changes)
+    pub async fn get_json_translation_keys(&self, path: PathBuf) -> Result<Option<Arc<HashSet<String>>>, &'static str> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.sender
+            .send(SalsaRequest::GetJsonTranslationKeys { path, reply: reply_tx })
+            .await
+            .map_err(|_| "Salsa actor disconnected")?;
+        reply_rx.await.map_err(|_| "Salsa actor dropped reply channel")
+    }
+
     /// Get a parsed env variable from Salsa
     /// Returns the highest-priority variable if multiple files define the same var
     pub async fn get_parsed_env_var(&self, name: String) -> Result<Option<ParsedEnvVarData>, &'static str> {
@@ -2781,6 +4438,17 @@ impl SalsaHandle {
         reply_rx.await.map_err(|_| "Salsa actor dropped reply channel")
     }
 
+    /// Register a batch of service provider files (framework/vendor indexing)
+    ///
+    /// Interactive requests sent while the batch is in progress are answered
+    /// first, so indexing a large vendor tree doesn't hold up goto or diagnostics.
+    /// Returns the number of files registered.
+    pub async fn register_batch(&self, sources: Vec<ServiceProviderSource>, root_path: PathBuf) -> Result<usize, &'static str> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.send(SalsaRequest::RegisterServiceProviderBatch { sources, root_path, reply: reply_tx }).await?;
+        reply_rx.await.map_err(|_| "Salsa actor dropped reply channel")
+    }
+
     /// Get middleware by alias from Salsa-parsed service providers
     /// Returns the highest-priority middleware if multiple providers define the same alias
     pub async fn get_parsed_middleware(&self, alias: String) -> Result<Option<ParsedMiddlewareData>, &'static str> {
@@ -2926,6 +4594,19 @@ impl SalsaHandle {
         reply_rx.await.map_err(|_| "Salsa actor dropped reply channel")
     }
 
+    /// Forget service providers, env files and data registered from the disk cache
+    ///
+    /// Open files and config files stay registered; the caller re-registers
+    /// everything else from disk afterwards.
+    pub async fn reset_project_index(&self) -> Result<(), &'static str> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.sender
+            .send(SalsaRequest::ResetProjectIndex { reply: reply_tx })
+            .await
+            .map_err(|_| "Salsa actor disconnected")?;
+        reply_rx.await.map_err(|_| "Salsa actor dropped reply channel")
+    }
+
     /// Register env variables from disk cache (bypasses parsing)
     pub async fn register_cached_env_vars(&self, variables: std::collections::HashMap<String, String>) -> Result<(), &'static str> {
         let (reply_tx, reply_rx) = oneshot::channel();
@@ -2944,12 +4625,18 @@ impl SalsaHandle {
 pub struct SalsaActor {
     db: LaravelDatabase,
     receiver: mpsc::Receiver<SalsaRequest>,
+    /// Indexing requests, only taken while `receiver` is empty
+    bulk_receiver: mpsc::Receiver<SalsaRequest>,
+    /// Service provider batch being worked through between interactive requests
+    pending_batch: Option<PendingBatch>,
     /// Map from path to SourceFile for efficient lookups and updates
     files: HashMap<PathBuf, SourceFile>,
     /// LRU cache of converted pattern data to avoid repeated conversion
     /// Key: file path, Value: (file version, cached patterns wrapped in Arc)
     /// Limited to 256 entries to prevent unbounded memory growth
     pattern_cache: LruCache<PathBuf, (i32, Arc<ParsedPatternsData>)>,
+    /// Extensions that decide whether a source file is Blade or PHP
+    file_types: FileTypeSettings,
 
     // === Config Management ===
 
@@ -2973,6 +4660,10 @@ pub struct SalsaActor {
     view_files: Vec<PathBuf>,
     livewire_files: Vec<PathBuf>,
     route_files: Vec<PathBuf>,
+    /// Remaining PHP under app/, which can render views too
+    source_files: Vec<PathBuf>,
+    /// Directories the file lists were built from (for classifying single-file updates)
+    project_file_roots: Option<ProjectFileRoots>,
 
     // === Service Provider Registry ===
 
@@ -3001,6 +4692,9 @@ pub struct SalsaActor {
     /// Version counter for env files
     salsa_env_version: i32,
 
+    /// JSON translation files registered with Salsa, parsed to key sets on demand
+    salsa_json_translation_files: HashMap<PathBuf, JsonTranslationFile>,
+
     // === Salsa-based Service Provider Tracking (New) ===
 
     /// Service provider files registered with Salsa for incremental parsing
@@ -3011,48 +4705,25 @@ pub struct SalsaActor {
     salsa_sp_root: Option<PathBuf>,
 }
 
+/// A service provider batch the actor is part way through
+struct PendingBatch {
+    sources: std::vec::IntoIter<ServiceProviderSource>,
+    root_path: PathBuf,
+    registered: usize,
+    /// Paths registered interactively since the batch was queued - their
+    /// batch entries are older and are skipped
+    superseded: HashSet<PathBuf>,
+    reply: oneshot::Sender<usize>,
+}
+
 impl SalsaActor {
     /// Spawn the actor on a dedicated thread and return a handle for communication
     pub fn spawn() -> SalsaHandle {
-        let (tx, rx) = mpsc::channel(256);
+        let (tx, rx) = mpsc::channel(INTERACTIVE_QUEUE_CAPACITY);
+        let (bulk_tx, bulk_rx) = mpsc::channel(BULK_QUEUE_CAPACITY);
 
         std::thread::spawn(move || {
-            let mut actor = SalsaActor {
-                db: LaravelDatabase::new(),
-                receiver: rx,
-                // Pre-allocate with reasonable capacity to avoid early reallocations
-                files: HashMap::with_capacity(64),
-                // LRU cache with 256 entry limit to prevent unbounded memory growth
-                pattern_cache: LruCache::new(NonZeroUsize::new(256).unwrap()),
-                // Config management
-                config_root: None,
-                config_files: HashMap::with_capacity(4),
-                config_version: 0,
-                config_cache: None,
-                // Reference finding
-                project_files: None,
-                project_files_version: 0,
-                controller_files: Vec::new(),
-                view_files: Vec::new(),
-                livewire_files: Vec::new(),
-                route_files: Vec::new(),
-                // Service provider registry
-                sp_middleware_aliases: HashMap::new(),
-                sp_bindings: HashMap::new(),
-                sp_singletons: HashMap::new(),
-                sp_view_namespaces: HashMap::new(),
-                sp_blade_components: HashMap::new(),
-                sp_component_namespaces: HashMap::new(),
-                // Environment variables
-                env_variables: HashMap::new(),
-                // Salsa-based env tracking
-                salsa_env_files: HashMap::with_capacity(4),
-                salsa_env_version: 0,
-                // Salsa-based service provider tracking
-                salsa_sp_files: HashMap::with_capacity(32),
-                salsa_sp_version: 0,
-                salsa_sp_root: None,
-            };
+            let mut actor = SalsaActor::new(rx, bulk_rx);
 
             // Pre-warm query cache on actor thread (background)
             // This runs before any file parsing requests arrive,
@@ -3062,223 +4733,501 @@ impl SalsaActor {
             actor.run();
         });
 
-        SalsaHandle { sender: tx }
+        SalsaHandle { sender: tx, bulk_sender: bulk_tx }
+    }
+
+    fn new(receiver: mpsc::Receiver<SalsaRequest>, bulk_receiver: mpsc::Receiver<SalsaRequest>) -> Self {
+        SalsaActor {
+            db: LaravelDatabase::new(),
+            receiver,
+            bulk_receiver,
+            pending_batch: None,
+            // Pre-allocate with reasonable capacity to avoid early reallocations
+            files: HashMap::with_capacity(64),
+            // LRU cache with 256 entry limit to prevent unbounded memory growth
+            pattern_cache: LruCache::new(NonZeroUsize::new(256).unwrap()),
+            file_types: FileTypeSettings::default(),
+            // Config management
+            config_root: None,
+            config_files: HashMap::with_capacity(4),
+            config_version: 0,
+            config_cache: None,
+            // Reference finding
+            project_files: None,
+            project_files_version: 0,
+            controller_files: Vec::new(),
+            view_files: Vec::new(),
+            livewire_files: Vec::new(),
+            route_files: Vec::new(),
+            source_files: Vec::new(),
+            project_file_roots: None,
+            // Service provider registry
+            sp_middleware_aliases: HashMap::new(),
+            sp_bindings: HashMap::new(),
+            sp_singletons: HashMap::new(),
+            sp_view_namespaces: HashMap::new(),
+            sp_blade_components: HashMap::new(),
+            sp_component_namespaces: HashMap::new(),
+            // Environment variables
+            env_variables: HashMap::new(),
+            // Salsa-based env tracking
+            salsa_env_files: HashMap::with_capacity(4),
+            salsa_env_version: 0,
+            salsa_json_translation_files: HashMap::new(),
+            // Salsa-based service provider tracking
+            salsa_sp_files: HashMap::with_capacity(32),
+            salsa_sp_version: 0,
+            salsa_sp_root: None,
+        }
     }
 
     /// Main event loop - process requests until shutdown
+    ///
+    /// Interactive requests always go first. A bulk batch is worked through
+    /// `BATCH_CHUNK_SIZE` entries at a time, checking for interactive requests
+    /// between chunks, so indexing delays goto or diagnostics by one chunk at most.
     fn run(&mut self) {
-        while let Some(request) = self.receiver.blocking_recv() {
-            match request {
-                SalsaRequest::UpdateFile { path, version, text, reply } => {
-                    self.handle_update_file(path, version, text);
-                    let _ = reply.send(());
+        // Only used to wait on both queues at once
+        let runtime = match tokio::runtime::Builder::new_current_thread().build() {
+            Ok(runtime) => runtime,
+            Err(e) => {
+                tracing::error!("Salsa actor could not start its queue runtime: {}", e);
+                return;
+            }
+        };
+
+        while self.step(&runtime) {}
+    }
+
+    /// Handle the next interactive request, else the next chunk of the pending
+    /// batch, else wait on both queues. Returns false once the actor should stop.
+    fn step(&mut self, runtime: &tokio::runtime::Runtime) -> bool {
+        let request = match self.receiver.try_recv() {
+            Ok(request) => request,
+            Err(mpsc::error::TryRecvError::Disconnected) => return false,
+            Err(mpsc::error::TryRecvError::Empty) if self.pending_batch.is_some() => {
+                self.continue_batch();
+                return true;
+            }
+            Err(mpsc::error::TryRecvError::Empty) => {
+                let (interactive, bulk) = (&mut self.receiver, &mut self.bulk_receiver);
+                let next = runtime.block_on(async {
+                    tokio::select! {
+                        biased;
+                        request = interactive.recv() => request,
+                        Some(request) = bulk.recv() => Some(request),
+                    }
+                });
+                match next {
+                    Some(request) => request,
+                    None => return false,
                 }
-                SalsaRequest::GetPatterns { path, reply } => {
-                    let result = self.handle_get_patterns(&path);
-                    let _ = reply.send(result);
+            }
+        };
+
+        self.handle_request(request)
+    }
+
+    /// Register the next chunk of the pending batch, replying once it's done
+    fn continue_batch(&mut self) {
+        let Some(mut batch) = self.pending_batch.take() else {
+            return;
+        };
+        for source in batch.sources.by_ref().take(BATCH_CHUNK_SIZE) {
+            if batch.superseded.contains(&source.path) {
+                continue;
+            }
+            self.handle_register_service_provider_source(source.path, source.text, source.priority, batch.root_path.clone());
+            batch.registered += 1;
+        }
+
+        if batch.sources.as_slice().is_empty() {
+            let _ = batch.reply.send(batch.registered);
+        } else {
+            self.pending_batch = Some(batch);
+        }
+    }
+
+    /// Handle one request; returns false once the actor should stop
+    fn handle_request(&mut self, request: SalsaRequest) -> bool {
+        match request {
+            SalsaRequest::UpdateFile { path, version, text, reply } => {
+                self.handle_update_file(path, version, text);
+                let _ = reply.send(());
+            }
+            SalsaRequest::GetPatterns { path, reply } => {
+                let result = self.handle_get_patterns(&path);
+                let _ = reply.send(result);
+            }
+            SalsaRequest::RemoveFile { path, reply } => {
+                self.files.remove(&path);
+                self.pattern_cache.pop(&path);
+                // A deleted provider's registrations go with it
+                if self.salsa_sp_files.remove(&path).is_some() {
+                    self.config_cache = None;
                 }
-                SalsaRequest::RemoveFile { path, reply } => {
-                    self.files.remove(&path);
-                    self.pattern_cache.pop(&path);
-                    let _ = reply.send(());
+                // So are a deleted config or translation file's keys
+                if self.config_files.remove(&path).is_some() {
+                    self.config_version += 1;
+                    self.config_cache = None;
                 }
+                self.salsa_json_translation_files.remove(&path);
+                let _ = reply.send(());
+            }
+            SalsaRequest::GetComponentProps { path, reply } => {
+                self.ensure_file_registered(&path);
+                let result = self.files.get(&path)
+                    .and_then(|file| parse_component_props(&self.db, *file));
+                let _ = reply.send(result);
+            }
+            SalsaRequest::GetExtendsTarget { path, reply } => {
+                self.ensure_file_registered(&path);
+                let result = self.files.get(&path)
+                    .and_then(|file| parse_extends_target(&self.db, *file));
+                let _ = reply.send(result);
+            }
+            SalsaRequest::GetLayoutSectionNames { path, reply } => {
+                self.ensure_file_registered(&path);
+                let result = self.files.get(&path)
+                    .and_then(|file| parse_layout_section_names(&self.db, *file));
+                let _ = reply.send(result);
+            }
+            SalsaRequest::SetFileTypes { file_types, reply } => {
+                self.handle_set_file_types(file_types);
+                let _ = reply.send(());
+            }
 
-                // === Config Handlers ===
-                SalsaRequest::RegisterConfigFiles {
+            // === Config Handlers ===
+            SalsaRequest::RegisterConfigFiles {
+                root_path,
+                composer_json,
+                view_config,
+                livewire_config,
+                reply,
+            } => {
+                self.handle_register_config_files(
                     root_path,
                     composer_json,
                     view_config,
                     livewire_config,
-                    reply,
-                } => {
-                    self.handle_register_config_files(
-                        root_path,
-                        composer_json,
-                        view_config,
-                        livewire_config,
-                    );
-                    let _ = reply.send(());
-                }
-                SalsaRequest::UpdateConfigFile { path, text, reply } => {
-                    self.handle_update_config_file(path, text);
-                    let _ = reply.send(());
-                }
-                SalsaRequest::GetLaravelConfig { reply } => {
-                    let result = self.handle_get_laravel_config();
-                    let _ = reply.send(result);
-                }
+                );
+                let _ = reply.send(());
+            }
+            SalsaRequest::UpdateConfigFile { path, text, reply } => {
+                self.handle_update_config_file(path, text);
+                let _ = reply.send(());
+            }
+            SalsaRequest::GetConfigFileEntries { path, reply } => {
+                let result = self.config_files
+                    .get(&path)
+                    .map(|file| parse_config_file_entries(&self.db, *file));
+                let _ = reply.send(result);
+            }
+            SalsaRequest::GetRouteFilePrefixes { reply } => {
+                let result = self.handle_get_route_file_prefixes();
+                let _ = reply.send(result);
+            }
+            SalsaRequest::GetGateDefinitions { reply } => {
+                let result = self.handle_get_gate_definitions();
+                let _ = reply.send(result);
+            }
+            SalsaRequest::GetPolicyRegistrations { reply } => {
+                let result = self.handle_get_policy_registrations();
+                let _ = reply.send(result);
+            }
+            SalsaRequest::GetComposerPackages { reply } => {
+                let result = self.handle_get_composer_packages();
+                let _ = reply.send(result);
+            }
+            SalsaRequest::GetLaravelConfig { reply } => {
+                let result = self.handle_get_laravel_config();
+                let _ = reply.send(result);
+            }
 
-                // === Reference Finding Handlers ===
-                SalsaRequest::RegisterProjectFiles {
+            // === Reference Finding Handlers ===
+            SalsaRequest::RegisterProjectFiles {
+                root_path,
+                controller_paths,
+                view_paths,
+                livewire_path,
+                routes_path,
+                reply,
+            } => {
+                self.handle_register_project_files(
                     root_path,
                     controller_paths,
                     view_paths,
                     livewire_path,
                     routes_path,
-                    reply,
-                } => {
-                    self.handle_register_project_files(
-                        root_path,
-                        controller_paths,
-                        view_paths,
-                        livewire_path,
-                        routes_path,
-                    );
-                    let _ = reply.send(());
-                }
-                SalsaRequest::FindViewReferences { view_name, reply } => {
-                    let result = self.handle_find_view_references(&view_name);
-                    let _ = reply.send(result);
-                }
+                );
+                let _ = reply.send(());
+            }
+            SalsaRequest::UpdateProjectFile { path, exists, reply } => {
+                let changed = self.handle_update_project_file(path, exists);
+                let _ = reply.send(changed);
+            }
+            SalsaRequest::FindViewReferences { view_name, reply } => {
+                let result = self.handle_find_view_references(&view_name);
+                let _ = reply.send(result);
+            }
+            SalsaRequest::FindSectionSites { name, yields, reply } => {
+                let result = self.blade_region_sites(|db, file| {
+                    parse_section_refs(db, file)
+                        .into_iter()
+                        .filter(|section| section.name == name && section.is_yield == yields)
+                        .map(|section| (section.line, section.column, section.end_column))
+                        .collect()
+                });
+                let _ = reply.send(result);
+            }
+            SalsaRequest::FindStackSites { name, stacks, reply } => {
+                let result = self.blade_region_sites(|db, file| {
+                    parse_stack_refs(db, file)
+                        .into_iter()
+                        .filter(|stack| stack.name == name && stack.is_stack == stacks)
+                        .map(|stack| (stack.line, stack.column, stack.end_column))
+                        .collect()
+                });
+                let _ = reply.send(result);
+            }
+            SalsaRequest::GetReferencedViewNames { reply } => {
+                let result = self.handle_get_referenced_view_names();
+                let _ = reply.send(result);
+            }
+            SalsaRequest::GetProjectFileCounts { reply } => {
+                let _ = reply.send(ProjectFileCountsData {
+                    controllers: self.controller_files.len(),
+                    views: self.view_files.len(),
+                    livewire: self.livewire_files.len(),
+                    routes: self.route_files.len(),
+                });
+            }
+            SalsaRequest::FindTranslationReferences { key, reply } => {
+                let result = self.handle_find_translation_references(&key);
+                let _ = reply.send(result);
+            }
 
-                // === Service Provider Handlers ===
-                SalsaRequest::RegisterServiceProviderRegistry {
+            // === Service Provider Handlers ===
+            SalsaRequest::RegisterServiceProviderRegistry {
+                middleware_aliases,
+                bindings,
+                singletons,
+                reply,
+            } => {
+                self.handle_register_service_provider_registry(
                     middleware_aliases,
                     bindings,
                     singletons,
-                    reply,
-                } => {
-                    self.handle_register_service_provider_registry(
-                        middleware_aliases,
-                        bindings,
-                        singletons,
-                    );
-                    let _ = reply.send(());
-                }
-                SalsaRequest::GetMiddlewareByAlias { alias, reply } => {
-                    let result = self.handle_get_middleware_by_alias(&alias);
-                    let _ = reply.send(result);
-                }
-                SalsaRequest::GetBindingByName { name, reply } => {
-                    let result = self.handle_get_binding_by_name(&name);
-                    let _ = reply.send(result);
-                }
-                SalsaRequest::GetViewNamespace { namespace, reply } => {
-                    let result = self.handle_get_view_namespace(&namespace);
-                    let _ = reply.send(result);
-                }
-                SalsaRequest::GetAllViewNamespaces { reply } => {
-                    let result = self.handle_get_all_view_namespaces();
-                    let _ = reply.send(result);
-                }
-                SalsaRequest::GetBladeComponentReg { tag_name, reply } => {
-                    let result = self.handle_get_blade_component_reg(&tag_name);
-                    let _ = reply.send(result);
-                }
-                SalsaRequest::GetAllBladeComponentRegs { reply } => {
-                    let result = self.handle_get_all_blade_component_regs();
-                    let _ = reply.send(result);
-                }
-                SalsaRequest::GetComponentNamespace { prefix, reply } => {
-                    let result = self.handle_get_component_namespace(&prefix);
-                    let _ = reply.send(result);
-                }
-                SalsaRequest::GetAllComponentNamespaces { reply } => {
-                    let result = self.handle_get_all_component_namespaces();
-                    let _ = reply.send(result);
-                }
+                );
+                let _ = reply.send(());
+            }
+            SalsaRequest::GetMiddlewareByAlias { alias, reply } => {
+                let result = self.handle_get_middleware_by_alias(&alias);
+                let _ = reply.send(result);
+            }
+            SalsaRequest::GetBindingByName { name, reply } => {
+                let result = self.handle_get_binding_by_name(&name);
+                let _ = reply.send(result);
+            }
+            SalsaRequest::GetViewNamespace { namespace, reply } => {
+                let result = self.handle_get_view_namespace(&namespace);
+                let _ = reply.send(result);
+            }
+            SalsaRequest::GetAllViewNamespaces { reply } => {
+                let result = self.handle_get_all_view_namespaces();
+                let _ = reply.send(result);
+            }
+            SalsaRequest::GetBladeComponentReg { tag_name, reply } => {
+                let result = self.handle_get_blade_component_reg(&tag_name);
+                let _ = reply.send(result);
+            }
+            SalsaRequest::GetAllBladeComponentRegs { reply } => {
+                let result = self.handle_get_all_blade_component_regs();
+                let _ = reply.send(result);
+            }
+            SalsaRequest::GetLivewireComponentReg { alias, reply } => {
+                let result = self.handle_get_livewire_component_reg(&alias);
+                let _ = reply.send(result);
+            }
+            SalsaRequest::GetAllLivewireComponentRegs { reply } => {
+                let result = self.handle_get_all_livewire_component_regs();
+                let _ = reply.send(result);
+            }
+            SalsaRequest::GetAllBladeDirectiveRegs { reply } => {
+                let result = self.handle_get_all_blade_directive_regs();
+                let _ = reply.send(result);
+            }
+            SalsaRequest::GetComponentNamespace { prefix, reply } => {
+                let result = self.handle_get_component_namespace(&prefix);
+                let _ = reply.send(result);
+            }
+            SalsaRequest::GetAllComponentNamespaces { reply } => {
+                let result = self.handle_get_all_component_namespaces();
+                let _ = reply.send(result);
+            }
 
-                // === Environment Variable Handlers ===
-                SalsaRequest::RegisterEnvVariables { variables, reply } => {
-                    self.handle_register_env_variables(variables);
-                    let _ = reply.send(());
-                }
-                SalsaRequest::GetEnvVariable { name, reply } => {
-                    let result = self.handle_get_env_variable(&name);
-                    let _ = reply.send(result);
-                }
-                SalsaRequest::GetEnvVariableNames { reply } => {
-                    let result = self.handle_get_env_variable_names();
-                    let _ = reply.send(result);
-                }
+            // === Environment Variable Handlers ===
+            SalsaRequest::RegisterEnvVariables { variables, reply } => {
+                self.handle_register_env_variables(variables);
+                let _ = reply.send(());
+            }
+            SalsaRequest::GetEnvVariable { name, reply } => {
+                let result = self.handle_get_env_variable(&name);
+                let _ = reply.send(result);
+            }
+            SalsaRequest::GetEnvVariableNames { reply } => {
+                let result = self.handle_get_env_variable_names();
+                let _ = reply.send(result);
+            }
 
-                // === Salsa-based Environment Variable Handlers (New) ===
-                SalsaRequest::RegisterEnvSource { path, text, priority, reply } => {
-                    self.handle_register_env_source(path, text, priority);
-                    let _ = reply.send(());
-                }
-                SalsaRequest::GetParsedEnvVar { name, reply } => {
-                    let result = self.handle_get_parsed_env_var(&name);
-                    let _ = reply.send(result);
-                }
-                SalsaRequest::GetAllParsedEnvVars { reply } => {
-                    let result = self.handle_get_all_parsed_env_vars();
-                    let _ = reply.send(result);
-                }
+            // === Salsa-based Environment Variable Handlers (New) ===
+            SalsaRequest::RegisterEnvSource { path, text, priority, reply } => {
+                self.handle_register_env_source(path, text, priority);
+                let _ = reply.send(());
+            }
+            SalsaRequest::GetParsedEnvVar { name, reply } => {
+                let result = self.handle_get_parsed_env_var(&name);
+                let _ = reply.send(result);
+            }
+            SalsaRequest::GetAllParsedEnvVars { reply } => {
+                let result = self.handle_get_all_parsed_env_vars();
+                let _ = reply.send(result);
+            }
 
-                // === Salsa-based Service Provider Handlers (New) ===
-                SalsaRequest::RegisterServiceProviderSource { path, text, priority, root_path, reply } => {
-                    self.handle_register_service_provider_source(path, text, priority, root_path);
-                    let _ = reply.send(());
-                }
-                SalsaRequest::GetParsedMiddleware { alias, reply } => {
-                    let result = self.handle_get_parsed_middleware(&alias);
-                    let _ = reply.send(result);
-                }
-                SalsaRequest::GetAllParsedMiddleware { reply } => {
-                    let result = self.handle_get_all_parsed_middleware();
-                    let _ = reply.send(result);
-                }
-                SalsaRequest::GetParsedBinding { name, reply } => {
-                    let result = self.handle_get_parsed_binding(&name);
-                    let _ = reply.send(result);
+            // === JSON Translation Handlers ===
+            SalsaRequest::RegisterJsonTranslationSource { path, text, reply } => {
+                self.handle_register_json_translation_source(path, text);
+                let _ = reply.send(());
+            }
+            SalsaRequest::GetJsonTranslationKeys { path, reply } => {
+                let result = self.salsa_json_translation_files
+                    .get(&path)
+                    .map(|file| parse_json_translation_keys(&self.db, *file));
+                let _ = reply.send(result);
+            }
+
+            // === Salsa-based Service Provider Handlers (New) ===
+            SalsaRequest::RegisterServiceProviderSource { path, text, priority, root_path, reply } => {
+                // Newer than anything still waiting in the batch
+                if let Some(batch) = self.pending_batch.as_mut() {
+                    batch.superseded.insert(path.clone());
                 }
-                SalsaRequest::GetAllParsedBindings { reply } => {
-                    let result = self.handle_get_all_parsed_bindings();
-                    let _ = reply.send(result);
+                self.handle_register_service_provider_source(path, text, priority, root_path);
+                let _ = reply.send(());
+            }
+            SalsaRequest::RegisterServiceProviderBatch { sources, root_path, reply } => {
+                // One batch at a time - finish any earlier one first
+                while self.pending_batch.is_some() {
+                    self.continue_batch();
                 }
+                self.pending_batch = Some(PendingBatch {
+                    sources: sources.into_iter(),
+                    root_path,
+                    registered: 0,
+                    superseded: HashSet::new(),
+                    reply,
+                });
+                self.continue_batch();
+            }
+            SalsaRequest::GetParsedMiddleware { alias, reply } => {
+                let result = self.handle_get_parsed_middleware(&alias);
+                let _ = reply.send(result);
+            }
+            SalsaRequest::GetAllParsedMiddleware { reply } => {
+                let result = self.handle_get_all_parsed_middleware();
+                let _ = reply.send(result);
+            }
+            SalsaRequest::GetParsedBinding { name, reply } => {
+                let result = self.handle_get_parsed_binding(&name);
+                let _ = reply.send(result);
+            }
+            SalsaRequest::GetAllParsedBindings { reply } => {
+                let result = self.handle_get_all_parsed_bindings();
+                let _ = reply.send(result);
+            }
 
-                // === Cache-based Registration Handlers ===
-                SalsaRequest::RegisterCachedMiddleware { alias, class, class_file, source_file, line, reply } => {
+            // === Cache-based Registration Handlers ===
+            SalsaRequest::RegisterCachedMiddleware { alias, class, class_file, source_file, line, reply } => {
+                self.handle_register_cached_middleware(alias, class, class_file, source_file, line);
+                let _ = reply.send(());
+            }
+            SalsaRequest::RegisterCachedBinding { name, class, binding_type, class_file, source_file, line, reply } => {
+                self.handle_register_cached_binding(name, class, binding_type, class_file, source_file, line);
+                let _ = reply.send(());
+            }
+            SalsaRequest::RegisterCachedMiddlewareBatch { entries, reply } => {
+                for (alias, class, class_file, source_file, line) in entries {
                     self.handle_register_cached_middleware(alias, class, class_file, source_file, line);
-                    let _ = reply.send(());
                 }
-                SalsaRequest::RegisterCachedBinding { name, class, binding_type, class_file, source_file, line, reply } => {
+                let _ = reply.send(());
+            }
+            SalsaRequest::RegisterCachedBindingBatch { entries, reply } => {
+                for (name, class, binding_type, class_file, source_file, line) in entries {
                     self.handle_register_cached_binding(name, class, binding_type, class_file, source_file, line);
-                    let _ = reply.send(());
-                }
-                SalsaRequest::RegisterCachedMiddlewareBatch { entries, reply } => {
-                    for (alias, class, class_file, source_file, line) in entries {
-                        self.handle_register_cached_middleware(alias, class, class_file, source_file, line);
-                    }
-                    let _ = reply.send(());
                 }
-                SalsaRequest::RegisterCachedBindingBatch { entries, reply } => {
-                    for (name, class, binding_type, class_file, source_file, line) in entries {
-                        self.handle_register_cached_binding(name, class, binding_type, class_file, source_file, line);
-                    }
-                    let _ = reply.send(());
-                }
-                SalsaRequest::RegisterCachedConfig { config, reply } => {
-                    // Set config directly from cache, bypassing parsing
-                    self.config_root = Some(config.root.clone());
-                    self.config_cache = Some((self.config_version, config));
-                    tracing::info!("📋 Registered cached Laravel config");
-                    let _ = reply.send(());
-                }
-                SalsaRequest::RegisterCachedEnvVars { variables, reply } => {
-                    // Set env vars directly from cache
-                    let count = variables.len();
-                    for (name, value) in variables {
-                        self.env_variables.insert(name.clone(), EnvVariableData {
-                            name,
-                            value,
-                            file_path: PathBuf::from(".env"), // Placeholder
-                            line: 0,
-                            column: 0,
-                            value_column: 0,
-                            is_commented: false,
-                        });
-                    }
-                    tracing::debug!("Registered {} cached env variables", count);
-                    let _ = reply.send(());
+                let _ = reply.send(());
+            }
+            SalsaRequest::RegisterCachedConfig { config, reply } => {
+                // Set config directly from cache, bypassing parsing
+                self.config_root = Some(config.root.clone());
+                self.config_cache = Some((self.config_version, config));
+                tracing::info!("📋 Registered cached Laravel config");
+                let _ = reply.send(());
+            }
+            SalsaRequest::RegisterCachedEnvVars { variables, reply } => {
+                // Set env vars directly from cache
+                let count = variables.len();
+                for (name, value) in variables {
+                    self.env_variables.insert(name.clone(), EnvVariableData {
+                        name,
+                        value,
+                        file_path: PathBuf::from(".env"), // Placeholder
+                        line: 0,
+                        column: 0,
+                        value_column: 0,
+                        is_commented: false,
+                    });
                 }
+                tracing::debug!("Registered {} cached env variables", count);
+                let _ = reply.send(());
+            }
+            SalsaRequest::ResetProjectIndex { reply } => {
+                self.handle_reset_project_index();
+                let _ = reply.send(());
+            }
 
-                SalsaRequest::Shutdown => {
-                    break;
-                }
+            SalsaRequest::Shutdown => {
+                return false;
             }
         }
+        true
+    }
+
+    /// Handle project index reset (before a full rebuild)
+    fn handle_reset_project_index(&mut self) {
+        // Registries filled from the disk cache, which may be stale
+        self.sp_middleware_aliases.clear();
+        self.sp_bindings.clear();
+        self.sp_singletons.clear();
+        self.sp_view_namespaces.clear();
+        self.sp_blade_components.clear();
+        self.sp_component_namespaces.clear();
+        self.env_variables.clear();
+
+        // Providers and env files that no longer exist must not linger
+        self.salsa_sp_files.clear();
+        self.salsa_env_files.clear();
+        self.salsa_json_translation_files.clear();
+
+        self.config_version += 1;
+        self.config_cache = None;
+        self.pattern_cache.clear();
+
+        // A batch queued before the reset would bring back providers the
+        // rebuild registers again anyway - stop it where it is
+        if let Some(batch) = self.pending_batch.take() {
+            let _ = batch.reply.send(batch.registered);
+        }
     }
 
     /// Handle file update - create or update the SourceFile
@@ -3292,11 +5241,28 @@ impl SalsaActor {
             file.set_text(&mut self.db).to(text);
         } else {
             // Create new file
-            let file = SourceFile::new(&self.db, path.clone(), version, text);
+            let is_blade = FileKind::from_path(&path, &self.file_types).is_blade();
+            let file = SourceFile::new(&self.db, path.clone(), version, text, is_blade);
             self.files.insert(path, file);
         }
     }
 
+    /// Store new file type settings and re-classify files already loaded
+    fn handle_set_file_types(&mut self, file_types: FileTypeSettings) {
+        if file_types == self.file_types {
+            return;
+        }
+        self.file_types = file_types;
+
+        for (path, file) in &self.files {
+            let is_blade = FileKind::from_path(path, &self.file_types).is_blade();
+            if file.is_blade(&self.db) != is_blade {
+                file.set_is_blade(&mut self.db).to(is_blade);
+                self.pattern_cache.pop(path);
+            }
+        }
+    }
+
     /// Handle pattern query - parse file and extract patterns
     /// Uses cached data if version matches, otherwise converts and caches
     /// Returns Arc for efficient sharing without cloning the entire data structure
@@ -3333,6 +5299,7 @@ impl SalsaActor {
                     column: v.column(&self.db),
                     end_column: v.end_column(&self.db),
                     is_route_view: v.is_route_view(&self.db),
+                    is_volt_route: v.is_volt_route(&self.db),
                 })
             })
             .collect();
@@ -3389,6 +5356,7 @@ impl SalsaActor {
                     line: c.line(&self.db),
                     column: c.column(&self.db),
                     end_column: c.end_column(&self.db),
+                    is_set: c.is_set(&self.db),
                 })
             })
             .collect();
@@ -3428,6 +5396,7 @@ impl SalsaActor {
                     line: t.line(&self.db),
                     column: t.column(&self.db),
                     end_column: t.end_column(&self.db),
+                    is_existence_check: t.is_existence_check(&self.db),
                 })
             })
             .collect();
@@ -3462,56 +5431,123 @@ impl SalsaActor {
 
         // Parse route, url, action patterns directly (not cached in Salsa to keep field count under 12)
         // Uses single-pass extraction - query is cached globally so this is fast
-        use crate::parser::{parse_php, language_php};
-        use crate::queries::extract_all_php_patterns;
-
         let text = file.text(&self.db);
+        let echo_sources = if file.is_blade(&self.db) {
+            blade_echo_php_sources(text)
+        } else {
+            Vec::new()
+        };
         let mut route_refs = Vec::new();
         let mut url_refs = Vec::new();
         let mut action_refs = Vec::new();
         let mut feature_refs = Vec::new();
+        let mut config_name_refs = Vec::new();
+        let mut model_refs = Vec::new();
+        let mut ability_refs = Vec::new();
+        let mut rule_class_refs = Vec::new();
+        let mut command_refs = Vec::new();
+
+        let slot_refs = parse_slot_refs(&self.db, *file)
+            .into_iter()
+            .map(Arc::new)
+            .collect();
+        let section_refs = parse_section_refs(&self.db, *file)
+            .into_iter()
+            .map(Arc::new)
+            .collect();
+        let stack_refs = parse_stack_refs(&self.db, *file)
+            .into_iter()
+            .map(Arc::new)
+            .collect();
 
-        if let Ok(tree) = parse_php(text) {
-            let lang = language_php();
-
-            if let Ok(php_patterns) = extract_all_php_patterns(&tree, text, &lang) {
-                for r in php_patterns.route_calls {
-                    route_refs.push(Arc::new(RouteReferenceData {
-                        name: r.route_name.to_string(),
-                        line: r.row as u32,
-                        column: r.column as u32,
-                        end_column: r.end_column as u32,
-                    }));
-                }
+        for php_patterns in extract_php_pattern_sets(text, &echo_sources) {
+            for r in php_patterns.route_calls {
+                route_refs.push(Arc::new(RouteReferenceData {
+                    name: r.route_name.to_string(),
+                    line: r.row as u32,
+                    column: r.column as u32,
+                    end_column: r.end_column as u32,
+                }));
+            }
 
-                for u in php_patterns.url_calls {
-                    url_refs.push(Arc::new(UrlReferenceData {
-                        path: u.url_path.to_string(),
-                        line: u.row as u32,
-                        column: u.column as u32,
-                        end_column: u.end_column as u32,
-                    }));
-                }
+            for u in php_patterns.url_calls {
+                url_refs.push(Arc::new(UrlReferenceData {
+                    path: u.url_path.to_string(),
+                    line: u.row as u32,
+                    column: u.column as u32,
+                    end_column: u.end_column as u32,
+                }));
+            }
 
-                for a in php_patterns.action_calls {
-                    action_refs.push(Arc::new(ActionReferenceData {
-                        action: a.action_name.to_string(),
-                        line: a.row as u32,
-                        column: a.column as u32,
-                        end_column: a.end_column as u32,
-                    }));
-                }
+            for a in php_patterns.action_calls {
+                action_refs.push(Arc::new(ActionReferenceData {
+                    action: match a.method_name {
+                        Some(method) => format!("{}@{}", a.action_name, method),
+                        None => a.action_name.to_string(),
+                    },
+                    line: a.row as u32,
+                    column: a.column as u32,
+                    end_column: a.end_column as u32,
+                }));
+            }
 
-                for f in php_patterns.feature_calls {
-                    feature_refs.push(Arc::new(FeatureReferenceData {
-                        feature_name: f.feature_name.to_string(),
-                        method_name: f.method_name.to_string(),
-                        is_class_reference: f.is_class_reference,
-                        line: f.row as u32,
-                        column: f.column as u32,
-                        end_column: f.end_column as u32,
-                    }));
-                }
+            for f in php_patterns.feature_calls {
+                feature_refs.push(Arc::new(FeatureReferenceData {
+                    feature_name: f.feature_name.to_string(),
+                    method_name: f.method_name.to_string(),
+                    is_class_reference: f.is_class_reference,
+                    line: f.row as u32,
+                    column: f.column as u32,
+                    end_column: f.end_column as u32,
+                }));
+            }
+
+            for c in php_patterns.config_name_calls {
+                config_name_refs.push(Arc::new(ConfigNameReferenceData {
+                    name: c.name.to_string(),
+                    config_path: c.config_path.to_string(),
+                    line: c.row as u32,
+                    column: c.column as u32,
+                    end_column: c.end_column as u32,
+                }));
+            }
+
+            for m in php_patterns.model_calls {
+                model_refs.push(Arc::new(ModelReferenceData {
+                    class_name: m.class_name.to_string(),
+                    line: m.row as u32,
+                    column: m.column as u32,
+                    end_column: m.end_column as u32,
+                }));
+            }
+
+            for a in php_patterns.ability_calls {
+                ability_refs.push(Arc::new(AbilityReferenceData {
+                    ability: a.ability.to_string(),
+                    subject: a.subject.map(str::to_string),
+                    line: a.row as u32,
+                    column: a.column as u32,
+                    end_column: a.end_column as u32,
+                }));
+            }
+
+            for r in php_patterns.rule_classes {
+                rule_class_refs.push(Arc::new(RuleClassReferenceData {
+                    class_name: r.class_name.to_string(),
+                    line: r.row as u32,
+                    column: r.column as u32,
+                    end_column: r.end_column as u32,
+                }));
+            }
+
+            for c in php_patterns.command_calls {
+                command_refs.push(Arc::new(CommandReferenceData {
+                    command: c.command.to_string(),
+                    is_class_reference: c.is_class_reference,
+                    line: c.row as u32,
+                    column: c.column as u32,
+                    end_column: c.end_column as u32,
+                }));
             }
         }
 
@@ -3530,6 +5566,14 @@ impl SalsaActor {
             url_refs,
             action_refs,
             feature_refs,
+            config_name_refs,
+            model_refs,
+            ability_refs,
+            rule_class_refs,
+            command_refs,
+            slot_refs,
+            section_refs,
+            stack_refs,
             sorted_positions: Vec::new(),
         };
 
@@ -3630,7 +5674,17 @@ impl SalsaActor {
 
         // Collect view namespaces from all parsed service providers
         let mut view_namespaces: HashMap<String, PathBuf> = HashMap::new();
+        let mut view_paths = config_ref.view_paths(&self.db).clone();
         let mut component_namespaces: HashMap<String, String> = HashMap::new();
+        let mut translation_namespaces: HashMap<String, PathBuf> = HashMap::new();
+        let mut package_configs: HashMap<String, PathBuf> = HashMap::new();
+
+        // nwidart/laravel-modules: each module registers `{alias}::` for its views.
+        // Module providers build that path with module_path(), which the provider
+        // parser can't follow, so the module layout takes precedence.
+        for module in discover_modules(&root, composer.map(|f| f.text(&self.db).as_str())) {
+            view_namespaces.insert(module.view_namespace, module.view_dir);
+        }
 
         if let Some(sp_root) = self.salsa_sp_root.as_ref() {
             for sp_file in self.salsa_sp_files.values() {
@@ -3648,6 +5702,18 @@ impl SalsaActor {
                     }
                 }
 
+                // Collect runtime view locations (View::addLocation / prependLocation)
+                for (location, prepend) in parsed.view_locations(&self.db) {
+                    if view_paths.contains(location) {
+                        continue;
+                    }
+                    if *prepend {
+                        view_paths.insert(0, location.clone());
+                    } else {
+                        view_paths.push(location.clone());
+                    }
+                }
+
                 // Collect component namespaces
                 for cn in parsed.component_namespaces(&self.db) {
                     let prefix = cn.prefix(&self.db).namespace(&self.db).clone();
@@ -3657,6 +5723,22 @@ impl SalsaActor {
                         None => { component_namespaces.insert(prefix, php_ns); }
                     }
                 }
+
+                // Collect translation namespaces
+                for tn in parsed.translation_namespaces(&self.db) {
+                    let ns = tn.namespace(&self.db).namespace(&self.db).clone();
+                    if let Some(path) = tn.lang_path(&self.db).clone() {
+                        translation_namespaces.entry(ns).or_insert(path);
+                    }
+                }
+
+                // Collect package config files
+                for merge in parsed.config_merges(&self.db) {
+                    let key = merge.key(&self.db).namespace(&self.db).clone();
+                    if let Some(path) = merge.config_path(&self.db).clone() {
+                        package_configs.entry(key).or_insert(path);
+                    }
+                }
             }
         }
 
@@ -3673,12 +5755,15 @@ impl SalsaActor {
         // Convert to data transfer type
         let data = LaravelConfigData {
             root: config_ref.root(&self.db).clone(),
-            view_paths: config_ref.view_paths(&self.db).clone(),
+            view_paths,
             component_paths: config_ref.component_paths(&self.db).clone(),
             livewire_path: config_ref.livewire_path(&self.db).clone(),
+            livewire_view_path: config_ref.livewire_view_path(&self.db).clone(),
             has_livewire: config_ref.has_livewire(&self.db),
             view_namespaces,
             component_namespaces,
+            translation_namespaces,
+            package_configs,
         };
 
         // Cache the result
@@ -3701,13 +5786,20 @@ impl SalsaActor {
     ) {
         use walkdir::WalkDir;
 
-        self.project_files_version += 1;
+        self.project_file_roots = Some(ProjectFileRoots {
+            controller_dirs: controller_paths.iter().map(|p| root_path.join(p)).collect(),
+            view_dirs: view_paths.iter().map(|p| root_path.join(p)).collect(),
+            livewire_dir: livewire_path.as_ref().map(|p| root_path.join(p)),
+            routes_dir: root_path.join(&routes_path),
+            app_dir: root_path.join("app"),
+        });
 
         // Clear existing file lists
         self.controller_files.clear();
         self.view_files.clear();
         self.livewire_files.clear();
         self.route_files.clear();
+        self.source_files.clear();
 
         // Scan controller directories
         for controller_path in &controller_paths {
@@ -3718,14 +5810,12 @@ impl SalsaActor {
                     .filter_entry(|e| e.file_name().to_str().map(|s| s != ".git").unwrap_or(true))
                     .filter_map(|e| e.ok())
                 {
-                    if entry.file_type().is_file() {
-                        if let Some(ext) = entry.path().extension() {
-                            if ext == "php" {
-                                let path = entry.path().to_path_buf();
-                                self.controller_files.push(path.clone());
-                                self.ensure_file_registered(&path);
-                            }
-                        }
+                    if entry.file_type().is_file()
+                        && FileKind::from_path(entry.path(), &self.file_types).is_source()
+                    {
+                        let path = entry.path().to_path_buf();
+                        self.controller_files.push(path.clone());
+                        self.ensure_file_registered(&path);
                     }
                 }
             }
@@ -3742,7 +5832,7 @@ impl SalsaActor {
                 {
                     if entry.file_type().is_file() {
                         if let Some(file_name) = entry.path().file_name() {
-                            if file_name.to_string_lossy().ends_with(".blade.php") {
+                            if FileKind::from_path(file_name, &self.file_types).is_blade() {
                                 let path = entry.path().to_path_buf();
                                 self.view_files.push(path.clone());
                                 self.ensure_file_registered(&path);
@@ -3762,14 +5852,12 @@ impl SalsaActor {
                     .filter_entry(|e| e.file_name().to_str().map(|s| s != ".git").unwrap_or(true))
                     .filter_map(|e| e.ok())
                 {
-                    if entry.file_type().is_file() {
-                        if let Some(ext) = entry.path().extension() {
-                            if ext == "php" {
-                                let path = entry.path().to_path_buf();
-                                self.livewire_files.push(path.clone());
-                                self.ensure_file_registered(&path);
-                            }
-                        }
+                    if entry.file_type().is_file()
+                        && FileKind::from_path(entry.path(), &self.file_types).is_source()
+                    {
+                        let path = entry.path().to_path_buf();
+                        self.livewire_files.push(path.clone());
+                        self.ensure_file_registered(&path);
                     }
                 }
             }
@@ -3783,19 +5871,40 @@ impl SalsaActor {
                 .filter_entry(|e| e.file_name().to_str().map(|s| s != ".git").unwrap_or(true))
                 .filter_map(|e| e.ok())
             {
-                if entry.file_type().is_file() {
-                    if let Some(ext) = entry.path().extension() {
-                        if ext == "php" {
-                            let path = entry.path().to_path_buf();
-                            self.route_files.push(path.clone());
-                            self.ensure_file_registered(&path);
-                        }
-                    }
+                if entry.file_type().is_file()
+                    && FileKind::from_path(entry.path(), &self.file_types).is_source()
+                {
+                    let path = entry.path().to_path_buf();
+                    self.route_files.push(path.clone());
+                    self.ensure_file_registered(&path);
+                }
+            }
+        }
+
+        // Scan the rest of app/ - files already listed above classify as their own kind
+        let app_path = root_path.join("app");
+        if let Some(roots) = self.project_file_roots.clone().filter(|_| app_path.exists()) {
+            for entry in WalkDir::new(&app_path)
+                .into_iter()
+                .filter_entry(|e| e.file_name().to_str().map(|s| s != ".git").unwrap_or(true))
+                .filter_map(|e| e.ok())
+            {
+                if entry.file_type().is_file()
+                    && roots.classify(entry.path(), &self.file_types) == Some(ProjectFileKind::Source)
+                {
+                    let path = entry.path().to_path_buf();
+                    self.source_files.push(path.clone());
+                    self.ensure_file_registered(&path);
                 }
             }
         }
 
-        // Create the ProjectFiles input
+        self.rebuild_project_files_input();
+    }
+
+    /// Create a new ProjectFiles input from the current file lists
+    fn rebuild_project_files_input(&mut self) {
+        self.project_files_version += 1;
         self.project_files = Some(ProjectFiles::new(
             &self.db,
             self.project_files_version,
@@ -3806,13 +5915,62 @@ impl SalsaActor {
         ));
     }
 
+    /// Apply a single created/deleted file to the project file lists
+    ///
+    /// Returns true if any list changed. Files outside the registered
+    /// directories (or before registration) are ignored.
+    fn handle_update_project_file(&mut self, path: PathBuf, exists: bool) -> bool {
+        let Some(roots) = &self.project_file_roots else {
+            return false;
+        };
+        let Some(kind) = roots.classify(&path, &self.file_types) else {
+            return false;
+        };
+
+        let list = match kind {
+            ProjectFileKind::Controller => &mut self.controller_files,
+            ProjectFileKind::View => &mut self.view_files,
+            ProjectFileKind::Livewire => &mut self.livewire_files,
+            ProjectFileKind::Route => &mut self.route_files,
+            ProjectFileKind::Source => &mut self.source_files,
+        };
+
+        let changed = if exists {
+            if list.contains(&path) {
+                false
+            } else {
+                list.push(path.clone());
+                true
+            }
+        } else {
+            let before = list.len();
+            list.retain(|p| p != &path);
+            list.len() != before
+        };
+
+        if !changed {
+            return false;
+        }
+
+        if exists {
+            self.ensure_file_registered(&path);
+        } else {
+            self.files.remove(&path);
+        }
+
+        debug!("Project file {:?} ({:?}) {}", path, kind, if exists { "added" } else { "removed" });
+        self.rebuild_project_files_input();
+        true
+    }
+
     /// Ensure a file is registered with Salsa (read from disk if needed)
     fn ensure_file_registered(&mut self, path: &PathBuf) {
         use std::collections::hash_map::Entry;
         // Use entry API to avoid double lookup
         if let Entry::Vacant(entry) = self.files.entry(path.clone()) {
             if let Ok(text) = std::fs::read_to_string(path) {
-                let file = SourceFile::new(&self.db, path.clone(), 0, text);
+                let is_blade = FileKind::from_path(path, &self.file_types).is_blade();
+                let file = SourceFile::new(&self.db, path.clone(), 0, text, is_blade);
                 entry.insert(file);
             }
         }
@@ -3862,44 +6020,164 @@ impl SalsaActor {
                     }
                 }
             }
-        }
+        }
+
+        // Search Livewire files
+        for path in &self.livewire_files.clone() {
+            if let Some(patterns) = self.handle_get_patterns(path) {
+                for view_ref in &patterns.views {
+                    if view_ref.name == view_name {
+                        references.push(ViewReferenceLocationData {
+                            file_path: path.clone(),
+                            line: view_ref.line,
+                            character: view_ref.column,
+                            reference_type: FileReferenceType::LivewireComponent,
+                            view_name: view_ref.name.clone(),
+                            is_route_view: view_ref.is_route_view,
+                        });
+                    }
+                }
+            }
+        }
+
+        // Search route files
+        for path in &self.route_files.clone() {
+            if let Some(patterns) = self.handle_get_patterns(path) {
+                for view_ref in &patterns.views {
+                    if view_ref.name == view_name {
+                        references.push(ViewReferenceLocationData {
+                            file_path: path.clone(),
+                            line: view_ref.line,
+                            character: view_ref.column,
+                            reference_type: FileReferenceType::Route,
+                            view_name: view_ref.name.clone(),
+                            is_route_view: view_ref.is_route_view,
+                        });
+                    }
+                }
+            }
+        }
+
+        // Search the rest of app/ (mailables, notifications, view composers)
+        for path in &self.source_files.clone() {
+            if let Some(patterns) = self.handle_get_patterns(path) {
+                for view_ref in &patterns.views {
+                    if view_ref.name == view_name {
+                        references.push(ViewReferenceLocationData {
+                            file_path: path.clone(),
+                            line: view_ref.line,
+                            character: view_ref.column,
+                            reference_type: FileReferenceType::PhpSource,
+                            view_name: view_ref.name.clone(),
+                            is_route_view: view_ref.is_route_view,
+                        });
+                    }
+                }
+            }
+        }
+
+        references
+    }
+
+    /// Collect matching sites from every registered view and open Blade document
+    /// `sites` returns (line, column, end_column) triples and is memoized per file by Salsa
+    fn blade_region_sites(
+        &mut self,
+        sites: impl Fn(&dyn Db, SourceFile) -> Vec<(u32, u32, u32)>,
+    ) -> Vec<BladeRegionSiteData> {
+        let paths: BTreeSet<PathBuf> = self.view_files.iter()
+            .chain(self.files.keys())
+            .cloned()
+            .collect();
+
+        let mut result = Vec::new();
+        for path in paths {
+            let Some(file) = self.files.get(&path).copied() else {
+                continue;
+            };
+            if !file.is_blade(&self.db) {
+                continue;
+            }
+            for (line, column, end_column) in sites(&self.db, file) {
+                result.push(BladeRegionSiteData { file_path: path.clone(), line, column, end_column });
+            }
+        }
+        result
+    }
+
+    /// Handle get referenced view names request
+    fn handle_get_referenced_view_names(&mut self) -> HashSet<String> {
+        use lazy_static::lazy_static;
+        use regex::Regex;
+
+        lazy_static! {
+            static ref QUOTED: Regex = Regex::new(r#"['"]([^'"]+)['"]"#).unwrap();
+        }
+        const VIEW_DIRECTIVES: &[&str] = &[
+            "extends", "include", "includeIf", "includeWhen", "includeUnless", "includeFirst", "each", "component",
+        ];
+
+        let paths: BTreeSet<PathBuf> = self.controller_files.iter()
+            .chain(&self.view_files)
+            .chain(&self.livewire_files)
+            .chain(&self.route_files)
+            .chain(&self.source_files)
+            .chain(self.files.keys())
+            .cloned()
+            .collect();
+
+        let mut names = HashSet::new();
+        for path in &paths {
+            let Some(patterns) = self.handle_get_patterns(path) else {
+                continue;
+            };
+            names.extend(patterns.views.iter().map(|view_ref| view_ref.name.clone()));
+
+            // Every string argument counts: @includeWhen's view is the second one,
+            // @includeFirst takes a list, and @each names a fallback view too
+            for directive in &patterns.directives {
+                if !VIEW_DIRECTIVES.contains(&directive.name.as_str()) {
+                    continue;
+                }
+                if let Some(ref args) = directive.arguments {
+                    names.extend(QUOTED.captures_iter(args).map(|c| c[1].to_string()));
+                }
+            }
 
-        // Search Livewire files
-        for path in &self.livewire_files.clone() {
-            if let Some(patterns) = self.handle_get_patterns(path) {
-                for view_ref in &patterns.views {
-                    if view_ref.name == view_name {
-                        references.push(ViewReferenceLocationData {
-                            file_path: path.clone(),
-                            line: view_ref.line,
-                            character: view_ref.column,
-                            reference_type: FileReferenceType::LivewireComponent,
-                            view_name: view_ref.name.clone(),
-                            is_route_view: view_ref.is_route_view,
-                        });
-                    }
-                }
+            for component in &patterns.components {
+                names.insert(format!("components.{}", component.name));
+                names.insert(format!("components.{}.index", component.name));
+            }
+            for livewire in &patterns.livewire_refs {
+                names.insert(format!("livewire.{}", livewire.name));
             }
         }
+        names
+    }
 
-        // Search route files
-        for path in &self.route_files.clone() {
-            if let Some(patterns) = self.handle_get_patterns(path) {
-                for view_ref in &patterns.views {
-                    if view_ref.name == view_name {
-                        references.push(ViewReferenceLocationData {
+    /// Handle find translation references request
+    ///
+    /// Searches every registered source file (project files plus open documents),
+    /// sorted by path so results are stable.
+    fn handle_find_translation_references(&mut self, key: &str) -> Vec<TranslationReferenceLocationData> {
+        let mut paths: Vec<PathBuf> = self.files.keys().cloned().collect();
+        paths.sort();
+
+        let mut references = Vec::new();
+        for path in paths {
+            if let Some(patterns) = self.handle_get_patterns(&path) {
+                for trans in &patterns.translation_refs {
+                    if trans.key == key {
+                        references.push(TranslationReferenceLocationData {
                             file_path: path.clone(),
-                            line: view_ref.line,
-                            character: view_ref.column,
-                            reference_type: FileReferenceType::Route,
-                            view_name: view_ref.name.clone(),
-                            is_route_view: view_ref.is_route_view,
+                            line: trans.line,
+                            column: trans.column,
+                            end_column: trans.end_column,
                         });
                     }
                 }
             }
         }
-
         references
     }
 
@@ -3964,6 +6242,67 @@ impl SalsaActor {
         best
     }
 
+    /// Collect route file prefixes from service providers and routes files
+    fn handle_get_route_file_prefixes(&self) -> HashMap<PathBuf, String> {
+        let mut prefixes = HashMap::new();
+
+        if let Some(root) = self.salsa_sp_root.as_ref() {
+            for sp_file in self.salsa_sp_files.values() {
+                let parsed = parse_service_provider_source(&self.db, *sp_file, root.clone());
+                for (path, prefix) in parsed.route_file_prefixes(&self.db) {
+                    prefixes.entry(path.clone()).or_insert_with(|| prefix.clone());
+                }
+            }
+        }
+
+        if let Some(root) = self.config_root.as_ref() {
+            for path in &self.route_files {
+                let Some(file) = self.files.get(path) else { continue };
+                for (path, prefix) in parse_route_file_prefixes(&self.db, *file, root.clone()) {
+                    prefixes.entry(path).or_insert(prefix);
+                }
+            }
+        }
+
+        prefixes
+    }
+
+    /// Collect `Gate::define()` abilities from every registered service provider
+    fn handle_get_gate_definitions(&self) -> Vec<GateDefinitionData> {
+        let mut gates = Vec::new();
+        for (path, sp_file) in &self.salsa_sp_files {
+            let parsed = parse_authorization_source(&self.db, *sp_file);
+            for (name, line, column) in parsed.gates(&self.db) {
+                gates.push(GateDefinitionData {
+                    name: name.clone(),
+                    source_file: path.clone(),
+                    line: *line,
+                    column: *column,
+                });
+            }
+        }
+        gates
+    }
+
+    /// Collect `Gate::policy()` registrations from every registered service provider
+    fn handle_get_policy_registrations(&self) -> Vec<(String, String)> {
+        self.salsa_sp_files
+            .values()
+            .flat_map(|sp_file| parse_authorization_source(&self.db, *sp_file).policies(&self.db).clone())
+            .collect()
+    }
+
+    /// Packages required in the registered composer.json
+    fn handle_get_composer_packages(&self) -> Vec<String> {
+        let Some(root) = self.config_root.as_ref() else {
+            return Vec::new();
+        };
+        self.config_files
+            .get(&root.join("composer.json"))
+            .map(|file| parse_composer_json(&self.db, *file).1)
+            .unwrap_or_default()
+    }
+
     /// Handle get all view namespaces
     fn handle_get_all_view_namespaces(&self) -> Vec<ViewNamespaceData> {
         let mut merged: HashMap<String, ViewNamespaceData> = self.sp_view_namespaces.clone();
@@ -4055,6 +6394,69 @@ impl SalsaActor {
         merged.into_values().collect()
     }
 
+    /// Handle get all Livewire component registrations (highest priority wins per alias)
+    fn handle_get_all_livewire_component_regs(&self) -> Vec<LivewireComponentRegData> {
+        let mut merged: HashMap<String, LivewireComponentRegData> = HashMap::new();
+
+        if let Some(root) = self.salsa_sp_root.as_ref() {
+            for sp_file in self.salsa_sp_files.values() {
+                let parsed = parse_service_provider_source(&self.db, *sp_file, root.clone());
+                for lw in parsed.livewire_components(&self.db) {
+                    let alias = lw.alias(&self.db).name(&self.db).clone();
+                    let data = LivewireComponentRegData {
+                        alias: alias.clone(),
+                        class_name: lw.class_name(&self.db).clone(),
+                        file_path: lw.file_path(&self.db).clone(),
+                        source_file: lw.source_file(&self.db).clone(),
+                        source_line: lw.source_line(&self.db),
+                        priority: lw.priority(&self.db),
+                    };
+
+                    match merged.get(&alias) {
+                        Some(existing) if existing.priority >= data.priority => {}
+                        _ => { merged.insert(alias, data); }
+                    }
+                }
+            }
+        }
+
+        merged.into_values().collect()
+    }
+
+    /// Handle get all custom Blade directive registrations (highest priority wins per name)
+    fn handle_get_all_blade_directive_regs(&self) -> Vec<BladeDirectiveRegData> {
+        let mut merged: HashMap<String, BladeDirectiveRegData> = HashMap::new();
+
+        if let Some(root) = self.salsa_sp_root.as_ref() {
+            for sp_file in self.salsa_sp_files.values() {
+                let parsed = parse_service_provider_source(&self.db, *sp_file, root.clone());
+                for directive in parsed.blade_directives(&self.db) {
+                    let name = directive.name(&self.db).clone();
+                    let data = BladeDirectiveRegData {
+                        name: name.clone(),
+                        source_file: directive.source_file(&self.db).clone(),
+                        source_line: directive.source_line(&self.db),
+                        priority: directive.priority(&self.db),
+                    };
+
+                    match merged.get(&name) {
+                        Some(existing) if existing.priority >= data.priority => {}
+                        _ => { merged.insert(name, data); }
+                    }
+                }
+            }
+        }
+
+        merged.into_values().collect()
+    }
+
+    /// Handle get Livewire component registration by alias
+    fn handle_get_livewire_component_reg(&self, alias: &str) -> Option<LivewireComponentRegData> {
+        self.handle_get_all_livewire_component_regs()
+            .into_iter()
+            .find(|data| data.alias == alias)
+    }
+
     /// Handle get component namespace by prefix
     fn handle_get_component_namespace(&self, prefix: &str) -> Option<ComponentNamespaceData> {
         // First check the legacy cache
@@ -4152,6 +6554,24 @@ impl SalsaActor {
         }
     }
 
+    /// Handle registering a JSON translation file for Salsa to parse
+    fn handle_register_json_translation_source(&mut self, path: PathBuf, text: String) {
+        use salsa::Setter;
+
+        if let Some(file) = self.salsa_json_translation_files.get(&path) {
+            // Unchanged content keeps the memoized key set
+            if file.text(&self.db) == &text {
+                return;
+            }
+            let version = file.version(&self.db) + 1;
+            file.set_version(&mut self.db).to(version);
+            file.set_text(&mut self.db).to(text);
+        } else {
+            let file = JsonTranslationFile::new(&self.db, path.clone(), 1, text);
+            self.salsa_json_translation_files.insert(path, file);
+        }
+    }
+
     /// Handle getting a parsed env variable by name from Salsa
     fn handle_get_parsed_env_var(&self, name: &str) -> Option<ParsedEnvVarData> {
         // Find the variable with the highest priority
@@ -4222,6 +6642,8 @@ impl SalsaActor {
         use salsa::Setter;
         self.salsa_sp_version += 1;
         self.salsa_sp_root = Some(root_path);
+        // View namespaces and locations in the config come from providers
+        self.config_cache = None;
 
         if let Some(file) = self.salsa_sp_files.get(&path) {
             // Update existing file
@@ -4432,9 +6854,9 @@ mod vite_tests {
 
     #[test]
     fn test_vite_singular_syntax() {
-        // @vite('resources/css/app.css') - args from tree-sitter
-        let args = "('resources/css/app.css')";
-        let results = parse_vite_directive_assets(args, 0, 0, 5);
+        // @vite('resources/css/app.css') - args from tree-sitter, starting after the paren
+        let args = "'resources/css/app.css'";
+        let results = parse_vite_directive_assets(args, 0, 6);
         
         assert_eq!(results.len(), 1);
         assert_eq!(results[0].0, "resources/css/app.css");
@@ -4443,18 +6865,40 @@ mod vite_tests {
     #[test]
     fn test_vite_array_syntax() {
         // @vite(['resources/css/app.css', 'resources/js/app.js'])
-        let args = "(['resources/css/app.css', 'resources/js/app.js'])";
-        let results = parse_vite_directive_assets(args, 0, 0, 5);
+        let args = "['resources/css/app.css', 'resources/js/app.js']";
+        let results = parse_vite_directive_assets(args, 0, 6);
         
         assert_eq!(results.len(), 2);
         assert_eq!(results[0].0, "resources/css/app.css");
         assert_eq!(results[1].0, "resources/js/app.js");
+
+        // Each entry gets its own range
+        let line = format!("@vite({})", args);
+        for (path, row, start, end) in &results {
+            assert_eq!(*row, 0);
+            assert_eq!(&line[*start as usize..*end as usize], path);
+        }
+    }
+
+    #[test]
+    fn test_vite_multiline_array_syntax() {
+        // @vite([
+        //     'resources/css/app.css',
+        //     'resources/js/app.js',
+        // ])
+        let args = "[\n    'resources/css/app.css',\n    \"resources/js/app.js\",\n]";
+        let results = parse_vite_directive_assets(args, 3, 10);
+
+        assert_eq!(results, vec![
+            ("resources/css/app.css".to_string(), 4, 5, 26),
+            ("resources/js/app.js".to_string(), 5, 5, 24),
+        ]);
     }
 
     #[test]
     fn test_vite_double_quotes() {
-        let args = r#"("resources/css/app.css")"#;
-        let results = parse_vite_directive_assets(args, 0, 0, 5);
+        let args = r#""resources/css/app.css""#;
+        let results = parse_vite_directive_assets(args, 0, 6);
 
         assert_eq!(results.len(), 1);
         assert_eq!(results[0].0, "resources/css/app.css");
@@ -4487,21 +6931,637 @@ mod vite_tests {
 
     #[test]
     fn test_vite_column_positions() {
-        // For @vite('resources/css/app.css'):
+        // For     @vite('resources/css/app.css'):
         // Position: 0123456789...
-        //           @vite('resources/css/app.css')
-        // @ at 0, v at 1, ... e at 4, ( at 5, ' at 6, r at 7
+        //               @vite('resources/css/app.css')
+        // @ at 4, ( at 9, ' at 10, r at 11 - args start at 10
         // Path "resources/css/app.css" is 21 chars
-        // LSP needs +1 offset, so start col is 8
-        let args = "('resources/css/app.css')";
+        let args = "'resources/css/app.css'";
         let path = "resources/css/app.css";
-        let results = parse_vite_directive_assets(args, 0, 0, 5);
+        let results = parse_vite_directive_assets(args, 0, 10);
 
         assert_eq!(results.len(), 1);
         assert_eq!(results[0].0, path);
-        // Column should point to 'r' (first char of path), adjusted for LSP
-        assert_eq!(results[0].2, 8, "start column should be 8");
-        // End column should be 8 + 21 = 29
-        assert_eq!(results[0].3, 8 + path.len() as u32, "end column should be start + path.len()");
+        // Column should point to 'r' (first char of path)
+        assert_eq!(results[0].2, 11, "start column should be 11");
+        // End column should be 11 + 21 = 32
+        assert_eq!(results[0].3, 11 + path.len() as u32, "end column should be start + path.len()");
+    }
+}
+
+#[cfg(test)]
+mod service_provider_tests {
+    use super::*;
+
+    /// Parse provider source and return (alias, class_name, source_line) for each middleware entry
+    fn parse_middleware(path: &str, text: &str) -> Vec<(String, String, u32)> {
+        let db = LaravelDatabase::new();
+        let file = ServiceProviderFile::new(&db, PathBuf::from(path), 1, text.to_string(), 2);
+        let parsed = parse_service_provider_source(&db, file, PathBuf::from("/project"));
+        parsed
+            .middleware(&db)
+            .iter()
+            .map(|mw| {
+                (
+                    mw.alias(&db).name(&db).clone(),
+                    mw.class_name(&db).clone(),
+                    mw.source_line(&db),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_runtime_view_namespaces_and_locations() {
+        let db = LaravelDatabase::new();
+        let text = include_str!("../tests/fixtures/view-themes/ThemeServiceProvider.php");
+        let path = PathBuf::from("/project/app/Providers/ThemeServiceProvider.php");
+        let file = ServiceProviderFile::new(&db, path, 1, text.to_string(), 2);
+        let parsed = parse_service_provider_source(&db, file, PathBuf::from("/project"));
+
+        let namespaces: Vec<(String, Option<PathBuf>, u32)> = parsed
+            .view_namespaces(&db)
+            .iter()
+            .map(|vn| (vn.namespace(&db).namespace(&db).clone(), vn.view_path(&db).clone(), vn.source_line(&db)))
+            .collect();
+        assert_eq!(
+            namespaces,
+            vec![("theme".to_string(), Some(PathBuf::from("/project/resources/themes/default")), 12)]
+        );
+
+        assert_eq!(
+            parsed.view_locations(&db),
+            &vec![
+                (PathBuf::from("/project/resources/tenants/acme"), true),
+                (PathBuf::from("/project/legacy/views"), false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resolve_path_expression() {
+        let root = Path::new("/project");
+        let provider_dir = Path::new("/project/packages/theme/src");
+        assert_eq!(
+            resolve_path_expression("resource_path('views/themes')", provider_dir, root),
+            Some(PathBuf::from("/project/resources/views/themes"))
+        );
+        assert_eq!(resolve_path_expression("base_path()", provider_dir, root), Some(PathBuf::from("/project")));
+        assert_eq!(
+            resolve_path_expression("__DIR__.'/../views'", provider_dir, root),
+            Some(PathBuf::from("/project/packages/theme/views"))
+        );
+        assert_eq!(resolve_path_expression("$themePath", provider_dir, root), None);
+    }
+
+    #[test]
+    fn test_bootstrap_app_with_middleware_closure() {
+        let text = r#"<?php
+
+use Illuminate\Foundation\Application;
+use Illuminate\Foundation\Configuration\Middleware;
+
+return Application::configure(basePath: dirname(__DIR__))
+    ->withMiddleware(function (Middleware $middleware) {
+        $middleware->alias([
+            'admin' => \App\Http\Middleware\EnsureAdmin::class,
+        ]);
+        $middleware->appendToGroup('web', [
+            \App\Http\Middleware\TrackVisits::class,
+        ]);
+        $middleware->prependToGroup('api', \App\Http\Middleware\ForceJson::class);
+        $middleware->redirectGuestsTo('/login');
+    })
+    ->create();
+"#;
+        let entries = parse_middleware("/project/bootstrap/app.php", text);
+        assert_eq!(entries.len(), 3, "alias + two groups, nothing from redirectGuestsTo: {:?}", entries);
+
+        let find = |name: &str| entries.iter().find(|(alias, _, _)| alias == name).cloned();
+
+        let (_, class, line) = find("admin").expect("alias should be registered");
+        assert_eq!(class, "App\\Http\\Middleware\\EnsureAdmin");
+        assert_eq!(line, 9);
+
+        let (_, summary, line) = find("web").expect("appendToGroup group should be registered");
+        assert!(summary.contains("App\\Http\\Middleware\\TrackVisits"), "summary: {}", summary);
+        assert_eq!(line, 11);
+
+        let (_, summary, line) = find("api").expect("prependToGroup group should be registered");
+        assert!(summary.contains("App\\Http\\Middleware\\ForceJson"), "summary: {}", summary);
+        assert_eq!(line, 14);
+    }
+
+    #[test]
+    fn test_kernel_middleware_groups_block() {
+        let text = r#"<?php
+
+class Kernel extends HttpKernel
+{
+    protected $middlewareGroups = [
+        'web' => [
+            \App\Http\Middleware\EncryptCookies::class,
+            \Illuminate\Session\Middleware\StartSession::class,
+        ],
+
+        'api' => [
+            'throttle:api',
+            \Illuminate\Routing\Middleware\SubstituteBindings::class,
+        ],
+    ];
+
+    protected $middlewareAliases = [
+        'auth' => \App\Http\Middleware\Authenticate::class,
+    ];
+}
+"#;
+        let entries = parse_middleware("/project/app/Http/Kernel.php", text);
+        let names: Vec<&str> = entries.iter().map(|(alias, _, _)| alias.as_str()).collect();
+
+        assert!(names.contains(&"web"));
+        assert!(names.contains(&"api"));
+        assert!(names.contains(&"auth"));
+        assert_eq!(names.len(), 3, "group members must not become groups: {:?}", names);
+
+        let web = entries.iter().find(|(alias, _, _)| alias == "web").unwrap();
+        assert!(web.1.contains("Illuminate\\Session\\Middleware\\StartSession"));
+        assert_eq!(web.2, 6);
+    }
+
+    #[test]
+    fn test_load_translations_from_registers_namespace() {
+        let text = r#"<?php
+
+class CourierServiceProvider extends ServiceProvider
+{
+    public function boot(): void
+    {
+        $this->loadViewsFrom(__DIR__.'/../resources/views', 'courier');
+        $this->loadTranslationsFrom(__DIR__.'/../lang', 'courier');
+    }
+}
+"#;
+        let db = LaravelDatabase::new();
+        let path = "/project/vendor/acme/courier/src/CourierServiceProvider.php";
+        let file = ServiceProviderFile::new(&db, PathBuf::from(path), 1, text.to_string(), 1);
+        let parsed = parse_service_provider_source(&db, file, PathBuf::from("/project"));
+
+        let namespaces = parsed.translation_namespaces(&db);
+        assert_eq!(namespaces.len(), 1);
+        let reg = &namespaces[0];
+        assert_eq!(reg.namespace(&db).namespace(&db), "courier");
+        assert_eq!(
+            reg.lang_path(&db).as_deref(),
+            Some(Path::new("/project/vendor/acme/courier/lang"))
+        );
+        assert_eq!(reg.source_line(&db), 8);
+    }
+
+    #[test]
+    fn test_merge_config_from_registers_package_config() {
+        let text = include_str!("../tests/fixtures/package-config/CourierServiceProvider.php");
+        let db = LaravelDatabase::new();
+        let path = "/project/vendor/acme/courier/src/CourierServiceProvider.php";
+        let file = ServiceProviderFile::new(&db, PathBuf::from(path), 1, text.to_string(), 1);
+        let parsed = parse_service_provider_source(&db, file, PathBuf::from("/project"));
+
+        let merges = parsed.config_merges(&db);
+        assert_eq!(merges.len(), 1);
+        let reg = &merges[0];
+        assert_eq!(reg.key(&db).namespace(&db), "courier");
+        assert_eq!(
+            reg.config_path(&db).as_deref(),
+            Some(Path::new("/project/vendor/acme/courier/config/courier.php"))
+        );
+        assert_eq!(reg.source_line(&db), 11);
+    }
+
+    #[test]
+    fn test_livewire_component_aliases() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().to_path_buf();
+        let class_file = root.join("app/Http/Livewire/Custom.php");
+        std::fs::create_dir_all(class_file.parent().unwrap()).unwrap();
+        std::fs::write(&class_file, "<?php\n").unwrap();
+
+        let text = r#"<?php
+
+class AppServiceProvider extends ServiceProvider
+{
+    public function boot(): void
+    {
+        Livewire::component('custom-name', \App\Http\Livewire\Custom::class);
+        Livewire::component("stats", App\Livewire\Dashboard\Stats::class);
+    }
+}
+"#;
+        let db = LaravelDatabase::new();
+        let path = root.join("app/Providers/AppServiceProvider.php");
+        let file = ServiceProviderFile::new(&db, path, 1, text.to_string(), 2);
+        let parsed = parse_service_provider_source(&db, file, root.clone());
+
+        let components = parsed.livewire_components(&db);
+        assert_eq!(components.len(), 2);
+
+        let custom = &components[0];
+        assert_eq!(custom.alias(&db).name(&db), "custom-name");
+        assert_eq!(custom.class_name(&db), "App\\Http\\Livewire\\Custom");
+        assert_eq!(custom.file_path(&db).as_ref(), Some(&class_file));
+        assert_eq!(custom.source_line(&db), 7);
+
+        // Missing class files stay unresolved so callers can report the expected path
+        let stats = &components[1];
+        assert_eq!(stats.alias(&db).name(&db), "stats");
+        assert_eq!(stats.class_name(&db), "App\\Livewire\\Dashboard\\Stats");
+        assert_eq!(stats.file_path(&db), &None);
+    }
+
+    #[test]
+    fn test_custom_blade_directives() {
+        let text = r#"<?php
+
+class AppServiceProvider extends ServiceProvider
+{
+    public function boot(): void
+    {
+        Blade::directive('datetime', function (string $expression) {
+            return "<?php echo ($expression)->format('m/d/Y H:i'); ?>";
+        });
+        Blade::directive("money", fn ($amount) => "<?php echo money($amount); ?>");
+        Blade::if('admin', fn () => auth()->user()?->isAdmin());
+    }
+}
+"#;
+        let db = LaravelDatabase::new();
+        let path = PathBuf::from("/project/app/Providers/AppServiceProvider.php");
+        let file = ServiceProviderFile::new(&db, path, 1, text.to_string(), 2);
+        let parsed = parse_service_provider_source(&db, file, PathBuf::from("/project"));
+
+        let directives = parsed.blade_directives(&db);
+        let names: Vec<&String> = directives.iter().map(|d| d.name(&db)).collect();
+        assert_eq!(names, vec!["datetime", "money"]);
+        assert_eq!(directives[0].source_line(&db), 7);
+        assert_eq!(directives[0].priority(&db), 2);
+    }
+
+    #[test]
+    fn test_gates_and_policies() {
+        let text = r#"<?php
+
+namespace App\Providers;
+
+use App\Models\Comment;
+use App\Policies\Moderation\ModerationPolicy;
+
+class AuthServiceProvider extends ServiceProvider
+{
+    public function boot(): void
+    {
+        Gate::policy(Comment::class, ModerationPolicy::class);
+        Gate::policy(\App\Models\Post::class, \App\Policies\PostPolicy::class);
+        Gate::define('view-admin', fn ($user) => $user->is_admin);
+    }
+}
+"#;
+        let db = LaravelDatabase::new();
+        let path = PathBuf::from("/project/app/Providers/AuthServiceProvider.php");
+        let file = ServiceProviderFile::new(&db, path, 1, text.to_string(), 2);
+        let parsed = parse_authorization_source(&db, file);
+
+        assert_eq!(parsed.gates(&db), &vec![("view-admin".to_string(), 13, 22)]);
+        assert_eq!(parsed.policies(&db), &vec![
+            ("App\\Models\\Comment".to_string(), "App\\Policies\\Moderation\\ModerationPolicy".to_string()),
+            ("App\\Models\\Post".to_string(), "App\\Policies\\PostPolicy".to_string()),
+        ]);
+    }
+}
+
+#[cfg(test)]
+mod project_file_tests {
+    use super::*;
+
+    fn roots() -> ProjectFileRoots {
+        ProjectFileRoots {
+            controller_dirs: vec![PathBuf::from("/project/app/Http/Controllers")],
+            view_dirs: vec![PathBuf::from("/project/resources/views")],
+            livewire_dir: Some(PathBuf::from("/project/app/Livewire")),
+            routes_dir: PathBuf::from("/project/routes"),
+            app_dir: PathBuf::from("/project/app"),
+        }
+    }
+
+    #[test]
+    fn test_classify_project_files() {
+        let roots = roots();
+        let classify = |path: &str| roots.classify(Path::new(path), &FileTypeSettings::default());
+
+        assert_eq!(classify("/project/resources/views/users/index.blade.php"), Some(ProjectFileKind::View));
+        assert_eq!(classify("/project/app/Http/Controllers/UserController.php"), Some(ProjectFileKind::Controller));
+        assert_eq!(classify("/project/app/Livewire/Counter.php"), Some(ProjectFileKind::Livewire));
+        assert_eq!(classify("/project/routes/web.php"), Some(ProjectFileKind::Route));
+        assert_eq!(classify("/project/app/Mail/Welcome.php"), Some(ProjectFileKind::Source));
+
+        // Outside the scanned directories, or not a matching file type
+        assert_eq!(classify("/project/database/seeders/UserSeeder.php"), None);
+        assert_eq!(classify("/project/resources/views/app.css"), None);
+        assert_eq!(classify("/project/routes/README.md"), None);
+    }
+}
+
+#[cfg(test)]
+mod component_path_tests {
+    use super::*;
+
+    fn config() -> LaravelConfigData {
+        LaravelConfigData {
+            root: PathBuf::from("/project"),
+            view_paths: vec![PathBuf::from("resources/views")],
+            component_paths: Vec::new(),
+            livewire_path: None,
+            livewire_view_path: None,
+            has_livewire: false,
+            view_namespaces: HashMap::new(),
+            component_namespaces: HashMap::from([("ui".to_string(), "Acme\\Ui\\Components".to_string())]),
+            translation_namespaces: HashMap::new(),
+            package_configs: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_nested_kebab_component_views() {
+        let paths = config().resolve_component_path("admin.forms.text-input");
+        assert_eq!(paths, vec![
+            PathBuf::from("/project/resources/views/components/admin/forms/text-input.blade.php"),
+            PathBuf::from("/project/resources/views/components/admin/forms/text-input/index.blade.php"),
+        ]);
+    }
+
+    #[test]
+    fn test_namespaced_component_classes_match_view_naming() {
+        let paths = config().resolve_component_path("ui::admin.forms.text-input");
+        assert!(paths.contains(&PathBuf::from("/project/vendor/Acme/Ui/Components/Admin/Forms/TextInput.php")));
+        assert!(paths.contains(&PathBuf::from("/project/app/View/Components/Admin/Forms/TextInput.php")));
+        assert!(paths.contains(&PathBuf::from(
+            "/project/resources/views/vendor/ui/components/admin/forms/text-input/index.blade.php"
+        )));
+    }
+}
+
+#[cfg(test)]
+mod asset_helper_tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_helper_subdirectories() {
+        let root = Path::new("/project");
+
+        assert_eq!(
+            AssetHelperType::StoragePath.resolve(root, "app/public/x.txt"),
+            PathBuf::from("/project/storage/app/public/x.txt")
+        );
+        // Leading separators are trimmed like Laravel's joinPaths(), not treated as absolute
+        assert_eq!(
+            AssetHelperType::ConfigPath.resolve(root, "/app.php"),
+            PathBuf::from("/project/config/app.php")
+        );
+        // Empty argument is the helper's base directory itself
+        assert_eq!(AssetHelperType::BasePath.resolve(root, ""), PathBuf::from("/project"));
+    }
+
+    #[test]
+    fn test_asset_target_distinguishes_directories() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        std::fs::create_dir_all(root.join("storage/app/public")).unwrap();
+        std::fs::write(root.join("storage/app/public/x.txt"), "x").unwrap();
+
+        let directory = AssetHelperType::StoragePath.resolve(root, "app");
+        assert_eq!(AssetTarget::of(&directory), AssetTarget::Directory);
+
+        let file = AssetHelperType::StoragePath.resolve(root, "app/public/x.txt");
+        assert_eq!(AssetTarget::of(&file), AssetTarget::File);
+
+        let missing = AssetHelperType::StoragePath.resolve(root, "app/missing.txt");
+        assert_eq!(AssetTarget::of(&missing), AssetTarget::Missing);
+
+        // Bare base_path() is the project root, which always exists
+        assert_eq!(AssetTarget::of(&AssetHelperType::BasePath.resolve(root, "")), AssetTarget::Directory);
+    }
+}
+
+#[cfg(test)]
+mod translation_file_tests {
+    use super::*;
+
+    #[test]
+    fn test_json_translation_keys_follow_file_changes() {
+        let mut db = LaravelDatabase::new();
+        let path = PathBuf::from("/project/lang/en.json");
+        let file = JsonTranslationFile::new(&db, path, 1, r#"{"Log in": "Log in"}"#.to_string());
+
+        let keys = parse_json_translation_keys(&db, file);
+        assert!(keys.contains("Log in"));
+        assert!(!keys.contains("Sign up"));
+
+        file.set_text(&mut db).to(r#"{"Log in": "Log in", "Sign up": "Sign up"}"#.to_string());
+        file.set_version(&mut db).to(2);
+        let keys = parse_json_translation_keys(&db, file);
+        assert!(keys.contains("Sign up"));
+
+        // A half-typed file has no keys rather than stale ones
+        file.set_text(&mut db).to(r#"{"Log in": "#.to_string());
+        file.set_version(&mut db).to(3);
+        assert!(parse_json_translation_keys(&db, file).is_empty());
+    }
+
+    #[test]
+    fn test_choice_directive_and_lang_facade_in_blade() {
+        let text = include_str!("../tests/fixtures/translation-calls/choice.blade.php");
+        let db = LaravelDatabase::new();
+        let path = PathBuf::from("/project/resources/views/fruit.blade.php");
+        let file = SourceFile::new(&db, path, 1, text.to_string(), true);
+        let patterns = parse_file_patterns(&db, file);
+
+        let refs: Vec<(String, u32, bool)> = patterns
+            .translation_refs(&db)
+            .iter()
+            .map(|t| (t.key(&db).key(&db).clone(), t.line(&db), t.is_existence_check(&db)))
+            .collect();
+        assert_eq!(
+            refs,
+            vec![
+                ("messages.apples".to_string(), 0, false),
+                ("messages.minutes".to_string(), 1, false),
+                ("messages.welcome".to_string(), 2, false),
+                ("messages.apples".to_string(), 3, false),
+                ("messages.banner".to_string(), 4, true),
+            ]
+        );
+
+        // Ranges cover the key inside the quotes
+        for t in patterns.translation_refs(&db) {
+            let line = text.lines().nth(t.line(&db) as usize).unwrap();
+            assert_eq!(&line[t.column(&db) as usize..t.end_column(&db) as usize], t.key(&db).key(&db));
+        }
+    }
+}
+
+#[cfg(test)]
+mod layout_section_tests {
+    use super::*;
+
+    #[test]
+    fn test_layout_section_names_skip_comments_and_verbatim() {
+        let text = "<title>@yield('title', 'Laravel')</title>\n{{-- @yield('sidebar') --}}\n@verbatim\n    @yield('footer')\n@endverbatim\n@hasSection('nav')\n@endif\n@section('body')\n@show\n";
+        let db = LaravelDatabase::new();
+        let path = PathBuf::from("/project/resources/views/layouts/app.blade.php");
+        let file = SourceFile::new(&db, path, 1, text.to_string(), true);
+        assert_eq!(
+            parse_layout_section_names(&db, file),
+            Some(vec!["title".to_string(), "nav".to_string(), "body".to_string()])
+        );
+
+        // Any section could fill a dynamic yield
+        let dynamic = SourceFile::new(&db, PathBuf::from("/project/resources/views/layouts/dynamic.blade.php"), 1, "@yield($region)\n".to_string(), true);
+        assert_eq!(parse_layout_section_names(&db, dynamic), None);
+    }
+}
+
+#[cfg(test)]
+mod request_priority_tests {
+    use super::*;
+
+    fn provider_batch(root: &Path, count: usize) -> Vec<ServiceProviderSource> {
+        (0..count)
+            .map(|i| ServiceProviderSource {
+                path: root.join(format!("vendor/acme/pkg{}/src/PkgServiceProvider.php", i)),
+                text: "<?php // batch".to_string(),
+                priority: 1,
+            })
+            .collect()
+    }
+
+    fn test_actor() -> (SalsaActor, mpsc::Sender<SalsaRequest>, mpsc::Sender<SalsaRequest>, tokio::runtime::Runtime) {
+        let (tx, rx) = mpsc::channel(INTERACTIVE_QUEUE_CAPACITY);
+        let (bulk_tx, bulk_rx) = mpsc::channel(BULK_QUEUE_CAPACITY);
+        let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+        (SalsaActor::new(rx, bulk_rx), tx, bulk_tx, runtime)
+    }
+
+    #[test]
+    fn test_only_batches_travel_on_the_bulk_queue() {
+        let (reply, _) = oneshot::channel();
+        let batch = SalsaRequest::RegisterServiceProviderBatch { sources: Vec::new(), root_path: PathBuf::from("/project"), reply };
+        assert_eq!(batch.priority(), RequestPriority::Bulk);
+
+        let (reply, _) = oneshot::channel();
+        let patterns = SalsaRequest::GetPatterns { path: PathBuf::from("/project/a.php"), reply };
+        assert_eq!(patterns.priority(), RequestPriority::Interactive);
+    }
+
+    #[test]
+    fn test_interactive_requests_are_served_between_batch_chunks() {
+        const CHUNKS: usize = 3;
+        let (mut actor, tx, bulk_tx, runtime) = test_actor();
+        let root = PathBuf::from("/project");
+        let sources = provider_batch(&root, CHUNKS * BATCH_CHUNK_SIZE);
+        let newer = sources.last().unwrap().path.clone();
+
+        let (batch_tx, mut batch_rx) = oneshot::channel();
+        assert!(bulk_tx
+            .try_send(SalsaRequest::RegisterServiceProviderBatch { sources, root_path: root.clone(), reply: batch_tx })
+            .is_ok());
+
+        // The batch is picked up and its first chunk registered
+        assert!(actor.step(&runtime));
+        assert_eq!(actor.salsa_sp_files.len(), BATCH_CHUNK_SIZE);
+
+        // An edit and a newer copy of a provider still waiting in the batch
+        let view = root.join("resources/views/welcome.blade.php");
+        let (update_tx, mut update_rx) = oneshot::channel();
+        assert!(tx
+            .try_send(SalsaRequest::UpdateFile { path: view, version: 1, text: "@include('nav')".to_string(), reply: update_tx })
+            .is_ok());
+        let (register_tx, mut register_rx) = oneshot::channel();
+        assert!(tx
+            .try_send(SalsaRequest::RegisterServiceProviderSource {
+                path: newer.clone(),
+                text: "<?php // newer".to_string(),
+                priority: 1,
+                root_path: root.clone(),
+                reply: register_tx,
+            })
+            .is_ok());
+
+        // ...are each answered before the next chunk
+        assert!(actor.step(&runtime));
+        assert!(update_rx.try_recv().is_ok());
+        assert!(actor.step(&runtime));
+        assert!(register_rx.try_recv().is_ok());
+        assert_eq!(actor.salsa_sp_files.len(), BATCH_CHUNK_SIZE + 1);
+        assert!(matches!(batch_rx.try_recv(), Err(oneshot::error::TryRecvError::Empty)));
+
+        // The rest of the batch skips the provider registered since it was queued
+        for _ in 1..CHUNKS {
+            assert!(actor.step(&runtime));
+        }
+        assert_eq!(batch_rx.try_recv().unwrap(), CHUNKS * BATCH_CHUNK_SIZE - 1);
+        assert_eq!(actor.salsa_sp_files.len(), CHUNKS * BATCH_CHUNK_SIZE);
+        assert_eq!(actor.salsa_sp_files[&newer].text(&actor.db), "<?php // newer");
+    }
+
+    #[test]
+    fn test_reset_stops_a_pending_batch() {
+        let (mut actor, tx, bulk_tx, runtime) = test_actor();
+        let root = PathBuf::from("/project");
+
+        let (batch_tx, mut batch_rx) = oneshot::channel();
+        assert!(bulk_tx
+            .try_send(SalsaRequest::RegisterServiceProviderBatch {
+                sources: provider_batch(&root, 2 * BATCH_CHUNK_SIZE),
+                root_path: root.clone(),
+                reply: batch_tx,
+            })
+            .is_ok());
+        assert!(actor.step(&runtime));
+
+        let (reset_tx, _reset_rx) = oneshot::channel();
+        assert!(tx.try_send(SalsaRequest::ResetProjectIndex { reply: reset_tx }).is_ok());
+        assert!(actor.step(&runtime));
+
+        // The reply reports what was registered before the reset, and nothing comes back after it
+        assert_eq!(batch_rx.try_recv().unwrap(), BATCH_CHUNK_SIZE);
+        assert!(actor.pending_batch.is_none());
+        assert!(actor.salsa_sp_files.is_empty());
+    }
+}
+
+#[cfg(test)]
+mod config_key_tests {
+    use super::*;
+
+    #[test]
+    fn test_config_file_entries_follow_file_changes() {
+        let mut db = LaravelDatabase::new();
+        let path = PathBuf::from("/project/config/app.php");
+        let file = ConfigFile::new(&db, path, 1, "<?php
+return [
+    'name' => env('APP_NAME', 'Laravel'),
+];
+".to_string());
+
+        let keys = |entries: Vec<ConfigEntry>| entries.into_iter().map(|entry| entry.path.join(".")).collect::<Vec<_>>();
+        assert_eq!(keys(parse_config_file_entries(&db, file)), vec!["name"]);
+
+        file.set_text(&mut db).to("<?php
+return [
+    'name' => 'App',
+    'locales' => [
+        'en' => 'English',
+    ],
+];
+".to_string());
+        file.set_version(&mut db).to(2);
+        assert_eq!(keys(parse_config_file_entries(&db, file)), vec!["name", "locales", "locales.en"]);
     }
 }
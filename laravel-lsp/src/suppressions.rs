@@ -0,0 +1,84 @@
+//! In-file diagnostic suppression comments
+//!
+//! `laravel-lsp:disable` near the top of a file turns off its diagnostics;
+//! `laravel-lsp:disable-next-line` turns them off for the line after the
+//! comment. Either works in a Blade comment (`{{-- ... --}}`) or a PHP one
+//! (`// ...`, `# ...`, `/* ... */`). Go-to-definition is unaffected.
+
+/// How many lines from the top a file-level `laravel-lsp:disable` may appear on
+pub const FILE_MARKER_MAX_LINE: usize = 10;
+
+/// Suppression markers found in a file
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Suppressions {
+    /// `laravel-lsp:disable` appears near the top
+    pub file_disabled: bool,
+    /// Lines (0-indexed) following a `laravel-lsp:disable-next-line` comment
+    pub disabled_lines: Vec<u32>,
+}
+
+impl Suppressions {
+    /// Whether diagnostics starting on `line` are suppressed
+    pub fn is_line_suppressed(&self, line: u32) -> bool {
+        self.file_disabled || self.disabled_lines.contains(&line)
+    }
+}
+
+/// Find the suppression markers in a Blade or PHP file
+pub fn scan_suppressions(source: &str) -> Suppressions {
+    use lazy_static::lazy_static;
+    use regex::Regex;
+
+    lazy_static! {
+        static ref MARKER: Regex = Regex::new(
+            r"(?://|#|/\*|\{\{--)\s*laravel-lsp:(disable-next-line|disable)(?:\s|\*/|--\}\}|$)"
+        ).unwrap();
+    }
+
+    let mut suppressions = Suppressions::default();
+    // Most files have no markers - skip the per-line scan
+    if !source.contains("laravel-lsp:disable") {
+        return suppressions;
+    }
+
+    for (index, line) in source.lines().enumerate() {
+        for caps in MARKER.captures_iter(line) {
+            match &caps[1] {
+                "disable" if index < FILE_MARKER_MAX_LINE => suppressions.file_disabled = true,
+                "disable-next-line" => suppressions.disabled_lines.push(index as u32 + 1),
+                _ => {}
+            }
+        }
+    }
+
+    suppressions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_level_marker_near_the_top() {
+        let blade = include_str!("../tests/fixtures/suppressions/disabled.blade.php");
+        assert!(scan_suppressions(blade).file_disabled);
+        assert!(scan_suppressions("<?php\n// laravel-lsp:disable\n").file_disabled);
+
+        // Too far down to count as a file-level opt-out
+        let late = format!("{}{{{{-- laravel-lsp:disable --}}}}\n", "<div></div>\n".repeat(FILE_MARKER_MAX_LINE));
+        assert!(!scan_suppressions(&late).file_disabled);
+
+        // Mentioned outside a comment
+        assert!(!scan_suppressions("<p>Use laravel-lsp:disable to opt out</p>\n").file_disabled);
+    }
+
+    #[test]
+    fn test_next_line_markers() {
+        let blade = include_str!("../tests/fixtures/suppressions/next-line.blade.php");
+        let suppressions = scan_suppressions(blade);
+        assert!(!suppressions.file_disabled, "disable-next-line isn't a file-level opt-out");
+        assert_eq!(suppressions.disabled_lines, vec![2, 7]);
+        assert!(suppressions.is_line_suppressed(2));
+        assert!(!suppressions.is_line_suppressed(4));
+    }
+}
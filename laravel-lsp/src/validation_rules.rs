@@ -41,6 +41,48 @@ pub enum ParamType {
     Custom,
 }
 
+/// Rules whose every parameter names another field (e.g., "required_with:email,phone")
+const ALL_PARAMS_FIELD_RULES: &[&str] = &[
+    "required_with", "required_with_all", "required_without", "required_without_all",
+    "prohibits", "exclude_with", "exclude_without", "missing_with", "missing_with_all",
+    "present_with", "present_with_all", "different",
+];
+
+/// Rules whose first parameter names another field, followed by values
+/// (e.g., "required_if:role,admin")
+const FIRST_PARAM_FIELD_RULES: &[&str] = &[
+    "same", "required_if", "required_unless", "required_if_accepted", "required_if_declined",
+    "prohibited_if", "prohibited_unless", "exclude_if", "exclude_unless",
+    "missing_if", "missing_unless", "present_if", "present_unless",
+    "accepted_if", "declined_if", "in_array",
+];
+
+/// Fields named by a rule's parameters, with their byte offset within `params`
+///
+/// Only parameters that must be a field are returned. Comparison rules like
+/// `after:start_date` also accept dates (`after:tomorrow`), so they're left out.
+pub fn referenced_fields<'a>(rule_name: &str, params: &'a str) -> Vec<(usize, &'a str)> {
+    let take = if ALL_PARAMS_FIELD_RULES.contains(&rule_name) {
+        usize::MAX
+    } else if FIRST_PARAM_FIELD_RULES.contains(&rule_name) {
+        1
+    } else {
+        return Vec::new();
+    };
+
+    let mut fields = Vec::new();
+    let mut offset = 0;
+    for param in params.split(',').take(take) {
+        let trimmed = param.trim_start();
+        let field = trimmed.trim_end();
+        if !field.is_empty() {
+            fields.push((offset + param.len() - trimmed.len(), field));
+        }
+        offset += param.len() + 1;
+    }
+    fields
+}
+
 /// Parser for Laravel framework validation rules
 pub struct LaravelRulesParser {
     /// Path to the project root (containing vendor/)
@@ -390,6 +432,15 @@ mod tests {
         assert_eq!(LaravelRulesParser::camel_to_snake("Gt"), "gt");
     }
 
+    #[test]
+    fn test_referenced_fields() {
+        assert_eq!(referenced_fields("required_with", "email, phone"), vec![(0, "email"), (7, "phone")]);
+        assert_eq!(referenced_fields("required_if", "role,admin"), vec![(0, "role")]);
+        assert_eq!(referenced_fields("in_array", "tags.*"), vec![(0, "tags.*")]);
+        assert!(referenced_fields("after", "start_date").is_empty());
+        assert!(referenced_fields("max", "255").is_empty());
+    }
+
     #[test]
     fn test_default_dimension_options() {
         let options = LaravelRulesParser::default_dimension_options();
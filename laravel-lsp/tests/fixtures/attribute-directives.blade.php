@@ -0,0 +1,13 @@
+<form method="POST" action="/settings">
+    <input type="checkbox" name="active" @checked(old('active', $user->active))>
+
+    <select name="role">
+        @foreach ($roles as $role)
+            <option value="{{ $role }}" @selected($role === $user->role)>{{ $role }}</option>
+        @endforeach
+    </select>
+
+    <input type="email" name="email" @required($mustConfirm) @readonly($user->isLocked())>
+
+    <button type="submit" @disabled($errors->isNotEmpty())>Save</button>
+</form>
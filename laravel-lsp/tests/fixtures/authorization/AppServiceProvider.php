@@ -0,0 +1,16 @@
+<?php
+
+namespace App\Providers;
+
+use App\Models\Comment;
+use App\Policies\Moderation\ModerationPolicy;
+use Illuminate\Support\Facades\Gate;
+use Illuminate\Support\ServiceProvider;
+
+class AppServiceProvider extends ServiceProvider
+{
+    public function boot(): void
+    {
+        Gate::policy(Comment::class, ModerationPolicy::class);
+    }
+}
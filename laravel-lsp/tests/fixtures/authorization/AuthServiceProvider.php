@@ -0,0 +1,19 @@
+<?php
+
+namespace App\Providers;
+
+use App\Models\User;
+use Illuminate\Foundation\Support\Providers\AuthServiceProvider as ServiceProvider;
+use Illuminate\Support\Facades\Gate;
+
+class AuthServiceProvider extends ServiceProvider
+{
+    public function boot(): void
+    {
+        Gate::define('view-admin', function (User $user) {
+            return $user->is_admin;
+        });
+
+        Gate::define('manage-billing', fn (User $user) => $user->isOwner());
+    }
+}
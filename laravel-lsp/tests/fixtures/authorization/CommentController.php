@@ -0,0 +1,32 @@
+<?php
+
+namespace App\Http\Controllers;
+
+use App\Models\Comment;
+use Illuminate\Http\Request;
+use Illuminate\Support\Facades\Gate;
+
+class CommentController extends Controller
+{
+    public function approve(Request $request, Comment $comment)
+    {
+        $this->authorize('approve', Comment::class);
+
+        abort_unless($request->user()->can('approve', $comment), 403);
+
+        $comment->approve();
+
+        return back();
+    }
+
+    public function reject(Comment $comment)
+    {
+        if (Gate::denies('reject', Comment::class)) {
+            abort(403);
+        }
+
+        $comment->delete();
+
+        return back();
+    }
+}
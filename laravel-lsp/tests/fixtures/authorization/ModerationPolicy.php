@@ -0,0 +1,14 @@
+<?php
+
+namespace App\Policies\Moderation;
+
+use App\Models\Comment;
+use App\Models\User;
+
+class ModerationPolicy
+{
+    public function approve(User $user, Comment $comment): bool
+    {
+        return $user->is_moderator;
+    }
+}
@@ -0,0 +1,64 @@
+<?php
+
+namespace App\Http\Controllers;
+
+use App\Models\Post;
+use Illuminate\Http\Request;
+use Illuminate\Support\Facades\Gate;
+
+class PostController extends Controller
+{
+    public function index()
+    {
+        if (Gate::allows('view-admin')) {
+            return redirect()->route('admin.posts');
+        }
+
+        return view('posts.index');
+    }
+
+    public function edit(Request $request, Post $post)
+    {
+        abort_unless($request->user()->can('update', $post), 403);
+
+        return view('posts.edit', compact('post'));
+    }
+
+    public function update(Request $request, Post $post)
+    {
+        $this->authorize('update', $post);
+
+        $post->update($request->validated());
+
+        return redirect()->route('posts.show', $post);
+    }
+
+    public function destroy(Request $request, Post $post)
+    {
+        if ($request->user()->cannot('delete', $post)) {
+            abort(403);
+        }
+
+        $post->delete();
+
+        return redirect()->route('posts.index');
+    }
+
+    public function publish(Post $post)
+    {
+        if (Gate::denies('publish', Post::class)) {
+            abort(403);
+        }
+
+        $post->publish();
+
+        return back();
+    }
+
+    public function reports()
+    {
+        Gate::authorize('view-reports');
+
+        return view('posts.reports');
+    }
+}
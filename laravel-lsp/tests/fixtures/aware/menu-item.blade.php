@@ -0,0 +1,6 @@
+@aware(['color' => 'gray', 'size'])
+@props(['active' => false])
+
+<li {{ $attributes->class(["text-{$color}-800", 'font-bold' => $active]) }}>
+    {{ $slot }}
+</li>
@@ -0,0 +1,5 @@
+@props(['color' => 'gray', 'size' => 'md'])
+
+<ul {{ $attributes->merge(['class' => "menu menu-{$color} menu-{$size}"]) }}>
+    {{ $slot }}
+</ul>
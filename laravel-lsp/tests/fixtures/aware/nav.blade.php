@@ -0,0 +1,4 @@
+<x-menu color="purple">
+    <x-menu-item active>Home</x-menu-item>
+    <x-menu-item>About</x-menu-item>
+</x-menu>
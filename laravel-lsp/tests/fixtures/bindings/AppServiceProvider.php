@@ -0,0 +1,15 @@
+<?php
+
+namespace App\Providers;
+
+use App\Contracts\PaymentGateway;
+use App\Services\StripeGateway;
+use Illuminate\Support\ServiceProvider;
+
+class AppServiceProvider extends ServiceProvider
+{
+    public function register(): void
+    {
+        $this->app->bind(PaymentGateway::class, StripeGateway::class);
+    }
+}
@@ -0,0 +1,15 @@
+<?php
+
+namespace App\Http\Controllers;
+
+use App\Contracts\PaymentGateway;
+
+class CheckoutController extends Controller
+{
+    public function store()
+    {
+        $gateway = app(PaymentGateway::class);
+
+        return $gateway->charge(100);
+    }
+}
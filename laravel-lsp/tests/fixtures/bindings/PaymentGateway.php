@@ -0,0 +1,8 @@
+<?php
+
+namespace App\Contracts;
+
+interface PaymentGateway
+{
+    public function charge(int $amount): bool;
+}
@@ -0,0 +1,13 @@
+<?php
+
+namespace App\Services;
+
+use App\Contracts\PaymentGateway;
+
+class StripeGateway implements PaymentGateway
+{
+    public function charge(int $amount): bool
+    {
+        return true;
+    }
+}
@@ -0,0 +1,5 @@
+@extends('layouts.b')
+
+@section('content')
+    <main>@yield('page')</main>
+@endsection
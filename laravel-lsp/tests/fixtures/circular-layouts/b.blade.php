@@ -0,0 +1,5 @@
+@extends('layouts.a')
+
+@section('page')
+    <p>B</p>
+@endsection
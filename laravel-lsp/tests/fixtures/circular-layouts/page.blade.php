@@ -0,0 +1,5 @@
+@extends('layouts.a')
+
+@section('page')
+    <h1>Dashboard</h1>
+@endsection
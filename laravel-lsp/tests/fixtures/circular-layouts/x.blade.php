@@ -0,0 +1,5 @@
+@extends('layouts.y')
+
+@section('body')
+    @yield('content')
+@endsection
@@ -0,0 +1,5 @@
+@extends('layouts.z')
+
+@section('main')
+    @yield('body')
+@endsection
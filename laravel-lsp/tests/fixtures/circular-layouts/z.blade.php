@@ -0,0 +1,5 @@
+@extends('layouts.x')
+
+@section('content')
+    @yield('main')
+@endsection
@@ -0,0 +1,34 @@
+<?php
+
+namespace App\View\Components;
+
+use Illuminate\Contracts\View\View;
+use Illuminate\View\Component;
+
+class Alert extends Component
+{
+    public function __construct(public string $type = 'info')
+    {
+        //
+    }
+
+    public function classes(): string
+    {
+        return match ($this->type) {
+            'error' => 'bg-red-100 text-red-800',
+            default => 'bg-blue-100 text-blue-800',
+        };
+    }
+
+    /**
+     * Alerts share one template; the variant picks the icon and colours.
+     */
+    public function render(): View
+    {
+        $variant = $this->type === 'error' ? 'danger' : 'info';
+
+        return view('components.alerts.base', [
+            'variant' => $variant,
+        ]);
+    }
+}
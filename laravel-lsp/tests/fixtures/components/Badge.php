@@ -0,0 +1,22 @@
+<?php
+
+namespace App\View\Components;
+
+use Closure;
+use Illuminate\Contracts\View\View;
+use Illuminate\View\Component;
+
+class Badge extends Component
+{
+    public function __construct(public string $color = 'gray')
+    {
+        //
+    }
+
+    public function render(): View|Closure|string
+    {
+        return <<<'blade'
+            <span {{ $attributes->merge(['class' => "badge badge-{$color}"]) }}>{{ $slot }}</span>
+        blade;
+    }
+}
@@ -0,0 +1,20 @@
+<?php
+
+namespace App\Http\Middleware;
+
+use Closure;
+use Illuminate\Support\Facades\Config;
+
+class ConfigureTenant
+{
+    public function handle($request, Closure $next)
+    {
+        $name = config('app.name');
+        $mailer = config('mailers.default');
+
+        config(['app.runtime' => true, 'tenant.id' => $request->route('tenant')]);
+        Config::set('tenant.locale', 'en');
+
+        return $next($request);
+    }
+}
@@ -0,0 +1,25 @@
+<?php
+
+namespace App\Console;
+
+use App\Console\Commands\PruneReports;
+use Illuminate\Console\Scheduling\Schedule;
+use Illuminate\Foundation\Console\Kernel as ConsoleKernel;
+use Illuminate\Support\Facades\Artisan;
+
+class Kernel extends ConsoleKernel
+{
+    protected function schedule(Schedule $schedule): void
+    {
+        $schedule->command('mail:send 1 --queue=default')->daily();
+        $schedule->command(PruneReports::class)->weekly();
+        $schedule->command('inspire')->hourly();
+        $schedule->command('migrate --force')->monthly();
+        $schedule->command('backup:rn')->dailyAt('01:00');
+    }
+
+    public function pruneNow(): void
+    {
+        Artisan::call("reports:prune");
+    }
+}
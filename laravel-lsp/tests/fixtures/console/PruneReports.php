@@ -0,0 +1,15 @@
+<?php
+
+namespace App\Console\Commands;
+
+use Illuminate\Console\Command;
+use Symfony\Component\Console\Attribute\AsCommand;
+
+#[AsCommand(name: 'reports:prune')]
+class PruneReports extends Command
+{
+    public function handle(): void
+    {
+        //
+    }
+}
@@ -0,0 +1,24 @@
+<?php
+
+namespace App\Console\Commands;
+
+use Illuminate\Console\Command;
+
+class SendEmails extends Command
+{
+    /**
+     * The name and signature of the console command.
+     *
+     * @var string
+     */
+    protected $signature = 'mail:send
+                            {user : The ID of the user}
+                            {--queue= : Whether the job should be queued}';
+
+    protected $description = 'Send a marketing email to a user';
+
+    public function handle(): void
+    {
+        //
+    }
+}
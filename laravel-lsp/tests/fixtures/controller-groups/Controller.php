@@ -0,0 +1,11 @@
+<?php
+
+namespace App\Http\Controllers;
+
+abstract class Controller
+{
+    public function export()
+    {
+        return response()->streamDownload(fn () => null, 'export.csv');
+    }
+}
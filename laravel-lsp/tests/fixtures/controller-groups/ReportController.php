@@ -0,0 +1,13 @@
+<?php
+
+namespace App\Http\Controllers\Admin;
+
+use App\Http\Controllers\Controller;
+
+class ReportController extends Controller
+{
+    public function index()
+    {
+        return view('admin.reports');
+    }
+}
@@ -0,0 +1,16 @@
+<?php
+
+namespace App\Http\Controllers;
+
+class UserController extends Controller
+{
+    public function index()
+    {
+        return view('users.index');
+    }
+
+    public function show(string $user)
+    {
+        return view('users.show', compact('user'));
+    }
+}
@@ -0,0 +1,18 @@
+<?php
+
+use App\Http\Controllers\Admin\ReportController;
+use App\Http\Controllers\UserController;
+use Illuminate\Support\Facades\Route;
+
+Route::controller(UserController::class)->prefix('users')->group(function () {
+    Route::get('/', 'index')->name('users.index');
+    Route::get('/{user}', 'show')->name('users.show');
+    Route::get('/export', 'export')->name('users.export');
+    Route::post('/{user}/archive', 'archive')->name('users.archive');
+
+    Route::controller(ReportController::class)->group(function () {
+        Route::get('/reports', 'index')->name('users.reports');
+    });
+});
+
+Route::get('/about', 'about');
@@ -0,0 +1,7 @@
+<article>
+    <p>Published @datetime($post->created_at)</p>
+
+    @feature('beta-comments')
+        @include('missing.comments')
+    @endif
+</article>
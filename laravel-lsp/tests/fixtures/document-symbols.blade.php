@@ -0,0 +1,25 @@
+@extends('layouts.app')
+
+@section('title', 'Dashboard')
+
+@section('content')
+    <x-alert type="info" />
+
+    @component('components.panel')
+        @slot('heading')
+            Recent orders
+        @endslot
+    @endcomponent
+
+    @section('sidebar')
+        <livewire:order-feed />
+    @endsection
+@endsection
+
+@push('scripts')
+    <script src="/js/dashboard.js"></script>
+@endpush
+
+@pushOnce('styles')
+    <link rel="stylesheet" href="/css/dashboard.css">
+@endPushOnce
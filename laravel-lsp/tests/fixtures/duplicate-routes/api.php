@@ -0,0 +1,10 @@
+<?php
+
+use App\Http\Controllers\Api;
+use Illuminate\Support\Facades\Route;
+
+Route::group(['as' => 'api.', 'middleware' => ['auth:sanctum']], function () {
+    Route::get('/posts', [Api\PostController::class, 'index'])->name('posts.index');
+});
+
+Route::get('/users', [Api\UserController::class, 'index'])->name('users.index');
@@ -0,0 +1,14 @@
+<?php
+
+use App\Http\Controllers\Admin;
+use App\Http\Controllers\PostController;
+use App\Http\Controllers\UserController;
+use Illuminate\Support\Facades\Route;
+
+Route::get('/users', [UserController::class, 'index'])->name('users.index');
+Route::get('/posts', [PostController::class, 'index'])->name('posts.index');
+
+// Same suffix under a group prefix: admin.users.index is a different name
+Route::prefix('admin')->name('admin.')->middleware(['auth'])->group(function () {
+    Route::get('/users/{user}', [Admin\UserController::class, 'index'])->name('users.index');
+});
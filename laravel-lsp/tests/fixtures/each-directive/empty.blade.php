@@ -0,0 +1 @@
+<li>Nothing here yet</li>
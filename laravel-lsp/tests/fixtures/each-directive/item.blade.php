@@ -0,0 +1 @@
+<li>{{ $item->name }}</li>
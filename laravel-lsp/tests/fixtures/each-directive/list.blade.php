@@ -0,0 +1,3 @@
+<ul>
+    @each('partials.item', $items, 'item', 'partials.empty')
+</ul>
@@ -0,0 +1,7 @@
+<nav>
+    <a href="{{ route('dashboard') }}">{{ config('app.name') }}</a>
+    <img src="{{ asset('images/logo.png') }}" alt="">
+    {!! route('profile.edit') !!}
+    <span>{!! config('app.timezone') !!}</span>
+    <script src="{!! asset('js/missing.js') !!}"></script>
+</nav>
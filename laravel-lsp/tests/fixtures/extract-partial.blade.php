@@ -0,0 +1,10 @@
+@extends('layouts.app')
+
+@section('content')
+    <nav class="main-menu">
+        <a href="/">Home</a>
+        <a href="/about">About</a>
+    </nav>
+
+    <p>Welcome back, <strong>{{ $user->name }}</strong>!</p>
+@endsection
@@ -0,0 +1,15 @@
+<ul>
+    @forelse($users as $user)
+        <li>
+            {{ $loop->iteration }}. {{ $user->name }}
+            @empty($user->roles)
+                <span>No roles</span>
+            @endempty
+            @foreach($user->roles as $role)
+                {{ $role->name }}
+            @endforeach
+        </li>
+    @empty
+        <li>No users</li>
+    @endforelse
+</ul>
@@ -0,0 +1,11 @@
+@component('alert', ['type' => 'danger'])
+    @slot('title')
+        Forbidden
+    @endslot
+
+    You are not allowed to access this resource!
+@endcomponent
+
+@component('missing-panel')
+    Nothing to see here
+@endcomponent
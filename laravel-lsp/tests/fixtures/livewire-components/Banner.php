@@ -0,0 +1,15 @@
+<?php
+
+namespace App\Http\Components\Livewire;
+
+use Livewire\Component;
+
+class Banner extends Component
+{
+    public function render()
+    {
+        return <<<'BLADE'
+            <div>Maintenance tonight</div>
+        BLADE;
+    }
+}
@@ -0,0 +1,16 @@
+<?php
+
+namespace App\Http\Components\Livewire;
+
+use Livewire\Component;
+
+// No render(): Livewire renders the conventional view under view_path
+class Counter extends Component
+{
+    public int $count = 0;
+
+    public function increment(): void
+    {
+        $this->count++;
+    }
+}
@@ -0,0 +1,13 @@
+<?php
+
+namespace App\Http\Components\Livewire;
+
+use Livewire\Component;
+
+class Dashboard extends Component
+{
+    public function render()
+    {
+        return view('livewire.dashboard');
+    }
+}
@@ -0,0 +1,5 @@
+<livewire:counter />
+<livewire:dashboard />
+<livewire:banner />
+<livewire:legacy />
+<livewire:ghost />
@@ -0,0 +1,18 @@
+<?php
+
+namespace App\Livewire;
+
+use Livewire\Component;
+
+class EditPost extends Component
+{
+    public function save(): void
+    {
+        $this->dispatch('post-saved');
+    }
+
+    public function saveQuietly(): void
+    {
+        $this->dispatchTo('sidebar', 'post-saved');
+    }
+}
@@ -0,0 +1,14 @@
+<?php
+
+namespace App\Livewire;
+
+use Livewire\Attributes\On;
+use Livewire\Component;
+
+class Inbox extends Component
+{
+    #[On('message-received')]
+    public function refresh(): void
+    {
+    }
+}
@@ -0,0 +1,15 @@
+<?php
+
+namespace App\Livewire;
+
+use Livewire\Attributes\On;
+use Livewire\Component;
+
+class PostList extends Component
+{
+    #[On('post-saved')]
+    public function refreshPosts(): void
+    {
+        unset($this->posts);
+    }
+}
@@ -0,0 +1,17 @@
+<?php
+
+namespace App\Livewire;
+
+use Livewire\Component;
+
+class Sidebar extends Component
+{
+    protected $listeners = [
+        'post-saved' => 'refreshCounts',
+        'post-deleted',
+    ];
+
+    public function refreshCounts(): void
+    {
+    }
+}
@@ -0,0 +1,3 @@
+<form wire:submit="save">
+    <button type="button" wire:click="$dispatch('post-saved')">Save draft</button>
+</form>
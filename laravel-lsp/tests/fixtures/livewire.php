@@ -0,0 +1,29 @@
+<?php
+
+return [
+
+    /*
+    |---------------------------------------------------------------------------
+    | Class Namespace
+    |---------------------------------------------------------------------------
+    |
+    | Customized: components live outside the default App\Livewire namespace.
+    |
+    */
+
+    'class_namespace' => 'App\\Http\\Components\\Livewire',
+
+    /*
+    |---------------------------------------------------------------------------
+    | View Path
+    |---------------------------------------------------------------------------
+    |
+    | Customized: component views live next to the Blade components.
+    |
+    */
+
+    'view_path' => resource_path('views/components/livewire'),
+
+    'layout' => 'components.layouts.app',
+
+];
@@ -0,0 +1,18 @@
+<?php
+
+namespace Modules\Blog\Http\Controllers;
+
+use App\Http\Controllers\Controller;
+
+class PostController extends Controller
+{
+    public function index()
+    {
+        return view('blog::posts.index', ['posts' => []]);
+    }
+
+    public function show()
+    {
+        return view('blog::posts.missing');
+    }
+}
@@ -0,0 +1,6 @@
+<?php
+
+use Illuminate\Support\Facades\Route;
+use Modules\Blog\Http\Controllers\PostController;
+
+Route::get('/blog', [PostController::class, 'index'])->name('blog.index');
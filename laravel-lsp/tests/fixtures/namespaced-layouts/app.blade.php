@@ -0,0 +1,6 @@
+<html>
+<body>
+    <nav>@yield('navigation')</nav>
+    <main>@yield('content')</main>
+</body>
+</html>
@@ -0,0 +1,6 @@
+@extends('admin::layouts.app')
+
+@section('content')
+    @include('admin::partials.stats')
+    @include('admin::partials.missing')
+@endsection
@@ -0,0 +1,24 @@
+@extends('layouts.app')
+
+@section('title', 'Dashboard')
+@section('content')
+    {{-- @pushOnce('never-closed') --}}
+    @once
+        <script>
+            window.dashboardLoaded = true;
+        </script>
+    @endonce
+
+    @pushOnce('scripts')
+        <script src="/js/chart.js"></script>
+    @endPushOnce
+
+    @prependOnce('styles')
+        <link rel="stylesheet" href="/css/chart.css">
+    @endPrependOnce
+
+    @pushonce('scripts')
+        <script src="/js/table.js"></script>
+    @endpushonce
+    @php($total = 0)
+@endsection
@@ -0,0 +1,20 @@
+<?php
+
+namespace Acme\Courier;
+
+use Illuminate\Support\ServiceProvider;
+
+class CourierServiceProvider extends ServiceProvider
+{
+    public function register(): void
+    {
+        $this->mergeConfigFrom(__DIR__.'/../config/courier.php', 'courier');
+    }
+
+    public function boot(): void
+    {
+        $this->publishes([
+            __DIR__.'/../config/courier.php' => config_path('courier.php'),
+        ], 'courier-config');
+    }
+}
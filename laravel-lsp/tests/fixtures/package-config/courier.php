@@ -0,0 +1,7 @@
+<?php
+
+return [
+    'driver' => env('COURIER_DRIVER', 'smtp'),
+
+    'retries' => 3,
+];
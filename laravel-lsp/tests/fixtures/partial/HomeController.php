@@ -0,0 +1,16 @@
+<?php
+
+namespace App\Http\Controllers;
+
+class HomeController extends Controller
+{
+    public function index()
+    {
+        $title = config('app.name');
+
+        return view('home', ['title' => $title]);
+    }
+
+    public function show()
+    {
+        return view('hom
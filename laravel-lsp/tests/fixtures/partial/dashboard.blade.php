@@ -0,0 +1,7 @@
+<x-alert type="info" />
+
+<x-layouts.app>
+    <p>{{ __('Welcome back') }}</p>
+</x-layouts.app>
+
+<x-car
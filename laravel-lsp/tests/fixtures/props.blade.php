@@ -0,0 +1,18 @@
+@props([
+    'user',
+    'type' => 'info',
+    'items' => [],
+    'options' => ['sm' => 'Small', 'lg' => 'Large'],
+    'active' => false,
+    'count' => 0,
+    'owner' => \App\Models\User::class,
+    'label' => null,
+    'title',
+])
+
+<div {{ $attributes->merge(['class' => 'card card-' . $type]) }}>
+    <h2>{{ $title }}</h2>
+    @foreach ($items as $item)
+        {{ $item }}
+    @endforeach
+</div>
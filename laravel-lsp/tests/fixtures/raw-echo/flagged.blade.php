@@ -0,0 +1,8 @@
+@php($term = request('search'))
+
+<div class="profile">
+    <p>{!! $request->input('bio') !!}</p>
+    <p>Results for {!! request('q') !!}</p>
+    <span>{!! $_GET['name'] !!}</span>
+    <h2>{!!   $term   !!}</h2>
+</div>
@@ -0,0 +1,12 @@
+<div class="profile">
+    {{-- Escaped echoes are always fine --}}
+    <p>{{ $request->input('bio') }}</p>
+    <p>{{ request('q') }}</p>
+
+    {{-- Raw echoes of framework HTML or escaped values --}}
+    {!! $slot !!}
+    {!! $users->links() !!}
+    {!! e($request->input('bio')) !!}
+    {!! $post->body_html !!}
+    {!! $request->user()->render() !!}
+</div>
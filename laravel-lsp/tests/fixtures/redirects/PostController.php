@@ -0,0 +1,38 @@
+<?php
+
+namespace App\Http\Controllers;
+
+use Illuminate\Support\Facades\Redirect;
+
+class PostController extends Controller
+{
+    public function store()
+    {
+        return to_route('posts.index');
+    }
+
+    public function update($post)
+    {
+        return redirect()->route('posts.show', ['post' => $post]);
+    }
+
+    public function destroy()
+    {
+        return Redirect::route("posts.index");
+    }
+
+    public function cancel()
+    {
+        return back();
+    }
+
+    public function archive()
+    {
+        return redirect()->back()->with('status', 'archived');
+    }
+
+    public function publish()
+    {
+        return to_route('posts.published', ['post' => 1]);
+    }
+}
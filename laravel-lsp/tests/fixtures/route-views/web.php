@@ -0,0 +1,9 @@
+<?php
+
+use Illuminate\Support\Facades\Route;
+use Livewire\Volt\Volt;
+
+Route::view('/', 'home');
+Route::view('/pricing', 'marketing.pricing', ['plans' => []]);
+Volt::route('/settings', 'settings.profile');
+Route::redirect('/old-pricing', '/pricing');
@@ -0,0 +1,15 @@
+@extends('layouts.base')
+
+@section('body')
+    <aside>
+        @yield('sidebar')
+    </aside>
+
+    <main>
+        @yield('content')
+    </main>
+
+    @section('footer')
+        <footer>&copy; Laravel</footer>
+    @show
+@endsection
@@ -0,0 +1,9 @@
+<!DOCTYPE html>
+<html>
+<head>
+    <title>@yield('title', 'Laravel')</title>
+</head>
+<body>
+    @yield('body')
+</body>
+</html>
@@ -0,0 +1,19 @@
+@extends('layouts.app')
+
+@section('title', 'Dashboard')
+
+@section('sidbar')
+    <nav>Links</nav>
+@endsection
+
+@section('content')
+    <p>Welcome back.</p>
+@endsection
+
+@section('fotter')
+    <footer>Custom footer</footer>
+@endsection
+
+@section('scripts')
+    <script src="/js/dashboard.js"></script>
+@endsection
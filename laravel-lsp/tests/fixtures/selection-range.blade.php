@@ -0,0 +1,10 @@
+@extends('layouts.app')
+
+@section('content')
+    @if($user->isAdmin())
+        @foreach($posts as $post)
+            <h2>{{ $post->title }}</h2>
+            <p>{!! $post->excerpt !!}</p>
+        @endforeach
+    @endif
+@endsection
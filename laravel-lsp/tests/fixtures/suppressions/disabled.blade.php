@@ -0,0 +1,7 @@
+{{-- Generated by the design system export --}}
+{{-- laravel-lsp:disable --}}
+@extends('layouts.missing')
+
+@section('content')
+    <x-not-a-component />
+@endsection
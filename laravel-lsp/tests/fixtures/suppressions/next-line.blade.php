@@ -0,0 +1,10 @@
+<div>
+    {{-- laravel-lsp:disable-next-line --}}
+    @include('partials.rendered-at-runtime')
+
+    @include('partials.missing')
+    @php
+        // laravel-lsp:disable-next-line
+        $fallback = view('partials.also-runtime');
+    @endphp
+</div>
@@ -0,0 +1,5 @@
+<p>@choice('messages.apples', $count)</p>
+<p>@choice("messages.minutes", $minutes)</p>
+<p>{{ Lang::get('messages.welcome') }}</p>
+<p>{{ Lang::choice('messages.apples', $count) }}</p>
+<p>{{ Lang::has('messages.banner') ? __('messages.banner') : '' }}</p>
@@ -0,0 +1,23 @@
+<?php
+
+namespace App\Http\Controllers;
+
+use Illuminate\Support\Facades\Lang;
+
+class WelcomeController extends Controller
+{
+    public function __invoke(int $count)
+    {
+        $title = Lang::get('messages.welcome');
+
+        if (Lang::has('messages.banner') || Lang::hasForLocale("messages.banner", 'es')) {
+            $banner = __('messages.banner');
+        }
+
+        return view('welcome', [
+            'title' => $title,
+            'apples' => Lang::choice('messages.apples', $count),
+            'minutes' => \Illuminate\Support\Facades\Lang::get('messages.minutes'),
+        ]);
+    }
+}
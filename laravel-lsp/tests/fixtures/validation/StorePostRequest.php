@@ -0,0 +1,25 @@
+<?php
+
+namespace App\Http\Requests;
+
+use App\Rules\Uppercase;
+use Illuminate\Foundation\Http\FormRequest;
+
+class StorePostRequest extends FormRequest
+{
+    public function rules(): array
+    {
+        return [
+            // Titles shouldn't shout, but the slug's [a-z] only
+            'title' => ['required', 'string', new Uppercase],
+            'slug' => new \App\Rules\Slug(),
+            'email' => 'nullable|email',
+            'phone' => 'required_without:email|regex:/^[0-9]+$/',
+            'address.city' => 'required_with:address',
+            'tags' => 'array',
+            'featured_tag' => 'nullable|in_array:tags.*',
+            'publish_at' => 'required_with:scheduled,emial|after:tomorrow',
+            'notify' => 'required_if:status,published',
+        ];
+    }
+}
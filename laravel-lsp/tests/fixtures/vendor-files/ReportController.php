@@ -0,0 +1,17 @@
+<?php
+
+namespace Acme\Reports\Http;
+
+class ReportController
+{
+    public function index()
+    {
+        return view('welcome');
+    }
+
+    public function show()
+    {
+        // Rendered from the package's own namespace at runtime
+        return view('reports.detail');
+    }
+}
@@ -0,0 +1,21 @@
+<?php
+
+namespace App\Http\Controllers;
+
+class ReportController extends Controller
+{
+    public function index()
+    {
+        return view('home');
+    }
+
+    public function summary()
+    {
+        return view('reports.summary');
+    }
+
+    public function archive()
+    {
+        return view('archive');
+    }
+}
@@ -0,0 +1,6 @@
+@extends('archive')
+
+@section('content')
+    @include('home')
+    @include('reports.summary')
+@endsection
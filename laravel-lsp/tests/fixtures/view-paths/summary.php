@@ -0,0 +1,4 @@
+<?php
+// A plain PHP template - Laravel's finder would render it, but it isn't the Blade view
+?>
+<h1><?= e($title) ?></h1>
@@ -0,0 +1,21 @@
+<?php
+
+namespace App\Http\Controllers;
+
+class HomeController extends Controller
+{
+    public function index()
+    {
+        return view('theme::home');
+    }
+
+    public function about()
+    {
+        return view('about');
+    }
+
+    public function missing()
+    {
+        return view('theme::missing');
+    }
+}
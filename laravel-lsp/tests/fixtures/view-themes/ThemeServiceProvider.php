@@ -0,0 +1,18 @@
+<?php
+
+namespace App\Providers;
+
+use Illuminate\Support\Facades\View;
+use Illuminate\Support\ServiceProvider;
+
+class ThemeServiceProvider extends ServiceProvider
+{
+    public function boot(): void
+    {
+        View::addNamespace('theme', resource_path('themes/default'));
+
+        // Tenant overrides win over resources/views
+        View::getFinder()->prependLocation(resource_path('tenants/acme'));
+        View::addLocation(base_path('legacy/views'));
+    }
+}
@@ -0,0 +1,7 @@
+<head>
+    @vite(['resources/css/app.css', 'resources/js/app.js'])
+    @vite([
+        'resources/css/admin.css',
+        'resources/js/admin.js',
+    ])
+</head>
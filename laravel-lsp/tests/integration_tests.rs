@@ -12,7 +12,7 @@
 //! 3. File type detection routes to correct Salsa input
 //! 4. Debouncing behavior (single event after typing stops)
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::fs;
 
 // Import the library crate for pattern extraction testing
@@ -488,6 +488,43 @@ mod pattern_extraction {
             "routes/web.php should contain view() calls - found: {:?}", patterns.views);
     }
 
+    /// Test that Volt::route() names resolve as Volt components, not views
+    #[test]
+    fn test_php_volt_routes_resolve_to_volt_mounts() {
+        use laravel_lsp::salsa_impl::LaravelConfigData;
+
+        let source = read_test_file("routes/web.php");
+        let tree = parse_php(&source).expect("Failed to parse PHP");
+        let lang = language_php();
+        let patterns = extract_all_php_patterns(&tree, &source, &lang).expect("Failed to extract patterns");
+
+        let config = LaravelConfigData {
+            root: test_project_path(),
+            view_paths: vec![PathBuf::from("resources/views")],
+            component_paths: Vec::new(),
+            livewire_path: None,
+            livewire_view_path: None,
+            has_livewire: true,
+            view_namespaces: Default::default(),
+            component_namespaces: Default::default(),
+            translation_namespaces: Default::default(),
+            package_configs: Default::default(),
+        };
+
+        let volt_routes: Vec<_> = patterns.views.iter().filter(|v| v.is_volt_route).collect();
+        assert!(!volt_routes.is_empty(), "routes/web.php should contain Volt::route() calls");
+        for volt in volt_routes {
+            assert!(volt.is_route_view);
+            assert!(
+                config.resolve_volt_path(volt.view_name).iter().any(|p| p.exists()),
+                "Volt::route('{}') should resolve under a Volt mount", volt.view_name
+            );
+        }
+
+        // Route::redirect('settings', 'settings/profile') has no view
+        assert!(!patterns.views.iter().any(|v| v.view_name == "settings/profile"));
+    }
+
     /// Test that PHP pattern extraction finds env() calls
     #[test]
     fn test_php_extracts_env_patterns() {
@@ -930,6 +967,63 @@ mod resolution {
     }
 }
 
+// ============================================================================
+// Livewire Config Tests
+// ============================================================================
+
+mod livewire_config {
+    use super::*;
+    use laravel_lsp::salsa_impl::{LaravelConfigData, SalsaActor};
+
+    const COMPOSER_JSON: &str = r#"{ "require": { "livewire/livewire": "^3.0" } }"#;
+
+    /// Register a temp project's config files with Salsa and fetch the resolved config
+    async fn load_config(root: &Path, livewire_config: Option<String>) -> LaravelConfigData {
+        let salsa = SalsaActor::spawn();
+        salsa
+            .register_config_files(root.to_path_buf(), Some(COMPOSER_JSON.to_string()), None, livewire_config)
+            .await
+            .unwrap();
+        salsa.get_laravel_config().await.unwrap().expect("config should be registered")
+    }
+
+    #[tokio::test]
+    async fn test_custom_class_namespace_and_view_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().to_path_buf();
+        let fixture = include_str!("fixtures/livewire.php").to_string();
+
+        let config = load_config(&root, Some(fixture)).await;
+
+        // Diagnostics and goto both resolve component classes through resolve_livewire_path
+        assert_eq!(
+            config.resolve_livewire_path("admin.user-table"),
+            Some(root.join("app/Http/Components/Livewire/Admin/UserTable.php"))
+        );
+
+        // Component views (and Volt mounts) follow view_path
+        assert_eq!(config.livewire_view_dir(), root.join("resources/views/components/livewire"));
+        assert_eq!(
+            config.resolve_volt_path("settings.profile")[0],
+            root.join("resources/views/components/livewire/settings/profile.blade.php")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_defaults_without_livewire_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().to_path_buf();
+
+        let config = load_config(&root, None).await;
+
+        assert_eq!(
+            config.resolve_livewire_path("counter"),
+            Some(root.join("app/Livewire/Counter.php"))
+        );
+        assert_eq!(config.livewire_view_dir(), root.join("resources/views/livewire"));
+    }
+}
+
 // ============================================================================
 // Service Provider Parsing Tests
 // ============================================================================
@@ -1,10 +1,22 @@
 use std::fs;
 use std::path::PathBuf;
-use zed_extension_api::{self as zed, Result};
+use zed_extension_api::{self as zed, settings::LspSettings, Result};
 
 // Extension version - used for versioned binary directory
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// Language server id from extension.toml, used to look up user settings
+const LANGUAGE_SERVER_ID: &str = "laravel-lsp";
+
+/// Where release archives are downloaded from unless overridden
+const DEFAULT_RELEASE_BASE: &str = "https://github.com/GeneaLabs/zed-laravel/releases/download";
+
+/// Environment variable overriding the release base URL (forks, mirrors, air-gapped hosts)
+const RELEASE_BASE_ENV: &str = "LARAVEL_LSP_RELEASE_BASE";
+
+/// Environment variable pointing at a local binary, which skips the download entirely
+const BINARY_PATH_ENV: &str = "LARAVEL_LSP_BINARY";
+
 /// The main struct for our Laravel extension
 struct LaravelExtension {
     /// Cached path to the language server binary
@@ -48,6 +60,7 @@ impl LaravelExtension {
     /// Get or download the language server binary
     ///
     /// Search order (optimized for development workflow):
+    /// 0. Local override: `lsp.laravel-lsp.binary.path` setting or `LARAVEL_LSP_BINARY`
     /// 1. DEV MODE: Check cargo build output (multiple locations)
     ///    - Relative path (for dev extension from source)
     ///    - Absolute path in ~/Developer/zed-laravel (for installed extension)
@@ -55,8 +68,26 @@ impl LaravelExtension {
     /// 2. Check cached path (for production, verify still exists)
     /// 3. Check versioned extension directory (laravel-lsp-{VERSION}/)
     /// 4. Try system PATH via worktree.which()
-    /// 5. Download from GitHub releases
+    /// 5. Download from GitHub releases (or the configured release base URL)
     fn language_server_binary_path(&mut self, worktree: &zed::Worktree) -> Result<String> {
+        let lsp_settings = LspSettings::for_worktree(LANGUAGE_SERVER_ID, worktree).ok();
+        let shell_env = worktree.shell_env();
+        let env_var = |name: &str| {
+            shell_env.iter()
+                .find(|(k, _)| k == name)
+                .map(|(_, v)| v.clone())
+                .filter(|v| !v.is_empty())
+        };
+
+        // Step 0: Local override - use it as-is, never download
+        let binary_override = lsp_settings.as_ref()
+            .and_then(|s| s.binary.as_ref())
+            .and_then(|b| b.path.clone())
+            .or_else(|| env_var(BINARY_PATH_ENV));
+        if let Some(path) = binary_override {
+            return Ok(path);
+        }
+
         // Step 1: DEV MODE - Check for cargo build output in multiple locations
         // We check this FIRST and DON'T cache it, so rebuilds are picked up
         // immediately after "zed: reload extensions".
@@ -64,7 +95,6 @@ impl LaravelExtension {
 
         // Paths to check for dev builds (in priority order)
         // Get HOME from shell environment (more reliable in WASM)
-        let shell_env = worktree.shell_env();
         let home = shell_env.iter()
             .find(|(k, _)| k == "HOME" || k == "USERPROFILE")
             .map(|(_, v)| v.clone())
@@ -152,14 +182,22 @@ impl LaravelExtension {
             return Ok(path);
         }
 
-        // Step 5: Download from GitHub releases
-        let downloaded_path = self.download_binary(&binary_name, &version_dir)?;
+        // Step 5: Download from GitHub releases, or a mirror set in the
+        // `releaseBaseUrl` setting / LARAVEL_LSP_RELEASE_BASE
+        let release_base = lsp_settings.as_ref()
+            .and_then(|s| s.settings.as_ref())
+            .and_then(|s| s.get("releaseBaseUrl"))
+            .and_then(|v| v.as_str())
+            .map(str::to_string)
+            .or_else(|| env_var(RELEASE_BASE_ENV))
+            .unwrap_or_else(|| DEFAULT_RELEASE_BASE.to_string());
+        let downloaded_path = self.download_binary(&binary_name, &version_dir, &release_base)?;
         self.cached_binary_path = Some(downloaded_path.clone());
         Ok(downloaded_path)
     }
 
-    /// Download the binary from GitHub releases
-    fn download_binary(&self, binary_name: &str, version_dir: &str) -> Result<String> {
+    /// Download the binary from GitHub releases (or the configured release base URL)
+    fn download_binary(&self, binary_name: &str, version_dir: &str, release_base: &str) -> Result<String> {
         let binary_path = format!("{}/{}", version_dir, binary_name);
 
         // Check if already downloaded
@@ -174,12 +212,7 @@ impl LaravelExtension {
         };
         let archive_name = format!("{}.{}", binary_name, archive_ext);
 
-        // Repository URL - update this if you fork the project
-        let release_url = format!(
-            "https://github.com/GeneaLabs/zed-laravel/releases/download/{}/{}",
-            VERSION,
-            archive_name
-        );
+        let release_url = release_url(release_base, VERSION, &archive_name);
 
         let file_type = match os {
             zed::Os::Windows => zed::DownloadedFileType::Zip,
@@ -241,6 +274,15 @@ impl LaravelExtension {
     }
 }
 
+/// Build the download URL for a release archive
+///
+/// `base` is everything before the version segment, e.g.
+/// `https://github.com/GeneaLabs/zed-laravel/releases/download`. A trailing
+/// slash is tolerated so mirrors can be configured either way.
+fn release_url(base: &str, version: &str, archive_name: &str) -> String {
+    format!("{}/{}/{}", base.trim_end_matches('/'), version, archive_name)
+}
+
 // ============================================================================
 // PHASE 2: File System Navigation - View Path Resolution
 // These functions will be moved into our LSP in Phase 5
@@ -252,6 +294,10 @@ impl LaravelExtension {
 /// - `"welcome"` -> `"resources/views/welcome.blade.php"`
 /// - `"users.profile"` -> `"resources/views/users/profile.blade.php"`
 /// - `"admin.dashboard.index"` -> `"resources/views/admin/dashboard/index.blade.php"`
+/// - `"pkg::admin.dashboard"` -> `"resources/views/vendor/pkg/admin/dashboard.blade.php"`
+/// 
+/// Package views resolve to the application's published overrides in
+/// `resources/views/vendor/{package}`, which Laravel checks before the package itself.
 /// 
 /// Phase 5: This will be handled by the LSP's textDocument/definition handler
 pub fn view_name_to_path(view_name: &str) -> PathBuf {
@@ -259,20 +305,25 @@ pub fn view_name_to_path(view_name: &str) -> PathBuf {
     let mut path = PathBuf::from("resources/views");
     
     // Handle package views (e.g., "package::view.name")
-    let actual_view = if let Some(pos) = view_name.find("::") {
-        // Package view - would need special handling
-        &view_name[pos + 2..]
-    } else {
-        view_name
+    let actual_view = match view_name.split_once("::") {
+        Some((package, view)) => {
+            path.push("vendor");
+            path.push(package);
+            view
+        }
+        None => view_name,
     };
     
-    // Split the view name by dots and convert to path segments
-    for segment in actual_view.split('.') {
+    // Split the view name by dots: all but the last segment are directories
+    let mut segments: Vec<&str> = actual_view.split('.').collect();
+    let file_stem = segments.pop().unwrap_or_default();
+    for segment in segments {
         path.push(segment);
     }
     
-    // Add the Blade extension
-    path.set_extension("blade.php");
+    // Append the Blade extension literally - set_extension() replaces
+    // anything after the last dot of the file name instead of appending
+    path.push(format!("{}.blade.php", file_stem));
     
     path
 }
@@ -354,16 +405,36 @@ pub fn find_blade_components(blade_content: &str) -> Vec<(String, usize, usize)>
 /// # Examples
 /// - `"button"` -> `"resources/views/components/button.blade.php"`
 /// - `"forms.input"` -> `"resources/views/components/forms/input.blade.php"`
+/// - `"forms.text-input"` -> `"resources/views/components/forms/text-input.blade.php"`
 pub fn component_name_to_path(component_name: &str) -> PathBuf {
     let mut path = PathBuf::from("resources/views/components");
     
-    // Convert dots to directory separators (same as views)
-    for segment in component_name.split('.') {
-        // Convert kebab-case to path segments
+    // Convert dots to directory separators (same as views); view files keep kebab-case
+    let mut segments: Vec<&str> = component_name.split('.').collect();
+    let file_stem = segments.pop().unwrap_or_default();
+    for segment in segments {
+        path.push(segment);
+    }
+    
+    path.push(format!("{}.blade.php", file_stem));
+    path
+}
+
+/// Converts a Blade component name to its class file path
+/// 
+/// Uses the same segments as `component_name_to_path`, PascalCased:
+/// - `"alert"` -> `"app/View/Components/Alert.php"`
+/// - `"forms.text-input"` -> `"app/View/Components/Forms/TextInput.php"`
+pub fn component_name_to_class_path(component_name: &str) -> PathBuf {
+    let mut path = PathBuf::from("app/View/Components");
+    
+    let mut segments: Vec<String> = component_name.split('.').map(kebab_to_pascal_case).collect();
+    let class_name = segments.pop().unwrap_or_default();
+    for segment in segments {
         path.push(segment);
     }
     
-    path.set_extension("blade.php");
+    path.push(format!("{}.php", class_name));
     path
 }
 
@@ -471,6 +542,18 @@ fn kebab_to_pascal_case(s: &str) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_release_url() {
+        assert_eq!(
+            release_url(DEFAULT_RELEASE_BASE, "0.1.6", "laravel-lsp-linux-x64.tar.gz"),
+            "https://github.com/GeneaLabs/zed-laravel/releases/download/0.1.6/laravel-lsp-linux-x64.tar.gz"
+        );
+        assert_eq!(
+            release_url("https://mirror.internal/laravel-lsp/", "0.1.6", "laravel-lsp-macos-arm64.tar.gz"),
+            "https://mirror.internal/laravel-lsp/0.1.6/laravel-lsp-macos-arm64.tar.gz"
+        );
+    }
+
     #[test]
     fn test_simple_view_name() {
         let path = view_name_to_path("welcome");
@@ -490,6 +573,23 @@ mod tests {
         assert!(path.to_str().unwrap().contains("dashboard.blade.php"));
     }
 
+    #[test]
+    fn test_package_view_keeps_namespace() {
+        let path = view_name_to_path("pkg::admin.dashboard");
+        assert_eq!(path.to_str().unwrap(), "resources/views/vendor/pkg/admin/dashboard.blade.php");
+    }
+
+    #[test]
+    fn test_view_segment_resembling_extension() {
+        // The last segment is the file stem, even when it looks like an extension
+        let path = view_name_to_path("emails.orders.blade");
+        assert_eq!(path.to_str().unwrap(), "resources/views/emails/orders/blade.blade.php");
+
+        let path = view_name_to_path("mail.html");
+        assert_eq!(path.to_str().unwrap(), "resources/views/mail/html.blade.php");
+        assert_eq!(path.file_name().unwrap(), "html.blade.php");
+    }
+
     #[test]
     fn test_find_view_references() {
         let php_code = r#"
@@ -525,6 +625,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_nested_kebab_component_paths() {
+        let name = "admin.user-forms.text-input";
+        assert_eq!(
+            component_name_to_path(name).to_str().unwrap(),
+            "resources/views/components/admin/user-forms/text-input.blade.php"
+        );
+        assert_eq!(
+            component_name_to_class_path(name).to_str().unwrap(),
+            "app/View/Components/Admin/UserForms/TextInput.php"
+        );
+    }
+
     #[test]
     fn test_livewire_component_parsing() {
         let blade = r#"